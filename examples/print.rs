@@ -1,13 +1,10 @@
 extern crate iso_country as country;
 
 pub fn main() {
-    // use std::ascii::AsciiExt;
-    let mut countries = country::data::all();
-    countries.sort_by_key(|country| country.alpha2);
+    let mut countries = country::data::COUNTRIES.to_vec();
+    countries.sort_by_key(|info| info.country.to_string());
 
-    for country in countries {
-        // let num : usize = country.num.parse().unwrap();
-        // println!("(\"{}\",  Country::{}),", country.alpha2, country.alpha2);
-        println!("\"{}\" => {},", country.name, country.alpha2);
+    for info in countries {
+        println!("{}  {}  {:03}  {}", info.country, info.alpha3, info.numeric, info.name);
     }
 }
@@ -0,0 +1,106 @@
+// HTTP Accept-Language header (RFC 9110, section 12.5.4).
+//
+// Each language range is mapped to a country: ranges with a region subtag
+// ("de-AT") map to that region, language-only ranges ("pl") fall back to
+// the most likely country for the language.  Ranges for a UN M49 area
+// ("es-419") are skipped rather than guessed.
+
+use crate::Country;
+use crate::locale;
+
+/// A country derived from an Accept-Language header with its quality value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Preference {
+    pub country: Country,
+    pub q: f32,
+}
+
+/// Parses an Accept-Language header into countries ordered by preference.
+///
+/// Each country is listed once with the highest weight it was given.
+/// Ranges with `q=0`, the wildcard `*`, area ranges such as `es-419` and
+/// ranges with a malformed weight are skipped.
+pub fn parse(header: &str) -> Vec<Preference> {
+    let mut preferences: Vec<Preference> = vec![];
+
+    for range in header.split(',') {
+        let mut params = range.split(';');
+        let tag = params.next().unwrap_or("").trim();
+        let q = match quality(params) {
+            Some(q) if q > 0.0 => q,
+            _ => continue
+        };
+        let country = match country(tag) {
+            Some(country) => country,
+            None => continue
+        };
+        match preferences.iter_mut().find(|p| p.country == country) {
+            Some(p) => p.q = p.q.max(q),
+            None => preferences.push(Preference { country, q }),
+        }
+    }
+
+    preferences.sort_by(|a, b| b.q.total_cmp(&a.q));
+    preferences
+}
+
+fn country(tag: &str) -> Option<Country> {
+    if tag == "*" {
+        return None;
+    }
+    let locale = locale::parse(tag)?;
+    match locale.region {
+        Some(locale::Region::Country(country)) => Some(country),
+        Some(locale::Region::M49(_)) => None,
+        _ => locale::likely_country(locale.language, locale.script),
+    }
+}
+
+fn quality<'a, I: Iterator<Item = &'a str>>(params: I) -> Option<f32> {
+    for param in params {
+        let (name, value) = match param.split_once('=') {
+            Some(pair) => pair,
+            None => continue
+        };
+        if name.trim().eq_ignore_ascii_case("q") {
+            let q: f32 = value.trim().parse().ok()?;
+            return if (0.0..=1.0).contains(&q) { Some(q) } else { None };
+        }
+    }
+    Some(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ parse, Preference };
+
+    fn countries(header: &str) -> Vec<Country> {
+        parse(header).into_iter().map(|p| p.country).collect()
+    }
+
+    #[test]
+    fn weighted() {
+        assert_eq!(
+            vec![Preference { country: Country::AT, q: 1.0 }, Preference { country: Country::DE, q: 0.9 }],
+            parse("de-AT, de;q=0.9"));
+        assert_eq!(vec![Country::GB, Country::PL, Country::US], countries("en;q=0.5, pl;q=0.8, en-GB"));
+        assert_eq!(vec![Country::TW], countries("zh-Hant"));
+    }
+
+    #[test]
+    fn duplicates_keep_highest_weight() {
+        assert_eq!(vec![Preference { country: Country::US, q: 0.9 }], parse("en;q=0.3, en-US;q=0.9"));
+    }
+
+    #[test]
+    fn skipped() {
+        assert!(countries("").is_empty());
+        assert!(countries("*").is_empty());
+        assert!(countries("fr;q=0").is_empty());
+        assert!(countries("fr;q=high").is_empty());
+        assert!(countries("tlh").is_empty());
+        assert!(countries("es-419").is_empty());
+        assert_eq!(vec![Country::MX], countries("es-419, es-MX;q=0.5"));
+    }
+}
@@ -0,0 +1,939 @@
+// Postal address formats.
+//
+// The layouts follow the address data of Google's libaddressinput.  A
+// format is a template in which "%N" stands for the name, "%O" for the
+// organization, "%A" for the street address, "%D" for the dependent
+// locality, "%C" for the locality, "%S" for the region, "%Z" for the postal
+// code, "%X" for the sorting code and "%n" for a line break.  Any other
+// text is printed as it is when the fields around it are present.
+
+use crate::Country;
+
+/// An address field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Field {
+    Name,
+    Organization,
+    StreetAddress,
+    /// A district, suburb or neighbourhood within the locality.
+    DependentLocality,
+    /// The city or town.
+    Locality,
+    /// The state, province, prefecture or other top level subdivision.
+    Region,
+    PostalCode,
+    /// A sorting code such as the French CEDEX.
+    SortingCode,
+}
+
+impl Field {
+    fn from_letter(c: char) -> Option<Field> {
+        Some(match c {
+            'N' => Field::Name,
+            'O' => Field::Organization,
+            'A' => Field::StreetAddress,
+            'D' => Field::DependentLocality,
+            'C' => Field::Locality,
+            'S' => Field::Region,
+            'Z' => Field::PostalCode,
+            'X' => Field::SortingCode,
+            _ => return None
+        })
+    }
+
+    fn letter(&self) -> char {
+        match *self {
+            Field::Name => 'N',
+            Field::Organization => 'O',
+            Field::StreetAddress => 'A',
+            Field::DependentLocality => 'D',
+            Field::Locality => 'C',
+            Field::Region => 'S',
+            Field::PostalCode => 'Z',
+            Field::SortingCode => 'X',
+        }
+    }
+}
+
+/// What the region is called.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RegionLabel {
+    Area,
+    County,
+    Department,
+    District,
+    DoSi,
+    Emirate,
+    Island,
+    Oblast,
+    Parish,
+    Prefecture,
+    Province,
+    State,
+}
+
+/// What the postal code is called.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PostalCodeLabel {
+    Postal,
+    Zip,
+    Pin,
+    Eircode,
+}
+
+/// What the locality is called.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LocalityLabel {
+    City,
+    District,
+    PostTown,
+    Suburb,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AddressFormat {
+    /// The layout in the local script.
+    pub format: &'static str,
+    /// The layout for addresses written in Latin script, where it differs.
+    pub latin_format: Option<&'static str>,
+    required: &'static str,
+    uppercase: &'static str,
+    pub region_label: RegionLabel,
+    pub postal_code_label: PostalCodeLabel,
+    pub locality_label: LocalityLabel,
+}
+
+impl AddressFormat {
+    /// The fields used by the format, in order.
+    pub fn fields(&self) -> Vec<Field> {
+        let mut fields = vec![];
+        for field in self.format.split('%').skip(1).filter_map(|s| s.chars().next().and_then(Field::from_letter)) {
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+        fields
+    }
+
+    pub fn is_required(&self, field: Field) -> bool {
+        self.required.contains(field.letter())
+    }
+
+    /// Whether the field is written in capital letters.
+    pub fn is_uppercase(&self, field: Field) -> bool {
+        self.uppercase.contains(field.letter())
+    }
+
+    const fn latin(mut self, format: &'static str) -> AddressFormat {
+        self.latin_format = Some(format);
+        self
+    }
+
+    const fn region(mut self, label: RegionLabel) -> AddressFormat {
+        self.region_label = label;
+        self
+    }
+
+    const fn postal_code(mut self, label: PostalCodeLabel) -> AddressFormat {
+        self.postal_code_label = label;
+        self
+    }
+
+    const fn locality(mut self, label: LocalityLabel) -> AddressFormat {
+        self.locality_label = label;
+        self
+    }
+}
+
+/// A postal address.  Empty fields are left out when formatting.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Address<'a> {
+    pub name: &'a str,
+    pub organization: &'a str,
+    /// The street address, one line per line break.
+    pub street_address: &'a str,
+    pub dependent_locality: &'a str,
+    pub locality: &'a str,
+    pub region: &'a str,
+    pub postal_code: &'a str,
+    pub sorting_code: &'a str,
+}
+
+impl<'a> Address<'a> {
+    pub fn get(&self, field: Field) -> &'a str {
+        match field {
+            Field::Name => self.name,
+            Field::Organization => self.organization,
+            Field::StreetAddress => self.street_address,
+            Field::DependentLocality => self.dependent_locality,
+            Field::Locality => self.locality,
+            Field::Region => self.region,
+            Field::PostalCode => self.postal_code,
+            Field::SortingCode => self.sorting_code,
+        }
+    }
+
+    // Whether the address is written in Latin script.
+    fn is_latin(&self) -> bool {
+        [self.name, self.organization, self.street_address, self.dependent_locality, self.locality, self.region]
+            .iter().all(|s| s.chars().all(|c| c < '\u{0250}'))
+    }
+}
+
+/// Formats an address for a label in the layout of the country.
+///
+/// Addresses in Latin script use the Latin layout of countries such as
+/// China and Japan, where the local layout starts with the postal code and
+/// ends with the name.
+pub fn format_address(country: Country, address: &Address) -> String {
+    let format = country.address_format();
+    let template = match format.latin_format {
+        Some(latin) if address.is_latin() => latin,
+        _ => format.format,
+    };
+
+    let mut lines: Vec<String> = vec![];
+    for line in template.split("%n") {
+        // The text before each field and the field itself.
+        let mut parts: Vec<(&str, Option<Field>)> = vec![];
+        let mut rest = line;
+        while let Some(i) = rest.find('%') {
+            let field = rest[i + 1..].chars().next().and_then(Field::from_letter);
+            parts.push((&rest[..i], field));
+            rest = &rest[i + 2..];
+        }
+
+        let value = |field: Option<Field>| -> String {
+            let value = field.map(|f| address.get(f).trim()).unwrap_or("");
+            match field {
+                Some(f) if format.is_uppercase(f) => value.to_uppercase(),
+                _ => value.to_string(),
+            }
+        };
+        let mut formatted = String::new();
+        let mut previous: Option<usize> = None;
+        for (i, &(text, field)) in parts.iter().enumerate() {
+            let value = value(field);
+            if value.is_empty() {
+                continue;
+            }
+            // The text after a field separates it from the next field
+            // present; the text leading the line belongs to the first field.
+            match previous {
+                Some(previous) => formatted.push_str(parts[previous + 1].0),
+                None if i == 0 => formatted.push_str(text),
+                None => {},
+            }
+            formatted.push_str(&value);
+            previous = Some(i);
+        }
+        if parts.is_empty() || previous == Some(parts.len() - 1) {
+            formatted.push_str(rest);
+        }
+        let formatted = formatted.trim();
+        if !formatted.is_empty() {
+            lines.extend(formatted.lines().map(|l| l.trim().to_string()).filter(|l| !l.is_empty()));
+        }
+    }
+    lines.join("\n")
+}
+
+/// An address parsed from free text by [`parse_address`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedAddress {
+    /// The detected country, `Unspecified` if none was found.
+    pub country: Country,
+    pub name: String,
+    pub organization: String,
+    pub street_address: String,
+    pub dependent_locality: String,
+    pub locality: String,
+    pub region: String,
+    pub postal_code: String,
+    pub sorting_code: String,
+    /// How likely the parse is to be right, from 0 to 1.
+    pub confidence: f32,
+}
+
+impl ParsedAddress {
+    pub fn address(&self) -> Address<'_> {
+        Address {
+            name: &self.name,
+            organization: &self.organization,
+            street_address: &self.street_address,
+            dependent_locality: &self.dependent_locality,
+            locality: &self.locality,
+            region: &self.region,
+            postal_code: &self.postal_code,
+            sorting_code: &self.sorting_code,
+        }
+    }
+
+    fn set(&mut self, field: Field, value: &str) {
+        let value = value.trim_matches(|c: char| c.is_whitespace() || c == ',').to_string();
+        match field {
+            Field::Name => self.name = value,
+            Field::Organization => self.organization = value,
+            Field::StreetAddress => self.street_address = value,
+            Field::DependentLocality => self.dependent_locality = value,
+            Field::Locality => self.locality = value,
+            Field::Region => self.region = value,
+            Field::PostalCode => self.postal_code = value,
+            Field::SortingCode => self.sorting_code = value,
+        }
+    }
+}
+
+/// Parses an address pasted as free text, one line per line break or, on
+/// a single line, separated by commas.
+///
+/// The country is taken from a trailing country name or code, or else
+/// guessed from the postal code, preferring `hint` when the postal code
+/// fits several countries.  The remaining lines are split into fields
+/// following the address format of the country.
+pub fn parse_address(text: &str, hint: Option<Country>) -> ParsedAddress {
+    let mut lines: Vec<&str> = text.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+    let single_line = lines.len() == 1;
+    if single_line {
+        lines = lines[0].split(',').map(str::trim).filter(|l| !l.is_empty()).collect();
+    }
+    let mut confidence = 0.0;
+
+    // A trailing country name or code, on its own line or after a comma.
+    let mut country = None;
+    if let Some(&last) = lines.last() {
+        let (rest, tail) = match last.rfind(',') {
+            Some(i) => (last[..i].trim(), &last[i + 1..]),
+            None => ("", last),
+        };
+        if let Some(found) = country_of(tail, !single_line) {
+            country = Some(found);
+            confidence += 0.4;
+            lines.pop();
+            if !rest.is_empty() {
+                lines.push(rest);
+            }
+        }
+    }
+
+    // Otherwise the countries whose postal codes appear in the text.
+    let country = match country {
+        Some(country) => country,
+        None => {
+            let candidates: Vec<Country> = crate::COUNTRY_CODE_SEARCH_TABLE.iter().map(|&(_, c)| c)
+                .filter(|&c| lines.iter().any(|line| postal_code_in(c, line, true).is_some()))
+                .collect();
+            match hint {
+                Some(hint) if candidates.contains(&hint) => {
+                    confidence += 0.3;
+                    hint
+                },
+                _ if candidates.is_empty() => {
+                    if hint.is_some() {
+                        confidence += 0.2;
+                    }
+                    hint.unwrap_or(Country::Unspecified)
+                },
+                _ => {
+                    let fitting: Vec<Country> = candidates.iter().copied()
+                        .filter(|&c| lines.iter().any(|line| fits_layout(c, line)))
+                        .collect();
+                    let choices = if fitting.is_empty() { &candidates } else { &fitting };
+                    confidence += 0.3 / choices.len() as f32;
+                    choices[0]
+                },
+            }
+        },
+    };
+
+    let mut parsed = ParsedAddress {
+        country,
+        name: String::new(),
+        organization: String::new(),
+        street_address: String::new(),
+        dependent_locality: String::new(),
+        locality: String::new(),
+        region: String::new(),
+        postal_code: String::new(),
+        sorting_code: String::new(),
+        confidence: 0.0,
+    };
+    let format = country.address_format();
+    let latin = text.chars().all(|c| c < '\u{0250}');
+    let template = match format.latin_format {
+        Some(latin_format) if latin => latin_format,
+        _ => format.format,
+    };
+    let templates: Vec<&str> = template.split("%n").collect();
+    let street = templates.iter().position(|t| t.contains("%A")).unwrap_or(templates.len());
+
+    // Lines below the street address are matched from the bottom, lines
+    // above it from the top.  Name and organization lines are only taken
+    // when a street address line is left.
+    let mut lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    for template in templates[street + 1..].iter().rev() {
+        if lines.len() <= 1 {
+            break;
+        }
+        if is_name_line(template) {
+            continue;
+        }
+        let mut line = lines.pop().unwrap_or_default();
+        if single_line && template.contains(',') && !line.contains(',') && lines.len() > 1 {
+            line = format!("{}, {}", lines.pop().unwrap_or_default(), line);
+        }
+        fill(&mut parsed, template, &line);
+    }
+    for template in &templates[..street] {
+        if lines.len() <= 1 {
+            break;
+        }
+        if is_name_line(template) {
+            continue;
+        }
+        let line = lines.remove(0);
+        fill(&mut parsed, template, &line);
+    }
+    let name_first = templates.first().is_some_and(|t| is_name_line(t));
+    let mut names: Vec<&str> = templates.iter().copied().filter(|t| is_name_line(t)).collect();
+    if !name_first {
+        names.reverse();
+    }
+    for (i, template) in names.iter().enumerate() {
+        let field = if template.contains("%N") { Field::Name } else { Field::Organization };
+        // An organization line has no digits, unlike most street lines, and
+        // needs a line left for each name line after it and the street.
+        let needed = if field == Field::Name { 1 } else { names.len() - i };
+        let index = if name_first { 0 } else { lines.len().wrapping_sub(1) };
+        match lines.get(index) {
+            Some(line) if lines.len() > needed && (field == Field::Name || !line.chars().any(|c| c.is_ascii_digit())) => {
+                let line = lines.remove(index);
+                parsed.set(field, &line);
+            },
+            _ => {},
+        }
+    }
+    parsed.street_address = lines.join("\n");
+
+    if !parsed.postal_code.is_empty() {
+        confidence += 0.3;
+    }
+    let required: Vec<Field> = format.fields().into_iter().filter(|&f| format.is_required(f)).collect();
+    let present = required.iter().filter(|&&f| !parsed.address().get(f).is_empty()).count();
+    confidence += if required.is_empty() { 0.3 } else { 0.3 * present as f32 / required.len() as f32 };
+    parsed.confidence = confidence.min(1.0);
+    parsed
+}
+
+fn is_name_line(template: &str) -> bool {
+    template == "%N" || template == "%O"
+}
+
+// Splits a line into the fields of a template line.
+fn fill(parsed: &mut ParsedAddress, template: &str, line: &str) {
+    let mut parts: Vec<(&str, Field)> = vec![];
+    let mut rest = template;
+    while let Some(i) = rest.find('%') {
+        if let Some(field) = rest[i + 1..].chars().next().and_then(Field::from_letter) {
+            parts.push((&rest[..i], field));
+        }
+        rest = &rest[i + 2..];
+    }
+
+    let mut line = line.to_string();
+    if let Some(&(prefix, _)) = parts.first() {
+        if let Some(stripped) = line.strip_prefix(prefix.trim()) {
+            line = stripped.to_string();
+        }
+    }
+    if parts.iter().any(|&(_, f)| f == Field::PostalCode) {
+        if let Some((start, end, code)) = postal_code_in(parsed.country, &line, false) {
+            parsed.postal_code = code;
+            line.replace_range(start..end, "");
+        }
+    }
+
+    let fields: Vec<(&str, Field)> = parts.into_iter()
+        .filter(|&(_, f)| f != Field::PostalCode && f != Field::SortingCode)
+        .collect();
+    let mut line = line.trim_matches(|c: char| c.is_whitespace() || c == ',' || c == '-').to_string();
+    for i in (1..fields.len()).rev() {
+        let separator = fields[i].0.trim();
+        let last_space = || line.char_indices().rev().find(|&(_, c)| c.is_whitespace()).map(|(at, c)| (at, at + c.len_utf8()));
+        let split = if separator.is_empty() && fields[i].0.is_empty() {
+            None
+        } else if separator.is_empty() {
+            last_space()
+        } else {
+            line.rfind(separator).map(|at| (at, at + separator.len())).or_else(last_space)
+        };
+        if let Some((at, next)) = split {
+            parsed.set(fields[i].1, &line[next..]);
+            line.truncate(at);
+        }
+    }
+    if let Some(&(_, field)) = fields.first() {
+        parsed.set(field, &line);
+    }
+}
+
+// Finds a postal code of the country among the words of a line and returns
+// its position and canonical form.  Unless `exact`, separators may be left
+// out or misplaced.
+fn postal_code_in(country: Country, line: &str, exact: bool) -> Option<(usize, usize, String)> {
+    let format = country.postal_code_format();
+    if !format.used {
+        return None;
+    }
+    let words: Vec<(usize, usize)> = line.split_whitespace()
+        .map(|w| {
+            let start = w.as_ptr() as usize - line.as_ptr() as usize;
+            (start, start + w.trim_end_matches(',').len())
+        })
+        .collect();
+    for size in (1..=3).rev() {
+        for window in words.windows(size).rev() {
+            let (start, end) = (window[0].0, window[size - 1].1);
+            let candidate = &line[start..end];
+            if exact {
+                if format.patterns().any(|p| crate::postal_code::matches(p, candidate)) {
+                    return Some((start, end, candidate.to_string()));
+                }
+            } else if let Ok(code) = crate::postal_code::validate_postal_code(country, candidate) {
+                return Some((start, end, code));
+            }
+        }
+    }
+    None
+}
+
+// Whether the postal code is placed in the line where the address format
+// of the country places it, before or after the locality.
+fn fits_layout(country: Country, line: &str) -> bool {
+    let format = country.address_format();
+    let template = match format.format.split("%n").find(|t| t.contains("%Z")) {
+        Some(template) => template,
+        None => return false,
+    };
+    let leading = template.find("%Z") < template.find("%C");
+    match postal_code_in(country, line, true) {
+        Some((start, end, _)) => if leading { start == 0 } else { end == line.len() },
+        None => false,
+    }
+}
+
+// A country name, an alternative name or, if `codes`, an alpha-2 or alpha-3
+// code.
+fn country_of(s: &str, codes: bool) -> Option<Country> {
+    let s = s.trim().trim_end_matches('.');
+    if codes && (s.len() == 2 || s.len() == 3) && s.bytes().all(|b| b.is_ascii_uppercase()) {
+        if let Ok(country) = s.parse() {
+            return Some(country);
+        }
+        if let Some(country) = Country::from_alpha3(s) {
+            return Some(country);
+        }
+    }
+    if let Some(country) = Country::from_name(s) {
+        return Some(country);
+    }
+    // Upper case country lines, as on international mail, and "The Gambia".
+    let lower = s.to_lowercase();
+    let lower = lower.strip_prefix("the ").unwrap_or(&lower);
+    if let Some(country) = Country::iter().find(|c| c.name().to_lowercase() == lower) {
+        return Some(country);
+    }
+    ALTERNATIVE_NAME_SEARCH_TABLE.binary_search_by(|&(o, _)| o.cmp(lower)).ok()
+        .map(|i| ALTERNATIVE_NAME_SEARCH_TABLE[i].1)
+}
+
+// Common and native names not in the ISO 3166-1 table, in lower case.
+const ALTERNATIVE_NAME_SEARCH_TABLE : &[(&str, Country)] = &[
+    ("america", Country::US),
+    ("belgien", Country::BE),
+    ("belgique", Country::BE),
+    ("belgië", Country::BE),
+    ("bolivia", Country::BO),
+    ("brasil", Country::BR),
+    ("britain", Country::GB),
+    ("british virgin islands", Country::VG),
+    ("brunei", Country::BN),
+    ("burma", Country::MM),
+    ("cape verde", Country::CV),
+    ("china", Country::CN),
+    ("czechia", Country::CZ),
+    ("danmark", Country::DK),
+    ("democratic republic of the congo", Country::CD),
+    ("deutschland", Country::DE),
+    ("dr congo", Country::CD),
+    ("drc", Country::CD),
+    ("east timor", Country::TL),
+    ("emirates", Country::AE),
+    ("england", Country::GB),
+    ("espana", Country::ES),
+    ("españa", Country::ES),
+    ("eswatini", Country::SZ),
+    ("falklands", Country::FK),
+    ("france", Country::FR),
+    ("great britain", Country::GB),
+    ("hellas", Country::GR),
+    ("holland", Country::NL),
+    ("hrvatska", Country::HR),
+    ("iran", Country::IR),
+    ("italia", Country::IT),
+    ("ivory coast", Country::CI),
+    ("korea", Country::KR),
+    ("laos", Country::LA),
+    ("macau", Country::MO),
+    ("macedonia", Country::MK),
+    ("magyarország", Country::HU),
+    ("mexico", Country::MX),
+    ("micronesia", Country::FM),
+    ("moldova", Country::MD),
+    ("méxico", Country::MX),
+    ("nederland", Country::NL),
+    ("nippon", Country::JP),
+    ("norge", Country::NO),
+    ("north korea", Country::KP),
+    ("north macedonia", Country::MK),
+    ("northern ireland", Country::GB),
+    ("palestine", Country::PS),
+    ("panamá", Country::PA),
+    ("perú", Country::PE),
+    ("polska", Country::PL),
+    ("portugal", Country::PT),
+    ("republic of the congo", Country::CG),
+    ("românia", Country::RO),
+    ("russia", Country::RU),
+    ("schweiz", Country::CH),
+    ("scotland", Country::GB),
+    ("slovenija", Country::SI),
+    ("slovensko", Country::SK),
+    ("south korea", Country::KR),
+    ("srbija", Country::RS),
+    ("suisse", Country::CH),
+    ("suomi", Country::FI),
+    ("sverige", Country::SE),
+    ("svizzera", Country::CH),
+    ("switzerland", Country::CH),
+    ("syria", Country::SY),
+    ("taiwan", Country::TW),
+    ("tanzania", Country::TZ),
+    ("turkiye", Country::TR),
+    ("türkiye", Country::TR),
+    ("u.k", Country::GB),
+    ("u.s. virgin islands", Country::VI),
+    ("u.s.a", Country::US),
+    ("uae", Country::AE),
+    ("uk", Country::GB),
+    ("united kingdom", Country::GB),
+    ("united states", Country::US),
+    ("us", Country::US),
+    ("us virgin islands", Country::VI),
+    ("usa", Country::US),
+    ("vatican", Country::VA),
+    ("vatican city", Country::VA),
+    ("venezuela", Country::VE),
+    ("vietnam", Country::VN),
+    ("việt nam", Country::VN),
+    ("wales", Country::GB),
+    ("éire", Country::IE),
+    ("ísland", Country::IS),
+    ("österreich", Country::AT),
+    ("česko", Country::CZ),
+    ("ελλάδα", Country::GR),
+    ("беларусь", Country::BY),
+    ("българия", Country::BG),
+    ("россия", Country::RU),
+    ("україна", Country::UA),
+    ("中华人民共和国", Country::CN),
+    ("中国", Country::CN),
+    ("台湾", Country::TW),
+    ("台灣", Country::TW),
+    ("日本", Country::JP),
+    ("澳門", Country::MO),
+    ("香港", Country::HK),
+    ("대한민국", Country::KR),
+    ("한국", Country::KR),
+];
+
+const fn a(format: &'static str, required: &'static str, uppercase: &'static str) -> AddressFormat {
+    AddressFormat {
+        format,
+        latin_format: None,
+        required,
+        uppercase,
+        region_label: RegionLabel::Province,
+        postal_code_label: PostalCodeLabel::Postal,
+        locality_label: LocalityLabel::City,
+    }
+}
+
+impl Country {
+    /// The postal address format of this country.
+    pub fn address_format(&self) -> AddressFormat {
+        use Country::*;
+        use self::RegionLabel::*;
+        use self::PostalCodeLabel::*;
+        match *self {
+            AE => a("%N%n%O%n%A%n%S", "AS", "").region(Emirate),
+            AF | AI | KE | LK | NA => a("%N%n%O%n%A%n%C%n%Z", "AC", "C"),
+            AL | MU | OM => a("%N%n%O%n%A%n%Z%n%C", "AC", "C"),
+            AM => a("%N%n%O%n%A%n%Z%n%C%n%S", "AC", "C"),
+            AR => a("%N%n%O%n%A%n%Z %C%n%S", "AC", "ACZ"),
+            AS | FM | MH | MP | PW | VI => a("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOS").region(State).postal_code(Zip),
+            AT | BE | NL => a("%O%n%N%n%A%n%Z %C", "ACZ", ""),
+            AU => a("%O%n%N%n%A%n%C %S %Z", "ACSZ", "CS").region(State).locality(LocalityLabel::Suburb),
+            AX => a("%O%n%N%n%A%nAX-%Z %C%nÅLAND", "ACZ", ""),
+            AZ => a("%N%n%O%n%A%nAZ %Z %C", "AC", "C"),
+            BB => a("%N%n%O%n%A%n%C, %S %Z", "AC", "C").region(Parish),
+            BD => a("%N%n%O%n%A%n%C - %Z", "AC", "C"),
+            BH | BM | BN | IL | JO | KH | LB | MV | NP | SA => a("%N%n%O%n%A%n%C %Z", "AC", "C"),
+            BL | GF | GP | MF | MQ | NC | PF | PM | RE | WF | YT => a("%O%n%N%n%A%n%Z %C %X", "ACZ", "ACX"),
+            BR => a("%O%n%N%n%A%n%D%n%C-%S%n%Z", "ASCZ", "CS").region(State),
+            BS => a("%N%n%O%n%A%n%C, %S", "AC", "C").region(Island),
+            BY => a("%O%n%N%n%A%n%Z, %C%n%S", "AC", "C").region(Oblast),
+            CA => a("%N%n%O%n%A%n%C %S %Z", "ACSZ", "ACNOSZ"),
+            CH => a("%O%n%N%n%A%nCH-%Z %C", "ACZ", ""),
+            CL | CV => a("%N%n%O%n%A%n%Z %C%n%S", "AC", "C"),
+            CN => a("%Z%n%S%C%D%n%A%n%O%n%N", "ACS", "S").latin("%N%n%O%n%A%n%D%n%C%n%S, %Z"),
+            CO => a("%N%n%O%n%A%n%D%n%C, %S, %Z", "AS", "").region(Department),
+            CR => a("%N%n%O%n%A%n%S, %C%n%Z", "ACS", ""),
+            CX | CC | NF => a("%O%n%N%n%A%n%C %S %Z", "AC", "CS"),
+            DK | FO | GL | IS | NO | SJ => a("%N%n%O%n%A%n%Z %C", "ACZ", ""),
+            EC => a("%N%n%O%n%A%n%Z%n%C", "AC", "CZ"),
+            EG => a("%N%n%O%n%A%n%C%n%S%n%Z", "ACS", ""),
+            ES => a("%N%n%O%n%A%n%Z %C %S", "ACSZ", "CS"),
+            FI => a("%O%n%N%n%A%nFI-%Z %C", "ACZ", ""),
+            FK | GS | IO | PN | SH | TC => a("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ"),
+            FR => a("%O%n%N%n%A%n%Z %C %X", "ACZ", "CX"),
+            GB | GG | IM | JE => a("%N%n%O%n%A%n%C%n%Z", "ACZ", "CZ").locality(LocalityLabel::PostTown),
+            GI => a("%N%n%O%n%A%nGIBRALTAR%n%Z", "A", ""),
+            GT => a("%N%n%O%n%A%n%Z- %C", "AC", ""),
+            GU => a("%N%n%O%n%A%n%C %Z", "ACZ", "ACNO").postal_code(Zip),
+            HK => a("%S%n%C%n%A%n%O%n%N", "AS", "S").latin("%N%n%O%n%A%n%C%n%S").region(Area).locality(LocalityLabel::District),
+            HN => a("%N%n%O%n%A%n%C, %S%n%Z", "ACS", "").region(Department),
+            HT => a("%N%n%O%n%A%nHT%Z %C", "AC", ""),
+            HU => a("%N%n%O%n%C%n%A%n%Z", "ACZ", "ACNO"),
+            ID => a("%N%n%O%n%A%n%C%n%S %Z", "AS", ""),
+            IE => a("%N%n%O%n%A%n%D%n%C%n%S%n%Z", "", "").region(County).postal_code(Eircode),
+            IN => a("%N%n%O%n%A%n%C %Z%n%S", "ACSZ", "").region(State).postal_code(Pin),
+            IQ => a("%O%n%N%n%A%n%C, %S%n%Z", "ACS", "CS"),
+            IR => a("%O%n%N%n%S%n%C, %D%n%A%n%Z", "", ""),
+            IT => a("%N%n%O%n%A%n%Z %C %S", "ACSZ", "CS"),
+            JM => a("%N%n%O%n%A%n%C%n%S %X", "ACS", "").region(Parish),
+            JP => a("〒%Z%n%S%n%A%n%O%n%N", "ASZ", "S").latin("%N%n%O%n%A, %S%n%Z").region(Prefecture),
+            KR => a("%S %C%D%n%A%n%O%n%N%n%Z", "ACSZ", "Z").latin("%N%n%O%n%A%n%D%n%C%n%S%n%Z").region(DoSi),
+            KY => a("%N%n%O%n%A%n%S %Z", "AS", "").region(Island),
+            KZ => a("%Z%n%S%n%C%n%A%n%O%n%N", "", "").latin("%N%n%O%n%A%n%C%n%S%n%Z"),
+            LU => a("%O%n%N%n%A%nL-%Z %C", "ACZ", ""),
+            LV => a("%N%n%O%n%A%n%S%n%C, %Z", "ACZ", ""),
+            MC => a("%N%n%O%n%A%nMC-%Z %C %X", "", ""),
+            MD => a("%N%n%O%n%A%nMD-%Z %C", "", ""),
+            MN => a("%N%n%O%n%A%n%C%n%S %Z", "", ""),
+            MO => a("%A%n%O%n%N", "A", "").latin("%N%n%O%n%A"),
+            MT => a("%N%n%O%n%A%n%C %Z", "", "CZ"),
+            MX => a("%N%n%O%n%A%n%D%n%Z %C, %S", "ACSZ", "CSZ").region(State),
+            MY => a("%N%n%O%n%A%n%D%n%Z %C%n%S", "ACZ", "CS").region(State),
+            NG => a("%N%n%O%n%A%n%D%n%C %Z%n%S", "", "CS").region(State),
+            NI => a("%N%n%O%n%A%n%Z%n%C, %S", "", "CS").region(Department),
+            NR => a("%N%n%O%n%A%n%S", "AS", "").region(District),
+            NZ => a("%N%n%O%n%A%n%D%n%C %Z", "ACZ", ""),
+            PA | SR => a("%N%n%O%n%A%n%C%n%S", "", "CS"),
+            PE => a("%N%n%O%n%A%n%C %Z%n%S", "ACS", "").region(Department),
+            PG => a("%N%n%O%n%A%n%C %Z %S", "ACS", ""),
+            PH => a("%N%n%O%n%A%n%D, %C%n%Z %S", "AC", ""),
+            PK => a("%N%n%O%n%A%n%D%n%C-%Z", "", ""),
+            PR => a("%N%n%O%n%A%n%C PR %Z", "ACZ", "ACNO").postal_code(Zip),
+            RU => a("%N%n%O%n%A%n%C%n%S%n%Z", "ACSZ", "AC").latin("%N%n%O%n%A%n%C%n%S%n%Z").region(Oblast),
+            SC | TV => a("%N%n%O%n%A%n%C%n%S", "", "S").region(Island),
+            SE => a("%O%n%N%n%A%nSE-%Z %C", "ACZ", ""),
+            SG => a("%N%n%O%n%A%nSINGAPORE %Z", "AZ", ""),
+            SI => a("%N%n%O%n%A%nSI-%Z %C", "", ""),
+            SO => a("%N%n%O%n%A%n%C, %S %Z", "ACS", "ACS"),
+            SV => a("%N%n%O%n%A%n%Z-%C%n%S", "ACS", "CSZ").region(Department),
+            TH => a("%N%n%O%n%A%n%D %C%n%S %Z", "", "S").latin("%N%n%O%n%A%n%D, %C%n%S %Z"),
+            TR => a("%N%n%O%n%A%n%Z %C/%S", "ACZ", "").locality(LocalityLabel::District),
+            TW => a("%Z%n%S%C%n%A%n%O%n%N", "ACSZ", "").latin("%N%n%O%n%A%n%C, %S %Z").region(County),
+            UA => a("%N%n%O%n%A%n%C%n%S%n%Z", "ACZ", "").latin("%N%n%O%n%A%n%C%n%S%n%Z").region(Oblast),
+            UM => a("%N%n%O%n%A%n%C %S %Z", "ACS", "ACNOS").region(State).postal_code(Zip),
+            US => a("%N%n%O%n%A%n%C, %S %Z", "ACSZ", "CS").region(State).postal_code(Zip),
+            UY => a("%N%n%O%n%A%n%Z %C %S", "", "CS").region(Department),
+            UZ => a("%N%n%O%n%A%n%Z %C%n%S", "", "CS"),
+            VE => a("%N%n%O%n%A%n%C %Z, %S", "ACS", "CS").region(State),
+            VG => a("%N%n%O%n%A%n%C%n%Z", "A", ""),
+            VN => a("%N%n%O%n%A%n%C%n%S %Z", "", "").latin("%N%n%O%n%A%n%C%n%S %Z"),
+            ZA => a("%N%n%O%n%A%n%D%n%C%n%Z", "ACZ", ""),
+            _ if self.postal_code_format().used => a("%N%n%O%n%A%n%Z %C", "ACZ", "C"),
+            _ => a("%N%n%O%n%A%n%C", "AC", "C"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ format_address, parse_address, Address, Field, PostalCodeLabel, RegionLabel };
+
+    #[test]
+    fn format() {
+        let format = Country::US.address_format();
+        assert_eq!(vec![Field::Name, Field::Organization, Field::StreetAddress, Field::Locality, Field::Region, Field::PostalCode],
+                   format.fields());
+        assert!(format.is_required(Field::Region));
+        assert!(format.is_uppercase(Field::Locality));
+        assert!(!format.is_required(Field::Name));
+        assert_eq!(RegionLabel::State, format.region_label);
+        assert_eq!(PostalCodeLabel::Zip, format.postal_code_label);
+        assert_eq!(RegionLabel::Prefecture, Country::JP.address_format().region_label);
+    }
+
+    #[test]
+    fn western() {
+        let address = Address {
+            name: "John Doe",
+            street_address: "1600 Amphitheatre Parkway",
+            locality: "Mountain View",
+            region: "CA",
+            postal_code: "94043",
+            ..Default::default()
+        };
+        assert_eq!("John Doe\n1600 Amphitheatre Parkway\nMOUNTAIN VIEW, CA 94043", format_address(Country::US, &address));
+
+        let address = Address {
+            name: "Jan Kowalski",
+            organization: "Firma",
+            street_address: "ul. Długa 1\nm. 2",
+            locality: "Warszawa",
+            postal_code: "00-950",
+            ..Default::default()
+        };
+        assert_eq!("Jan Kowalski\nFirma\nul. Długa 1\nm. 2\n00-950 WARSZAWA", format_address(Country::PL, &address));
+    }
+
+    #[test]
+    fn east_asian() {
+        let address = Address {
+            name: "山田太郎",
+            street_address: "千代田区丸の内1-1",
+            region: "東京都",
+            postal_code: "100-0005",
+            ..Default::default()
+        };
+        assert_eq!("〒100-0005\n東京都\n千代田区丸の内1-1\n山田太郎", format_address(Country::JP, &address));
+
+        let address = Address {
+            name: "Taro Yamada",
+            street_address: "1-1 Marunouchi, Chiyoda-ku",
+            region: "Tokyo",
+            postal_code: "100-0005",
+            ..Default::default()
+        };
+        assert_eq!("Taro Yamada\n1-1 Marunouchi, Chiyoda-ku, TOKYO\n100-0005", format_address(Country::JP, &address));
+
+        let address = Address {
+            name: "张三",
+            street_address: "建国路88号",
+            dependent_locality: "朝阳区",
+            locality: "北京市",
+            region: "北京市",
+            postal_code: "100022",
+            ..Default::default()
+        };
+        assert_eq!("100022\n北京市北京市朝阳区\n建国路88号\n张三", format_address(Country::CN, &address));
+    }
+
+    #[test]
+    fn missing_fields() {
+        let address = Address { locality: "Mountain View", postal_code: "94043", ..Default::default() };
+        assert_eq!("MOUNTAIN VIEW, 94043", format_address(Country::US, &address));
+        let address = Address { street_address: "Bahnhofstrasse 1", locality: "Zürich", ..Default::default() };
+        assert_eq!("Bahnhofstrasse 1\nZürich", format_address(Country::CH, &address));
+    }
+
+    #[test]
+    fn parse() {
+        let parsed = parse_address("John Doe\n1600 Amphitheatre Parkway\nMountain View, CA 94043\nUSA", None);
+        assert_eq!(Country::US, parsed.country);
+        assert_eq!("John Doe", parsed.name);
+        assert_eq!("1600 Amphitheatre Parkway", parsed.street_address);
+        assert_eq!("Mountain View", parsed.locality);
+        assert_eq!("CA", parsed.region);
+        assert_eq!("94043", parsed.postal_code);
+        assert_eq!(1.0, parsed.confidence);
+
+        let parsed = parse_address("Jan Kowalski, ul. Długa 1, 00950 Warszawa, Polska", None);
+        assert_eq!(Country::PL, parsed.country);
+        assert_eq!("Jan Kowalski", parsed.name);
+        assert_eq!("ul. Długa 1", parsed.street_address);
+        assert_eq!("00-950", parsed.postal_code);
+        assert_eq!("Warszawa", parsed.locality);
+
+        let parsed = parse_address("Buckingham Palace\nLondon\nSW1A 1AA", None);
+        assert_eq!(Country::GB, parsed.country);
+        assert_eq!("Buckingham Palace", parsed.street_address);
+        assert_eq!("London", parsed.locality);
+        assert_eq!("SW1A 1AA", parsed.postal_code);
+
+        let parsed = parse_address("〒100-0005\n東京都\n千代田区丸の内1-1\n山田太郎", Some(Country::JP));
+        assert_eq!(Country::JP, parsed.country);
+        assert_eq!("100-0005", parsed.postal_code);
+        assert_eq!("東京都", parsed.region);
+        assert_eq!("千代田区丸の内1-1", parsed.street_address);
+        assert_eq!("山田太郎", parsed.name);
+    }
+
+    #[test]
+    fn parse_without_organization() {
+        let parsed = parse_address("John Smith\n1 George St\nSydney NSW 2000", Some(Country::AU));
+        assert_eq!("John Smith", parsed.name);
+        assert_eq!("", parsed.organization);
+        assert_eq!("1 George St", parsed.street_address);
+        assert_eq!("Sydney", parsed.locality);
+        assert_eq!("NSW", parsed.region);
+
+        let parsed = parse_address("Acme Pty Ltd\nJohn Smith\n1 George St\nSydney NSW 2000", Some(Country::AU));
+        assert_eq!("Acme Pty Ltd", parsed.organization);
+        assert_eq!("John Smith", parsed.name);
+    }
+
+    #[test]
+    fn parse_multibyte_spaces() {
+        for space in ['\u{3000}', '\u{a0}'] {
+            let parsed = parse_address(&format!("John Smith\n1 George St\nSydney{}NSW 2000", space), Some(Country::AU));
+            assert_eq!("Sydney", parsed.locality);
+            assert_eq!("NSW", parsed.region);
+            assert_eq!("2000", parsed.postal_code);
+        }
+    }
+
+    #[test]
+    fn parse_confidence() {
+        let explicit = parse_address("Hauptstraße 1\n10117 Berlin\nDeutschland", None);
+        let hinted = parse_address("Hauptstraße 1\n10117 Berlin", Some(Country::DE));
+        let guessed = parse_address("Hauptstraße 1\n10117 Berlin", None);
+        assert_eq!(Country::DE, explicit.country);
+        assert_eq!(Country::DE, hinted.country);
+        assert_eq!("Berlin", hinted.locality);
+        assert!(explicit.confidence > hinted.confidence);
+        assert!(hinted.confidence > guessed.confidence);
+        assert_eq!(Country::Unspecified, parse_address("somewhere", None).country);
+    }
+
+    #[test]
+    fn country_names() {
+        assert_eq!(Some(Country::PL), super::country_of("Poland", false));
+        assert_eq!(Some(Country::PL), super::country_of("POLAND", false));
+        assert_eq!(Some(Country::GM), super::country_of("The Gambia", false));
+        assert_eq!(Some(Country::DE), super::country_of("Deutschland", false));
+        assert_eq!(Some(Country::DE), super::country_of("DEU", true));
+        assert_eq!(None, super::country_of("DEU", false));
+        assert_eq!(None, super::country_of("", false));
+    }
+
+    #[test]
+    fn alternative_names_are_sorted() {
+        let names = super::ALTERNATIVE_NAME_SEARCH_TABLE;
+        assert!(names.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
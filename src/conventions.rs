@@ -0,0 +1,189 @@
+// Locale conventions per country.
+//
+// The data follows the CLDR supplemental data: measurementData, paperSize,
+// weekData, timeData and calendarPreferenceData.  Number separators and
+// date order are those of the locale of the main language of the country.
+
+use crate::Country;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MeasurementSystem {
+    Metric,
+    /// Miles, pounds and Fahrenheit.
+    US,
+    /// Metric with miles, pints and stones in everyday use.
+    UK,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaperSize {
+    A4,
+    Letter,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HourCycle {
+    /// 1:00 PM
+    H12,
+    /// 13:00
+    H23,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Calendar {
+    Gregorian,
+    /// The Thai solar calendar.
+    Buddhist,
+    /// The Solar Hijri calendar.
+    Persian,
+    /// The Umm al-Qura Hijri calendar of Saudi Arabia.
+    IslamicUmalqura,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    DMY,
+    MDY,
+    YMD,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Conventions {
+    pub measurement: MeasurementSystem,
+    pub paper_size: PaperSize,
+    pub first_day: Weekday,
+    /// The first and last day of the weekend, the same day for a one day
+    /// weekend.
+    pub weekend: (Weekday, Weekday),
+    pub hour_cycle: HourCycle,
+    pub calendar: Calendar,
+    pub decimal_separator: char,
+    pub grouping_separator: char,
+    pub date_order: DateOrder,
+}
+
+impl Country {
+    /// The conventions for units, dates, times and numbers in this country.
+    pub fn conventions(&self) -> Conventions {
+        use Country::*;
+        use self::Weekday::*;
+
+        let measurement = match *self {
+            BS | BZ | KY | LR | PR | PW | US => MeasurementSystem::US,
+            GB => MeasurementSystem::UK,
+            _ => MeasurementSystem::Metric,
+        };
+        let paper_size = match *self {
+            BZ | CA | CL | CO | CR | GT | MX | NI | PA | PH | PR | SV | US | VE => PaperSize::Letter,
+            _ => PaperSize::A4,
+        };
+        let first_day = match *self {
+            AG | AS | BD | BR | BS | BT | BW | BZ | CA | CO | DM | DO | ET | GT | GU | HK | HN | ID | IL | IN |
+            JM | JP | KE | KH | KR | LA | MH | MM | MO | MT | MX | MZ | NI | NP | PA | PE | PH | PK | PR | PT |
+            PY | SA | SG | SV | TH | TT | TW | UM | US | VE | VI | WS | YE | ZA | ZW => Sunday,
+            AF | BH | DJ | DZ | EG | IQ | IR | JO | KW | LY | OM | QA | SD | SY => Saturday,
+            MV => Friday,
+            _ => Monday,
+        };
+        let weekend = match *self {
+            BH | DZ | EG | IL | IQ | JO | KW | LY | OM | QA | SA | SD | SY | YE => (Friday, Saturday),
+            AF => (Thursday, Friday),
+            IR => (Friday, Friday),
+            IN | UG => (Sunday, Sunday),
+            _ => (Saturday, Sunday),
+        };
+        let hour_cycle = match *self {
+            AE | AG | AS | AU | BB | BD | BH | BM | BN | BS | BT | CA | DM | EG | FJ | FM | GD | GM | GU | GY |
+            HK | IN | IQ | JM | JO | KI | KN | KR | KW | KY | LB | LC | LR | LY | MH | MO | MP | MW | MY | NZ |
+            OM | PH | PK | PR | PS | QA | SA | SB | SD | SG | SL | SS | SY | SZ | TC | TT | TW | UM | US | VC |
+            VG | VI | YE | ZM => HourCycle::H12,
+            _ => HourCycle::H23,
+        };
+        let calendar = match *self {
+            AF | IR => Calendar::Persian,
+            TH => Calendar::Buddhist,
+            SA => Calendar::IslamicUmalqura,
+            _ => Calendar::Gregorian,
+        };
+        let (decimal_separator, grouping_separator) = match *self {
+            CH | LI => ('.', '\u{2019}'),
+            AD | AO | AR | AW | AZ | BA | BE | BO | BQ | BR | CL | CO | CV | CW | CY | DE | DK | EC | ES | FO |
+            GL | GQ | GR | GW | HR | ID | IS | IT | LU | ME | MK | MZ | NL | PY | RO | RS | SI | SM | SR | ST |
+            SX | TL | TR | UY | VA | VE | VN => (',', '.'),
+            AL | AM | AT | AX | BG | BY | CR | CZ | EE | FI | GE | HU | KG | KZ | LT | LV | MD | NO | PL | PT |
+            RU | SE | SJ | SK | TJ | UA | UZ | ZA => (',', '\u{a0}'),
+            BF | BI | BJ | BL | CD | CF | CG | CI | CM | DJ | FR | GA | GF | GN | GP | KM | MC | MF | MG | ML |
+            MQ | NC | NE | PF | PM | RE | SN | TD | TG | WF | YT => (',', '\u{202f}'),
+            _ => ('.', ','),
+        };
+        let date_order = match *self {
+            CA | CN | HU | IR | JP | KP | KR | LT | MN | SE | TW | ZA => DateOrder::YMD,
+            AS | FM | GU | MH | MP | PH | PR | PW | UM | US | VI => DateOrder::MDY,
+            _ => DateOrder::DMY,
+        };
+
+        Conventions {
+            measurement,
+            paper_size,
+            first_day,
+            weekend,
+            hour_cycle,
+            calendar,
+            decimal_separator,
+            grouping_separator,
+            date_order,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ Calendar, DateOrder, HourCycle, MeasurementSystem, PaperSize, Weekday };
+
+    #[test]
+    fn united_states() {
+        let conventions = Country::US.conventions();
+        assert_eq!(MeasurementSystem::US, conventions.measurement);
+        assert_eq!(PaperSize::Letter, conventions.paper_size);
+        assert_eq!(Weekday::Sunday, conventions.first_day);
+        assert_eq!((Weekday::Saturday, Weekday::Sunday), conventions.weekend);
+        assert_eq!(HourCycle::H12, conventions.hour_cycle);
+        assert_eq!(('.', ','), (conventions.decimal_separator, conventions.grouping_separator));
+        assert_eq!(DateOrder::MDY, conventions.date_order);
+    }
+
+    #[test]
+    fn europe() {
+        let conventions = Country::PL.conventions();
+        assert_eq!(MeasurementSystem::Metric, conventions.measurement);
+        assert_eq!(PaperSize::A4, conventions.paper_size);
+        assert_eq!(Weekday::Monday, conventions.first_day);
+        assert_eq!(HourCycle::H23, conventions.hour_cycle);
+        assert_eq!((',', '\u{a0}'), (conventions.decimal_separator, conventions.grouping_separator));
+        assert_eq!(DateOrder::DMY, conventions.date_order);
+        assert_eq!(MeasurementSystem::UK, Country::GB.conventions().measurement);
+        assert_eq!(',', Country::DE.conventions().decimal_separator);
+        assert_eq!('\u{2019}', Country::CH.conventions().grouping_separator);
+    }
+
+    #[test]
+    fn other_calendars_and_weekends() {
+        assert_eq!(Calendar::Persian, Country::IR.conventions().calendar);
+        assert_eq!(Calendar::Buddhist, Country::TH.conventions().calendar);
+        assert_eq!((Weekday::Friday, Weekday::Saturday), Country::SA.conventions().weekend);
+        assert_eq!(Weekday::Saturday, Country::EG.conventions().first_day);
+        assert_eq!(DateOrder::YMD, Country::JP.conventions().date_order);
+    }
+}
@@ -1,1509 +1,675 @@
-pub struct CountryCode<'a> {
-    pub alpha2: &'a str,
-    pub alpha3: &'a str,
-    pub name: &'a str,
-    pub num: &'a str,
+use crate::Country;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CountryInfo {
+    pub country: Country,
+    pub alpha2: &'static str,
+    pub alpha3: &'static str,
+    pub numeric: u16,
+    pub name: &'static str,
+}
+
+/// The former name of `CountryInfo`.
+pub type CountryCode = CountryInfo;
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for CountryInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: ::serde::Serializer {
+        use ::serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CountryInfo", 4)?;
+        state.serialize_field("country", &self.country)?;
+        state.serialize_field("alpha3", self.alpha3)?;
+        state.serialize_field("numeric", &self.numeric)?;
+        state.serialize_field("name", self.name)?;
+        state.end()
+    }
+}
+
+/// Deserializes the record of the `country` field.  The other fields are
+/// optional but must match the record when present.
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de> ::serde::Deserialize<'de> for CountryInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::serde::Deserializer<'de> {
+        use ::serde::de::{ Error, IgnoredAny, MapAccess, Visitor };
+        use core::fmt;
+        struct CountryInfoVisitor;
+
+        impl<'de> Visitor<'de> for CountryInfoVisitor {
+            type Value = CountryInfo;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("country code record")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<CountryInfo, A::Error> where A: MapAccess<'de> {
+                let (mut country, mut alpha3, mut numeric, mut name) = (None, None, None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "country" => country = Some(map.next_value::<Country>()?),
+                        "alpha3" => alpha3 = Some(map.next_value::<String>()?),
+                        "numeric" => numeric = Some(map.next_value::<u16>()?),
+                        "name" => name = Some(map.next_value::<String>()?),
+                        _ => { map.next_value::<IgnoredAny>()?; },
+                    }
+                }
+                let country = country.ok_or_else(|| A::Error::missing_field("country"))?;
+                let code = *country.info();
+                if country == Country::Unspecified {
+                    return Err(A::Error::custom("no record for an unspecified country"))
+                }
+                if alpha3.is_some_and(|a| a != code.alpha3) || numeric.is_some_and(|n| n != code.numeric)
+                    || name.is_some_and(|n| n != code.name) {
+                    return Err(A::Error::custom(format_args!("fields do not match the record of {}", country)))
+                }
+                Ok(code)
+            }
+        }
+
+        deserializer.deserialize_struct("CountryInfo", &["country", "alpha3", "numeric", "name"], CountryInfoVisitor)
+    }
+}
+
+/// The data of all countries, ordered by numeric code.
+pub static COUNTRIES : &[CountryInfo] = &[
+    CountryInfo { country: Country::AF, alpha2: "AF", alpha3: "AFG", numeric: 4, name: "Afghanistan" },
+    CountryInfo { country: Country::AL, alpha2: "AL", alpha3: "ALB", numeric: 8, name: "Albania" },
+    CountryInfo { country: Country::AQ, alpha2: "AQ", alpha3: "ATA", numeric: 10, name: "Antarctica" },
+    CountryInfo { country: Country::DZ, alpha2: "DZ", alpha3: "DZA", numeric: 12, name: "Algeria" },
+    CountryInfo { country: Country::AS, alpha2: "AS", alpha3: "ASM", numeric: 16, name: "American Samoa" },
+    CountryInfo { country: Country::AD, alpha2: "AD", alpha3: "AND", numeric: 20, name: "Andorra" },
+    CountryInfo { country: Country::AO, alpha2: "AO", alpha3: "AGO", numeric: 24, name: "Angola" },
+    CountryInfo { country: Country::AG, alpha2: "AG", alpha3: "ATG", numeric: 28, name: "Antigua and Barbuda" },
+    CountryInfo { country: Country::AZ, alpha2: "AZ", alpha3: "AZE", numeric: 31, name: "Azerbaijan" },
+    CountryInfo { country: Country::AR, alpha2: "AR", alpha3: "ARG", numeric: 32, name: "Argentina" },
+    CountryInfo { country: Country::AU, alpha2: "AU", alpha3: "AUS", numeric: 36, name: "Australia" },
+    CountryInfo { country: Country::AT, alpha2: "AT", alpha3: "AUT", numeric: 40, name: "Austria" },
+    CountryInfo { country: Country::BS, alpha2: "BS", alpha3: "BHS", numeric: 44, name: "Bahamas" },
+    CountryInfo { country: Country::BH, alpha2: "BH", alpha3: "BHR", numeric: 48, name: "Bahrain" },
+    CountryInfo { country: Country::BD, alpha2: "BD", alpha3: "BGD", numeric: 50, name: "Bangladesh" },
+    CountryInfo { country: Country::AM, alpha2: "AM", alpha3: "ARM", numeric: 51, name: "Armenia" },
+    CountryInfo { country: Country::BB, alpha2: "BB", alpha3: "BRB", numeric: 52, name: "Barbados" },
+    CountryInfo { country: Country::BE, alpha2: "BE", alpha3: "BEL", numeric: 56, name: "Belgium" },
+    CountryInfo { country: Country::BM, alpha2: "BM", alpha3: "BMU", numeric: 60, name: "Bermuda" },
+    CountryInfo { country: Country::BT, alpha2: "BT", alpha3: "BTN", numeric: 64, name: "Bhutan" },
+    CountryInfo { country: Country::BO, alpha2: "BO", alpha3: "BOL", numeric: 68, name: "Bolivia (Plurinational State of)" },
+    CountryInfo { country: Country::BA, alpha2: "BA", alpha3: "BIH", numeric: 70, name: "Bosnia and Herzegovina" },
+    CountryInfo { country: Country::BW, alpha2: "BW", alpha3: "BWA", numeric: 72, name: "Botswana" },
+    CountryInfo { country: Country::BV, alpha2: "BV", alpha3: "BVT", numeric: 74, name: "Bouvet Island" },
+    CountryInfo { country: Country::BR, alpha2: "BR", alpha3: "BRA", numeric: 76, name: "Brazil" },
+    CountryInfo { country: Country::BZ, alpha2: "BZ", alpha3: "BLZ", numeric: 84, name: "Belize" },
+    CountryInfo { country: Country::IO, alpha2: "IO", alpha3: "IOT", numeric: 86, name: "British Indian Ocean Territory" },
+    CountryInfo { country: Country::SB, alpha2: "SB", alpha3: "SLB", numeric: 90, name: "Solomon Islands" },
+    CountryInfo { country: Country::VG, alpha2: "VG", alpha3: "VGB", numeric: 92, name: "Virgin Islands (British)" },
+    CountryInfo { country: Country::BN, alpha2: "BN", alpha3: "BRN", numeric: 96, name: "Brunei Darussalam" },
+    CountryInfo { country: Country::BG, alpha2: "BG", alpha3: "BGR", numeric: 100, name: "Bulgaria" },
+    CountryInfo { country: Country::MM, alpha2: "MM", alpha3: "MMR", numeric: 104, name: "Myanmar" },
+    CountryInfo { country: Country::BI, alpha2: "BI", alpha3: "BDI", numeric: 108, name: "Burundi" },
+    CountryInfo { country: Country::BY, alpha2: "BY", alpha3: "BLR", numeric: 112, name: "Belarus" },
+    CountryInfo { country: Country::KH, alpha2: "KH", alpha3: "KHM", numeric: 116, name: "Cambodia" },
+    CountryInfo { country: Country::CM, alpha2: "CM", alpha3: "CMR", numeric: 120, name: "Cameroon" },
+    CountryInfo { country: Country::CA, alpha2: "CA", alpha3: "CAN", numeric: 124, name: "Canada" },
+    CountryInfo { country: Country::CV, alpha2: "CV", alpha3: "CPV", numeric: 132, name: "Cabo Verde" },
+    CountryInfo { country: Country::KY, alpha2: "KY", alpha3: "CYM", numeric: 136, name: "Cayman Islands" },
+    CountryInfo { country: Country::CF, alpha2: "CF", alpha3: "CAF", numeric: 140, name: "Central African Republic" },
+    CountryInfo { country: Country::LK, alpha2: "LK", alpha3: "LKA", numeric: 144, name: "Sri Lanka" },
+    CountryInfo { country: Country::TD, alpha2: "TD", alpha3: "TCD", numeric: 148, name: "Chad" },
+    CountryInfo { country: Country::CL, alpha2: "CL", alpha3: "CHL", numeric: 152, name: "Chile" },
+    CountryInfo { country: Country::CN, alpha2: "CN", alpha3: "CHN", numeric: 156, name: "China" },
+    CountryInfo { country: Country::TW, alpha2: "TW", alpha3: "TWN", numeric: 158, name: "Taiwan, Province of China[a]" },
+    CountryInfo { country: Country::CX, alpha2: "CX", alpha3: "CXR", numeric: 162, name: "Christmas Island" },
+    CountryInfo { country: Country::CC, alpha2: "CC", alpha3: "CCK", numeric: 166, name: "Cocos (Keeling) Islands" },
+    CountryInfo { country: Country::CO, alpha2: "CO", alpha3: "COL", numeric: 170, name: "Colombia" },
+    CountryInfo { country: Country::KM, alpha2: "KM", alpha3: "COM", numeric: 174, name: "Comoros" },
+    CountryInfo { country: Country::YT, alpha2: "YT", alpha3: "MYT", numeric: 175, name: "Mayotte" },
+    CountryInfo { country: Country::CG, alpha2: "CG", alpha3: "COG", numeric: 178, name: "Congo" },
+    CountryInfo { country: Country::CD, alpha2: "CD", alpha3: "COD", numeric: 180, name: "Congo (Democratic Republic of the)" },
+    CountryInfo { country: Country::CK, alpha2: "CK", alpha3: "COK", numeric: 184, name: "Cook Islands" },
+    CountryInfo { country: Country::CR, alpha2: "CR", alpha3: "CRI", numeric: 188, name: "Costa Rica" },
+    CountryInfo { country: Country::HR, alpha2: "HR", alpha3: "HRV", numeric: 191, name: "Croatia" },
+    CountryInfo { country: Country::CU, alpha2: "CU", alpha3: "CUB", numeric: 192, name: "Cuba" },
+    CountryInfo { country: Country::CY, alpha2: "CY", alpha3: "CYP", numeric: 196, name: "Cyprus" },
+    CountryInfo { country: Country::CZ, alpha2: "CZ", alpha3: "CZE", numeric: 203, name: "Czech Republic" },
+    CountryInfo { country: Country::BJ, alpha2: "BJ", alpha3: "BEN", numeric: 204, name: "Benin" },
+    CountryInfo { country: Country::DK, alpha2: "DK", alpha3: "DNK", numeric: 208, name: "Denmark" },
+    CountryInfo { country: Country::DM, alpha2: "DM", alpha3: "DMA", numeric: 212, name: "Dominica" },
+    CountryInfo { country: Country::DO, alpha2: "DO", alpha3: "DOM", numeric: 214, name: "Dominican Republic" },
+    CountryInfo { country: Country::EC, alpha2: "EC", alpha3: "ECU", numeric: 218, name: "Ecuador" },
+    CountryInfo { country: Country::SV, alpha2: "SV", alpha3: "SLV", numeric: 222, name: "El Salvador" },
+    CountryInfo { country: Country::GQ, alpha2: "GQ", alpha3: "GNQ", numeric: 226, name: "Equatorial Guinea" },
+    CountryInfo { country: Country::ET, alpha2: "ET", alpha3: "ETH", numeric: 231, name: "Ethiopia" },
+    CountryInfo { country: Country::ER, alpha2: "ER", alpha3: "ERI", numeric: 232, name: "Eritrea" },
+    CountryInfo { country: Country::EE, alpha2: "EE", alpha3: "EST", numeric: 233, name: "Estonia" },
+    CountryInfo { country: Country::FO, alpha2: "FO", alpha3: "FRO", numeric: 234, name: "Faroe Islands" },
+    CountryInfo { country: Country::FK, alpha2: "FK", alpha3: "FLK", numeric: 238, name: "Falkland Islands" },
+    CountryInfo { country: Country::GS, alpha2: "GS", alpha3: "SGS", numeric: 239, name: "South Georgia and the South Sandwich Islands" },
+    CountryInfo { country: Country::FJ, alpha2: "FJ", alpha3: "FJI", numeric: 242, name: "Fiji" },
+    CountryInfo { country: Country::FI, alpha2: "FI", alpha3: "FIN", numeric: 246, name: "Finland" },
+    CountryInfo { country: Country::AX, alpha2: "AX", alpha3: "ALA", numeric: 248, name: "Åland Islands" },
+    CountryInfo { country: Country::FR, alpha2: "FR", alpha3: "FRA", numeric: 250, name: "France" },
+    CountryInfo { country: Country::GF, alpha2: "GF", alpha3: "GUF", numeric: 254, name: "French Guiana" },
+    CountryInfo { country: Country::PF, alpha2: "PF", alpha3: "PYF", numeric: 258, name: "French Polynesia" },
+    CountryInfo { country: Country::TF, alpha2: "TF", alpha3: "ATF", numeric: 260, name: "French Southern Territories" },
+    CountryInfo { country: Country::DJ, alpha2: "DJ", alpha3: "DJI", numeric: 262, name: "Djibouti" },
+    CountryInfo { country: Country::GA, alpha2: "GA", alpha3: "GAB", numeric: 266, name: "Gabon" },
+    CountryInfo { country: Country::GE, alpha2: "GE", alpha3: "GEO", numeric: 268, name: "Georgia" },
+    CountryInfo { country: Country::GM, alpha2: "GM", alpha3: "GMB", numeric: 270, name: "Gambia" },
+    CountryInfo { country: Country::PS, alpha2: "PS", alpha3: "PSE", numeric: 275, name: "Palestine, State of" },
+    CountryInfo { country: Country::DE, alpha2: "DE", alpha3: "DEU", numeric: 276, name: "Germany" },
+    CountryInfo { country: Country::GH, alpha2: "GH", alpha3: "GHA", numeric: 288, name: "Ghana" },
+    CountryInfo { country: Country::GI, alpha2: "GI", alpha3: "GIB", numeric: 292, name: "Gibraltar" },
+    CountryInfo { country: Country::KI, alpha2: "KI", alpha3: "KIR", numeric: 296, name: "Kiribati" },
+    CountryInfo { country: Country::GR, alpha2: "GR", alpha3: "GRC", numeric: 300, name: "Greece" },
+    CountryInfo { country: Country::GL, alpha2: "GL", alpha3: "GRL", numeric: 304, name: "Greenland" },
+    CountryInfo { country: Country::GD, alpha2: "GD", alpha3: "GRD", numeric: 308, name: "Grenada" },
+    CountryInfo { country: Country::GP, alpha2: "GP", alpha3: "GLP", numeric: 312, name: "Guadeloupe" },
+    CountryInfo { country: Country::GU, alpha2: "GU", alpha3: "GUM", numeric: 316, name: "Guam" },
+    CountryInfo { country: Country::GT, alpha2: "GT", alpha3: "GTM", numeric: 320, name: "Guatemala" },
+    CountryInfo { country: Country::GN, alpha2: "GN", alpha3: "GIN", numeric: 324, name: "Guinea" },
+    CountryInfo { country: Country::GY, alpha2: "GY", alpha3: "GUY", numeric: 328, name: "Guyana" },
+    CountryInfo { country: Country::HT, alpha2: "HT", alpha3: "HTI", numeric: 332, name: "Haiti" },
+    CountryInfo { country: Country::HM, alpha2: "HM", alpha3: "HMD", numeric: 334, name: "Heard Island and McDonald Islands" },
+    CountryInfo { country: Country::VA, alpha2: "VA", alpha3: "VAT", numeric: 336, name: "Holy See" },
+    CountryInfo { country: Country::HN, alpha2: "HN", alpha3: "HND", numeric: 340, name: "Honduras" },
+    CountryInfo { country: Country::HK, alpha2: "HK", alpha3: "HKG", numeric: 344, name: "Hong Kong" },
+    CountryInfo { country: Country::HU, alpha2: "HU", alpha3: "HUN", numeric: 348, name: "Hungary" },
+    CountryInfo { country: Country::IS, alpha2: "IS", alpha3: "ISL", numeric: 352, name: "Iceland" },
+    CountryInfo { country: Country::IN, alpha2: "IN", alpha3: "IND", numeric: 356, name: "India" },
+    CountryInfo { country: Country::ID, alpha2: "ID", alpha3: "IDN", numeric: 360, name: "Indonesia" },
+    CountryInfo { country: Country::IR, alpha2: "IR", alpha3: "IRN", numeric: 364, name: "Iran (Islamic Republic of)" },
+    CountryInfo { country: Country::IQ, alpha2: "IQ", alpha3: "IRQ", numeric: 368, name: "Iraq" },
+    CountryInfo { country: Country::IE, alpha2: "IE", alpha3: "IRL", numeric: 372, name: "Ireland" },
+    CountryInfo { country: Country::IL, alpha2: "IL", alpha3: "ISR", numeric: 376, name: "Israel" },
+    CountryInfo { country: Country::IT, alpha2: "IT", alpha3: "ITA", numeric: 380, name: "Italy" },
+    CountryInfo { country: Country::CI, alpha2: "CI", alpha3: "CIV", numeric: 384, name: "Côte d'Ivoire" },
+    CountryInfo { country: Country::JM, alpha2: "JM", alpha3: "JAM", numeric: 388, name: "Jamaica" },
+    CountryInfo { country: Country::JP, alpha2: "JP", alpha3: "JPN", numeric: 392, name: "Japan" },
+    CountryInfo { country: Country::KZ, alpha2: "KZ", alpha3: "KAZ", numeric: 398, name: "Kazakhstan" },
+    CountryInfo { country: Country::JO, alpha2: "JO", alpha3: "JOR", numeric: 400, name: "Jordan" },
+    CountryInfo { country: Country::KE, alpha2: "KE", alpha3: "KEN", numeric: 404, name: "Kenya" },
+    CountryInfo { country: Country::KP, alpha2: "KP", alpha3: "PRK", numeric: 408, name: "Korea (Democratic People's Republic of)" },
+    CountryInfo { country: Country::KR, alpha2: "KR", alpha3: "KOR", numeric: 410, name: "Korea (Republic of)" },
+    CountryInfo { country: Country::KW, alpha2: "KW", alpha3: "KWT", numeric: 414, name: "Kuwait" },
+    CountryInfo { country: Country::KG, alpha2: "KG", alpha3: "KGZ", numeric: 417, name: "Kyrgyzstan" },
+    CountryInfo { country: Country::LA, alpha2: "LA", alpha3: "LAO", numeric: 418, name: "Lao People's Democratic Republic" },
+    CountryInfo { country: Country::LB, alpha2: "LB", alpha3: "LBN", numeric: 422, name: "Lebanon" },
+    CountryInfo { country: Country::LS, alpha2: "LS", alpha3: "LSO", numeric: 426, name: "Lesotho" },
+    CountryInfo { country: Country::LV, alpha2: "LV", alpha3: "LVA", numeric: 428, name: "Latvia" },
+    CountryInfo { country: Country::LR, alpha2: "LR", alpha3: "LBR", numeric: 430, name: "Liberia" },
+    CountryInfo { country: Country::LY, alpha2: "LY", alpha3: "LBY", numeric: 434, name: "Libya" },
+    CountryInfo { country: Country::LI, alpha2: "LI", alpha3: "LIE", numeric: 438, name: "Liechtenstein" },
+    CountryInfo { country: Country::LT, alpha2: "LT", alpha3: "LTU", numeric: 440, name: "Lithuania" },
+    CountryInfo { country: Country::LU, alpha2: "LU", alpha3: "LUX", numeric: 442, name: "Luxembourg" },
+    CountryInfo { country: Country::MO, alpha2: "MO", alpha3: "MAC", numeric: 446, name: "Macao" },
+    CountryInfo { country: Country::MG, alpha2: "MG", alpha3: "MDG", numeric: 450, name: "Madagascar" },
+    CountryInfo { country: Country::MW, alpha2: "MW", alpha3: "MWI", numeric: 454, name: "Malawi" },
+    CountryInfo { country: Country::MY, alpha2: "MY", alpha3: "MYS", numeric: 458, name: "Malaysia" },
+    CountryInfo { country: Country::MV, alpha2: "MV", alpha3: "MDV", numeric: 462, name: "Maldives" },
+    CountryInfo { country: Country::ML, alpha2: "ML", alpha3: "MLI", numeric: 466, name: "Mali" },
+    CountryInfo { country: Country::MT, alpha2: "MT", alpha3: "MLT", numeric: 470, name: "Malta" },
+    CountryInfo { country: Country::MQ, alpha2: "MQ", alpha3: "MTQ", numeric: 474, name: "Martinique" },
+    CountryInfo { country: Country::MR, alpha2: "MR", alpha3: "MRT", numeric: 478, name: "Mauritania" },
+    CountryInfo { country: Country::MU, alpha2: "MU", alpha3: "MUS", numeric: 480, name: "Mauritius" },
+    CountryInfo { country: Country::MX, alpha2: "MX", alpha3: "MEX", numeric: 484, name: "Mexico" },
+    CountryInfo { country: Country::MC, alpha2: "MC", alpha3: "MCO", numeric: 492, name: "Monaco" },
+    CountryInfo { country: Country::MN, alpha2: "MN", alpha3: "MNG", numeric: 496, name: "Mongolia" },
+    CountryInfo { country: Country::MD, alpha2: "MD", alpha3: "MDA", numeric: 498, name: "Moldova (Republic of)" },
+    CountryInfo { country: Country::ME, alpha2: "ME", alpha3: "MNE", numeric: 499, name: "Montenegro" },
+    CountryInfo { country: Country::MS, alpha2: "MS", alpha3: "MSR", numeric: 500, name: "Montserrat" },
+    CountryInfo { country: Country::MA, alpha2: "MA", alpha3: "MAR", numeric: 504, name: "Morocco" },
+    CountryInfo { country: Country::MZ, alpha2: "MZ", alpha3: "MOZ", numeric: 508, name: "Mozambique" },
+    CountryInfo { country: Country::OM, alpha2: "OM", alpha3: "OMN", numeric: 512, name: "Oman" },
+    CountryInfo { country: Country::NA, alpha2: "NA", alpha3: "NAM", numeric: 516, name: "Namibia" },
+    CountryInfo { country: Country::NR, alpha2: "NR", alpha3: "NRU", numeric: 520, name: "Nauru" },
+    CountryInfo { country: Country::NP, alpha2: "NP", alpha3: "NPL", numeric: 524, name: "Nepal" },
+    CountryInfo { country: Country::NL, alpha2: "NL", alpha3: "NLD", numeric: 528, name: "Netherlands" },
+    CountryInfo { country: Country::CW, alpha2: "CW", alpha3: "CUW", numeric: 531, name: "Curaçao" },
+    CountryInfo { country: Country::AW, alpha2: "AW", alpha3: "ABW", numeric: 533, name: "Aruba" },
+    CountryInfo { country: Country::SX, alpha2: "SX", alpha3: "SXM", numeric: 534, name: "Sint Maarten (Dutch part)" },
+    CountryInfo { country: Country::BQ, alpha2: "BQ", alpha3: "BES", numeric: 535, name: "Bonaire, Sint Eustatius and Saba" },
+    CountryInfo { country: Country::NC, alpha2: "NC", alpha3: "NCL", numeric: 540, name: "New Caledonia" },
+    CountryInfo { country: Country::VU, alpha2: "VU", alpha3: "VUT", numeric: 548, name: "Vanuatu" },
+    CountryInfo { country: Country::NZ, alpha2: "NZ", alpha3: "NZL", numeric: 554, name: "New Zealand" },
+    CountryInfo { country: Country::NI, alpha2: "NI", alpha3: "NIC", numeric: 558, name: "Nicaragua" },
+    CountryInfo { country: Country::NE, alpha2: "NE", alpha3: "NER", numeric: 562, name: "Niger" },
+    CountryInfo { country: Country::NG, alpha2: "NG", alpha3: "NGA", numeric: 566, name: "Nigeria" },
+    CountryInfo { country: Country::NU, alpha2: "NU", alpha3: "NIU", numeric: 570, name: "Niue" },
+    CountryInfo { country: Country::NF, alpha2: "NF", alpha3: "NFK", numeric: 574, name: "Norfolk Island" },
+    CountryInfo { country: Country::NO, alpha2: "NO", alpha3: "NOR", numeric: 578, name: "Norway" },
+    CountryInfo { country: Country::MP, alpha2: "MP", alpha3: "MNP", numeric: 580, name: "Northern Mariana Islands" },
+    CountryInfo { country: Country::UM, alpha2: "UM", alpha3: "UMI", numeric: 581, name: "United States Minor Outlying Islands" },
+    CountryInfo { country: Country::FM, alpha2: "FM", alpha3: "FSM", numeric: 583, name: "Micronesia (Federated States of)" },
+    CountryInfo { country: Country::MH, alpha2: "MH", alpha3: "MHL", numeric: 584, name: "Marshall Islands" },
+    CountryInfo { country: Country::PW, alpha2: "PW", alpha3: "PLW", numeric: 585, name: "Palau" },
+    CountryInfo { country: Country::PK, alpha2: "PK", alpha3: "PAK", numeric: 586, name: "Pakistan" },
+    CountryInfo { country: Country::PA, alpha2: "PA", alpha3: "PAN", numeric: 591, name: "Panama" },
+    CountryInfo { country: Country::PG, alpha2: "PG", alpha3: "PNG", numeric: 598, name: "Papua New Guinea" },
+    CountryInfo { country: Country::PY, alpha2: "PY", alpha3: "PRY", numeric: 600, name: "Paraguay" },
+    CountryInfo { country: Country::PE, alpha2: "PE", alpha3: "PER", numeric: 604, name: "Peru" },
+    CountryInfo { country: Country::PH, alpha2: "PH", alpha3: "PHL", numeric: 608, name: "Philippines" },
+    CountryInfo { country: Country::PN, alpha2: "PN", alpha3: "PCN", numeric: 612, name: "Pitcairn" },
+    CountryInfo { country: Country::PL, alpha2: "PL", alpha3: "POL", numeric: 616, name: "Poland" },
+    CountryInfo { country: Country::PT, alpha2: "PT", alpha3: "PRT", numeric: 620, name: "Portugal" },
+    CountryInfo { country: Country::GW, alpha2: "GW", alpha3: "GNB", numeric: 624, name: "Guinea-Bissau" },
+    CountryInfo { country: Country::TL, alpha2: "TL", alpha3: "TLS", numeric: 626, name: "Timor-Leste" },
+    CountryInfo { country: Country::PR, alpha2: "PR", alpha3: "PRI", numeric: 630, name: "Puerto Rico" },
+    CountryInfo { country: Country::QA, alpha2: "QA", alpha3: "QAT", numeric: 634, name: "Qatar" },
+    CountryInfo { country: Country::RE, alpha2: "RE", alpha3: "REU", numeric: 638, name: "Réunion" },
+    CountryInfo { country: Country::RO, alpha2: "RO", alpha3: "ROU", numeric: 642, name: "Romania" },
+    CountryInfo { country: Country::RU, alpha2: "RU", alpha3: "RUS", numeric: 643, name: "Russian Federation" },
+    CountryInfo { country: Country::RW, alpha2: "RW", alpha3: "RWA", numeric: 646, name: "Rwanda" },
+    CountryInfo { country: Country::BL, alpha2: "BL", alpha3: "BLM", numeric: 652, name: "Saint Barthélemy" },
+    CountryInfo { country: Country::SH, alpha2: "SH", alpha3: "SHN", numeric: 654, name: "Saint Helena, Ascension and Tristan da Cunha" },
+    CountryInfo { country: Country::KN, alpha2: "KN", alpha3: "KNA", numeric: 659, name: "Saint Kitts and Nevis" },
+    CountryInfo { country: Country::AI, alpha2: "AI", alpha3: "AIA", numeric: 660, name: "Anguilla" },
+    CountryInfo { country: Country::LC, alpha2: "LC", alpha3: "LCA", numeric: 662, name: "Saint Lucia" },
+    CountryInfo { country: Country::MF, alpha2: "MF", alpha3: "MAF", numeric: 663, name: "Saint Martin (French part)" },
+    CountryInfo { country: Country::PM, alpha2: "PM", alpha3: "SPM", numeric: 666, name: "Saint Pierre and Miquelon" },
+    CountryInfo { country: Country::VC, alpha2: "VC", alpha3: "VCT", numeric: 670, name: "Saint Vincent and the Grenadines" },
+    CountryInfo { country: Country::SM, alpha2: "SM", alpha3: "SMR", numeric: 674, name: "San Marino" },
+    CountryInfo { country: Country::ST, alpha2: "ST", alpha3: "STP", numeric: 678, name: "Sao Tome and Principe" },
+    CountryInfo { country: Country::SA, alpha2: "SA", alpha3: "SAU", numeric: 682, name: "Saudi Arabia" },
+    CountryInfo { country: Country::SN, alpha2: "SN", alpha3: "SEN", numeric: 686, name: "Senegal" },
+    CountryInfo { country: Country::RS, alpha2: "RS", alpha3: "SRB", numeric: 688, name: "Serbia" },
+    CountryInfo { country: Country::SC, alpha2: "SC", alpha3: "SYC", numeric: 690, name: "Seychelles" },
+    CountryInfo { country: Country::SL, alpha2: "SL", alpha3: "SLE", numeric: 694, name: "Sierra Leone" },
+    CountryInfo { country: Country::SG, alpha2: "SG", alpha3: "SGP", numeric: 702, name: "Singapore" },
+    CountryInfo { country: Country::SK, alpha2: "SK", alpha3: "SVK", numeric: 703, name: "Slovakia" },
+    CountryInfo { country: Country::VN, alpha2: "VN", alpha3: "VNM", numeric: 704, name: "Viet Nam" },
+    CountryInfo { country: Country::SI, alpha2: "SI", alpha3: "SVN", numeric: 705, name: "Slovenia" },
+    CountryInfo { country: Country::SO, alpha2: "SO", alpha3: "SOM", numeric: 706, name: "Somalia" },
+    CountryInfo { country: Country::ZA, alpha2: "ZA", alpha3: "ZAF", numeric: 710, name: "South Africa" },
+    CountryInfo { country: Country::ZW, alpha2: "ZW", alpha3: "ZWE", numeric: 716, name: "Zimbabwe" },
+    CountryInfo { country: Country::ES, alpha2: "ES", alpha3: "ESP", numeric: 724, name: "Spain" },
+    CountryInfo { country: Country::SS, alpha2: "SS", alpha3: "SSD", numeric: 728, name: "South Sudan" },
+    CountryInfo { country: Country::SD, alpha2: "SD", alpha3: "SDN", numeric: 729, name: "Sudan" },
+    CountryInfo { country: Country::EH, alpha2: "EH", alpha3: "ESH", numeric: 732, name: "Western Sahara" },
+    CountryInfo { country: Country::SR, alpha2: "SR", alpha3: "SUR", numeric: 740, name: "Suriname" },
+    CountryInfo { country: Country::SJ, alpha2: "SJ", alpha3: "SJM", numeric: 744, name: "Svalbard and Jan Mayen" },
+    CountryInfo { country: Country::SZ, alpha2: "SZ", alpha3: "SWZ", numeric: 748, name: "Swaziland" },
+    CountryInfo { country: Country::SE, alpha2: "SE", alpha3: "SWE", numeric: 752, name: "Sweden" },
+    CountryInfo { country: Country::CH, alpha2: "CH", alpha3: "CHE", numeric: 756, name: "Switzerland" },
+    CountryInfo { country: Country::SY, alpha2: "SY", alpha3: "SYR", numeric: 760, name: "Syrian Arab Republic" },
+    CountryInfo { country: Country::TJ, alpha2: "TJ", alpha3: "TJK", numeric: 762, name: "Tajikistan" },
+    CountryInfo { country: Country::TH, alpha2: "TH", alpha3: "THA", numeric: 764, name: "Thailand" },
+    CountryInfo { country: Country::TG, alpha2: "TG", alpha3: "TGO", numeric: 768, name: "Togo" },
+    CountryInfo { country: Country::TK, alpha2: "TK", alpha3: "TKL", numeric: 772, name: "Tokelau" },
+    CountryInfo { country: Country::TO, alpha2: "TO", alpha3: "TON", numeric: 776, name: "Tonga" },
+    CountryInfo { country: Country::TT, alpha2: "TT", alpha3: "TTO", numeric: 780, name: "Trinidad and Tobago" },
+    CountryInfo { country: Country::AE, alpha2: "AE", alpha3: "ARE", numeric: 784, name: "United Arab Emirates" },
+    CountryInfo { country: Country::TN, alpha2: "TN", alpha3: "TUN", numeric: 788, name: "Tunisia" },
+    CountryInfo { country: Country::TR, alpha2: "TR", alpha3: "TUR", numeric: 792, name: "Turkey" },
+    CountryInfo { country: Country::TM, alpha2: "TM", alpha3: "TKM", numeric: 795, name: "Turkmenistan" },
+    CountryInfo { country: Country::TC, alpha2: "TC", alpha3: "TCA", numeric: 796, name: "Turks and Caicos Islands" },
+    CountryInfo { country: Country::TV, alpha2: "TV", alpha3: "TUV", numeric: 798, name: "Tuvalu" },
+    CountryInfo { country: Country::UG, alpha2: "UG", alpha3: "UGA", numeric: 800, name: "Uganda" },
+    CountryInfo { country: Country::UA, alpha2: "UA", alpha3: "UKR", numeric: 804, name: "Ukraine" },
+    CountryInfo { country: Country::MK, alpha2: "MK", alpha3: "MKD", numeric: 807, name: "Macedonia (the former Yugoslav Republic of)" },
+    CountryInfo { country: Country::EG, alpha2: "EG", alpha3: "EGY", numeric: 818, name: "Egypt" },
+    CountryInfo { country: Country::GB, alpha2: "GB", alpha3: "GBR", numeric: 826, name: "United Kingdom of Great Britain and Northern Ireland" },
+    CountryInfo { country: Country::GG, alpha2: "GG", alpha3: "GGY", numeric: 831, name: "Guernsey" },
+    CountryInfo { country: Country::JE, alpha2: "JE", alpha3: "JEY", numeric: 832, name: "Jersey" },
+    CountryInfo { country: Country::IM, alpha2: "IM", alpha3: "IMN", numeric: 833, name: "Isle of Man" },
+    CountryInfo { country: Country::TZ, alpha2: "TZ", alpha3: "TZA", numeric: 834, name: "Tanzania, United Republic of" },
+    CountryInfo { country: Country::US, alpha2: "US", alpha3: "USA", numeric: 840, name: "United States of America" },
+    CountryInfo { country: Country::VI, alpha2: "VI", alpha3: "VIR", numeric: 850, name: "Virgin Islands (U.S.)" },
+    CountryInfo { country: Country::BF, alpha2: "BF", alpha3: "BFA", numeric: 854, name: "Burkina Faso" },
+    CountryInfo { country: Country::UY, alpha2: "UY", alpha3: "URY", numeric: 858, name: "Uruguay" },
+    CountryInfo { country: Country::UZ, alpha2: "UZ", alpha3: "UZB", numeric: 860, name: "Uzbekistan" },
+    CountryInfo { country: Country::VE, alpha2: "VE", alpha3: "VEN", numeric: 862, name: "Venezuela (Bolivarian Republic of)" },
+    CountryInfo { country: Country::WF, alpha2: "WF", alpha3: "WLF", numeric: 876, name: "Wallis and Futuna" },
+    CountryInfo { country: Country::WS, alpha2: "WS", alpha3: "WSM", numeric: 882, name: "Samoa" },
+    CountryInfo { country: Country::YE, alpha2: "YE", alpha3: "YEM", numeric: 887, name: "Yemen" },
+    CountryInfo { country: Country::ZM, alpha2: "ZM", alpha3: "ZMB", numeric: 894, name: "Zambia" },
+];
+
+static UNSPECIFIED : CountryInfo = CountryInfo { country: Country::Unspecified, alpha2: "", alpha3: "", numeric: 0, name: "" };
+
+/// Positions in `COUNTRIES` by numeric code, `u8::MAX` for unused codes.
+static NUMERIC_INDEX : [u8; 1000] = {
+    let mut index = [u8::MAX; 1000];
+    let mut i = 0;
+    while i < COUNTRIES.len() {
+        index[COUNTRIES[i].numeric as usize] = i as u8;
+        i += 1;
+    }
+    index
+};
+
+pub(crate) const ALPHA3_SEARCH_TABLE : &[(&str, Country)] = &[
+    ("ABW", Country::AW),
+    ("AFG", Country::AF),
+    ("AGO", Country::AO),
+    ("AIA", Country::AI),
+    ("ALA", Country::AX),
+    ("ALB", Country::AL),
+    ("AND", Country::AD),
+    ("ARE", Country::AE),
+    ("ARG", Country::AR),
+    ("ARM", Country::AM),
+    ("ASM", Country::AS),
+    ("ATA", Country::AQ),
+    ("ATF", Country::TF),
+    ("ATG", Country::AG),
+    ("AUS", Country::AU),
+    ("AUT", Country::AT),
+    ("AZE", Country::AZ),
+    ("BDI", Country::BI),
+    ("BEL", Country::BE),
+    ("BEN", Country::BJ),
+    ("BES", Country::BQ),
+    ("BFA", Country::BF),
+    ("BGD", Country::BD),
+    ("BGR", Country::BG),
+    ("BHR", Country::BH),
+    ("BHS", Country::BS),
+    ("BIH", Country::BA),
+    ("BLM", Country::BL),
+    ("BLR", Country::BY),
+    ("BLZ", Country::BZ),
+    ("BMU", Country::BM),
+    ("BOL", Country::BO),
+    ("BRA", Country::BR),
+    ("BRB", Country::BB),
+    ("BRN", Country::BN),
+    ("BTN", Country::BT),
+    ("BVT", Country::BV),
+    ("BWA", Country::BW),
+    ("CAF", Country::CF),
+    ("CAN", Country::CA),
+    ("CCK", Country::CC),
+    ("CHE", Country::CH),
+    ("CHL", Country::CL),
+    ("CHN", Country::CN),
+    ("CIV", Country::CI),
+    ("CMR", Country::CM),
+    ("COD", Country::CD),
+    ("COG", Country::CG),
+    ("COK", Country::CK),
+    ("COL", Country::CO),
+    ("COM", Country::KM),
+    ("CPV", Country::CV),
+    ("CRI", Country::CR),
+    ("CUB", Country::CU),
+    ("CUW", Country::CW),
+    ("CXR", Country::CX),
+    ("CYM", Country::KY),
+    ("CYP", Country::CY),
+    ("CZE", Country::CZ),
+    ("DEU", Country::DE),
+    ("DJI", Country::DJ),
+    ("DMA", Country::DM),
+    ("DNK", Country::DK),
+    ("DOM", Country::DO),
+    ("DZA", Country::DZ),
+    ("ECU", Country::EC),
+    ("EGY", Country::EG),
+    ("ERI", Country::ER),
+    ("ESH", Country::EH),
+    ("ESP", Country::ES),
+    ("EST", Country::EE),
+    ("ETH", Country::ET),
+    ("FIN", Country::FI),
+    ("FJI", Country::FJ),
+    ("FLK", Country::FK),
+    ("FRA", Country::FR),
+    ("FRO", Country::FO),
+    ("FSM", Country::FM),
+    ("GAB", Country::GA),
+    ("GBR", Country::GB),
+    ("GEO", Country::GE),
+    ("GGY", Country::GG),
+    ("GHA", Country::GH),
+    ("GIB", Country::GI),
+    ("GIN", Country::GN),
+    ("GLP", Country::GP),
+    ("GMB", Country::GM),
+    ("GNB", Country::GW),
+    ("GNQ", Country::GQ),
+    ("GRC", Country::GR),
+    ("GRD", Country::GD),
+    ("GRL", Country::GL),
+    ("GTM", Country::GT),
+    ("GUF", Country::GF),
+    ("GUM", Country::GU),
+    ("GUY", Country::GY),
+    ("HKG", Country::HK),
+    ("HMD", Country::HM),
+    ("HND", Country::HN),
+    ("HRV", Country::HR),
+    ("HTI", Country::HT),
+    ("HUN", Country::HU),
+    ("IDN", Country::ID),
+    ("IMN", Country::IM),
+    ("IND", Country::IN),
+    ("IOT", Country::IO),
+    ("IRL", Country::IE),
+    ("IRN", Country::IR),
+    ("IRQ", Country::IQ),
+    ("ISL", Country::IS),
+    ("ISR", Country::IL),
+    ("ITA", Country::IT),
+    ("JAM", Country::JM),
+    ("JEY", Country::JE),
+    ("JOR", Country::JO),
+    ("JPN", Country::JP),
+    ("KAZ", Country::KZ),
+    ("KEN", Country::KE),
+    ("KGZ", Country::KG),
+    ("KHM", Country::KH),
+    ("KIR", Country::KI),
+    ("KNA", Country::KN),
+    ("KOR", Country::KR),
+    ("KWT", Country::KW),
+    ("LAO", Country::LA),
+    ("LBN", Country::LB),
+    ("LBR", Country::LR),
+    ("LBY", Country::LY),
+    ("LCA", Country::LC),
+    ("LIE", Country::LI),
+    ("LKA", Country::LK),
+    ("LSO", Country::LS),
+    ("LTU", Country::LT),
+    ("LUX", Country::LU),
+    ("LVA", Country::LV),
+    ("MAC", Country::MO),
+    ("MAF", Country::MF),
+    ("MAR", Country::MA),
+    ("MCO", Country::MC),
+    ("MDA", Country::MD),
+    ("MDG", Country::MG),
+    ("MDV", Country::MV),
+    ("MEX", Country::MX),
+    ("MHL", Country::MH),
+    ("MKD", Country::MK),
+    ("MLI", Country::ML),
+    ("MLT", Country::MT),
+    ("MMR", Country::MM),
+    ("MNE", Country::ME),
+    ("MNG", Country::MN),
+    ("MNP", Country::MP),
+    ("MOZ", Country::MZ),
+    ("MRT", Country::MR),
+    ("MSR", Country::MS),
+    ("MTQ", Country::MQ),
+    ("MUS", Country::MU),
+    ("MWI", Country::MW),
+    ("MYS", Country::MY),
+    ("MYT", Country::YT),
+    ("NAM", Country::NA),
+    ("NCL", Country::NC),
+    ("NER", Country::NE),
+    ("NFK", Country::NF),
+    ("NGA", Country::NG),
+    ("NIC", Country::NI),
+    ("NIU", Country::NU),
+    ("NLD", Country::NL),
+    ("NOR", Country::NO),
+    ("NPL", Country::NP),
+    ("NRU", Country::NR),
+    ("NZL", Country::NZ),
+    ("OMN", Country::OM),
+    ("PAK", Country::PK),
+    ("PAN", Country::PA),
+    ("PCN", Country::PN),
+    ("PER", Country::PE),
+    ("PHL", Country::PH),
+    ("PLW", Country::PW),
+    ("PNG", Country::PG),
+    ("POL", Country::PL),
+    ("PRI", Country::PR),
+    ("PRK", Country::KP),
+    ("PRT", Country::PT),
+    ("PRY", Country::PY),
+    ("PSE", Country::PS),
+    ("PYF", Country::PF),
+    ("QAT", Country::QA),
+    ("REU", Country::RE),
+    ("ROU", Country::RO),
+    ("RUS", Country::RU),
+    ("RWA", Country::RW),
+    ("SAU", Country::SA),
+    ("SDN", Country::SD),
+    ("SEN", Country::SN),
+    ("SGP", Country::SG),
+    ("SGS", Country::GS),
+    ("SHN", Country::SH),
+    ("SJM", Country::SJ),
+    ("SLB", Country::SB),
+    ("SLE", Country::SL),
+    ("SLV", Country::SV),
+    ("SMR", Country::SM),
+    ("SOM", Country::SO),
+    ("SPM", Country::PM),
+    ("SRB", Country::RS),
+    ("SSD", Country::SS),
+    ("STP", Country::ST),
+    ("SUR", Country::SR),
+    ("SVK", Country::SK),
+    ("SVN", Country::SI),
+    ("SWE", Country::SE),
+    ("SWZ", Country::SZ),
+    ("SXM", Country::SX),
+    ("SYC", Country::SC),
+    ("SYR", Country::SY),
+    ("TCA", Country::TC),
+    ("TCD", Country::TD),
+    ("TGO", Country::TG),
+    ("THA", Country::TH),
+    ("TJK", Country::TJ),
+    ("TKL", Country::TK),
+    ("TKM", Country::TM),
+    ("TLS", Country::TL),
+    ("TON", Country::TO),
+    ("TTO", Country::TT),
+    ("TUN", Country::TN),
+    ("TUR", Country::TR),
+    ("TUV", Country::TV),
+    ("TWN", Country::TW),
+    ("TZA", Country::TZ),
+    ("UGA", Country::UG),
+    ("UKR", Country::UA),
+    ("UMI", Country::UM),
+    ("URY", Country::UY),
+    ("USA", Country::US),
+    ("UZB", Country::UZ),
+    ("VAT", Country::VA),
+    ("VCT", Country::VC),
+    ("VEN", Country::VE),
+    ("VGB", Country::VG),
+    ("VIR", Country::VI),
+    ("VNM", Country::VN),
+    ("VUT", Country::VU),
+    ("WLF", Country::WF),
+    ("WSM", Country::WS),
+    ("YEM", Country::YE),
+    ("ZAF", Country::ZA),
+    ("ZMB", Country::ZM),
+    ("ZWE", Country::ZW),
+];
+
+/// The data of all countries.
+#[cfg(feature = "std")]
+#[deprecated(note = "use the static `data::COUNTRIES` or `Country::info()`")]
+pub fn all() -> Vec<CountryInfo> {
+    COUNTRIES.to_vec()
 }
 
-pub fn all<'a>() -> Vec<CountryCode<'a>> {
-    let mut codes: Vec<CountryCode> = vec![];
+impl Country {
+    /// The codes and name of this country.
+    pub fn info(&self) -> &'static CountryInfo {
+        match NUMERIC_INDEX[*self as usize] {
+            u8::MAX => &UNSPECIFIED,
+            i => &COUNTRIES[i as usize],
+        }
+    }
+
+    /// The ISO 3166-1 alpha-2 code, "PL".
+    pub fn alpha2(&self) -> &'static str {
+        self.info().alpha2
+    }
+
+    /// The ISO 3166-1 alpha-3 code, "POL".
+    pub fn alpha3(&self) -> &'static str {
+        self.info().alpha3
+    }
+
+    /// The ISO 3166-1 numeric code, 616.
+    pub fn numeric(&self) -> u16 {
+        *self as u16
+    }
+
+    pub fn from_alpha3(s: &str) -> Option<Country> {
+        ALPHA3_SEARCH_TABLE.binary_search_by(|&(o, _)| o.cmp(s))
+            .map(|pos| ALPHA3_SEARCH_TABLE[pos].1)
+            .ok()
+    }
+
+    pub fn from_numeric(n: u16) -> Option<Country> {
+        match NUMERIC_INDEX.get(n as usize) {
+            Some(&i) if i != u8::MAX => Some(COUNTRIES[i as usize].country),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+
+    #[test]
+    fn numeric_codes_match_the_enum() {
+        for code in super::COUNTRIES {
+            assert_eq!(code.country as u16, code.numeric, "{}", code.name);
+        }
+    }
+
+    #[test]
+    fn every_country_has_info() {
+        for &(alpha2, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            assert_eq!(country, country.info().country, "{}", alpha2);
+            assert_eq!(Some(country).filter(|&c| c != Country::Unspecified), Country::from_numeric(country.numeric()));
+            assert_eq!(Some(country).filter(|&c| c != Country::Unspecified), Country::from_alpha3(country.alpha3()));
+        }
+        assert_eq!("POL", Country::PL.alpha3());
+        assert_eq!(None, Country::from_numeric(999));
+        assert_eq!(None, Country::from_alpha3("XYZ"));
+    }
 
-    // Begin
-    codes.push(CountryCode {
-        alpha2: "AF",
-        alpha3: "AFG",
-        name: "Afghanistan",
-        num: "004",
-    });
-    codes.push(CountryCode {
-        alpha2: "AL",
-        alpha3: "ALB",
-        name: "Albania",
-        num: "008",
-    });
-    codes.push(CountryCode {
-        alpha2: "AQ",
-        alpha3: "ATA",
-        name: "Antarctica",
-        num: "010",
-    });
-    codes.push(CountryCode {
-        alpha2: "DZ",
-        alpha3: "DZA",
-        name: "Algeria",
-        num: "012",
-    });
-    codes.push(CountryCode {
-        alpha2: "AS",
-        alpha3: "ASM",
-        name: "American Samoa",
-        num: "016",
-    });
-    codes.push(CountryCode {
-        alpha2: "AD",
-        alpha3: "AND",
-        name: "Andorra",
-        num: "020",
-    });
-    codes.push(CountryCode {
-        alpha2: "AO",
-        alpha3: "AGO",
-        name: "Angola",
-        num: "024",
-    });
-    codes.push(CountryCode {
-        alpha2: "AG",
-        alpha3: "ATG",
-        name: "Antigua and Barbuda",
-        num: "028",
-    });
-    codes.push(CountryCode {
-        alpha2: "AZ",
-        alpha3: "AZE",
-        name: "Azerbaijan",
-        num: "031",
-    });
-    codes.push(CountryCode {
-        alpha2: "AR",
-        alpha3: "ARG",
-        name: "Argentina",
-        num: "032",
-    });
-    codes.push(CountryCode {
-        alpha2: "AU",
-        alpha3: "AUS",
-        name: "Australia",
-        num: "036",
-    });
-    codes.push(CountryCode {
-        alpha2: "AT",
-        alpha3: "AUT",
-        name: "Austria",
-        num: "040",
-    });
-    codes.push(CountryCode {
-        alpha2: "BS",
-        alpha3: "BHS",
-        name: "Bahamas",
-        num: "044",
-    });
-    codes.push(CountryCode {
-        alpha2: "BH",
-        alpha3: "BHR",
-        name: "Bahrain",
-        num: "048",
-    });
-    codes.push(CountryCode {
-        alpha2: "BD",
-        alpha3: "BGD",
-        name: "Bangladesh",
-        num: "050",
-    });
-    codes.push(CountryCode {
-        alpha2: "AM",
-        alpha3: "ARM",
-        name: "Armenia",
-        num: "051",
-    });
-    codes.push(CountryCode {
-        alpha2: "BB",
-        alpha3: "BRB",
-        name: "Barbados",
-        num: "052",
-    });
-    codes.push(CountryCode {
-        alpha2: "BE",
-        alpha3: "BEL",
-        name: "Belgium",
-        num: "056",
-    });
-    codes.push(CountryCode {
-        alpha2: "BM",
-        alpha3: "BMU",
-        name: "Bermuda",
-        num: "060",
-    });
-    codes.push(CountryCode {
-        alpha2: "BT",
-        alpha3: "BTN",
-        name: "Bhutan",
-        num: "064",
-    });
-    codes.push(CountryCode {
-        alpha2: "BO",
-        alpha3: "BOL",
-        name: "Bolivia (Plurinational State of)",
-        num: "068",
-    });
-    codes.push(CountryCode {
-        alpha2: "BA",
-        alpha3: "BIH",
-        name: "Bosnia and Herzegovina",
-        num: "070",
-    });
-    codes.push(CountryCode {
-        alpha2: "BW",
-        alpha3: "BWA",
-        name: "Botswana",
-        num: "072",
-    });
-    codes.push(CountryCode {
-        alpha2: "BV",
-        alpha3: "BVT",
-        name: "Bouvet Island",
-        num: "074",
-    });
-    codes.push(CountryCode {
-        alpha2: "BR",
-        alpha3: "BRA",
-        name: "Brazil",
-        num: "076",
-    });
-    codes.push(CountryCode {
-        alpha2: "BZ",
-        alpha3: "BLZ",
-        name: "Belize",
-        num: "084",
-    });
-    codes.push(CountryCode {
-        alpha2: "IO",
-        alpha3: "IOT",
-        name: "British Indian Ocean Territory",
-        num: "086",
-    });
-    codes.push(CountryCode {
-        alpha2: "SB",
-        alpha3: "SLB",
-        name: "Solomon Islands",
-        num: "090",
-    });
-    codes.push(CountryCode {
-        alpha2: "VG",
-        alpha3: "VGB",
-        name: "Virgin Islands (British)",
-        num: "092",
-    });
-    codes.push(CountryCode {
-        alpha2: "BN",
-        alpha3: "BRN",
-        name: "Brunei Darussalam",
-        num: "096",
-    });
-    codes.push(CountryCode {
-        alpha2: "BG",
-        alpha3: "BGR",
-        name: "Bulgaria",
-        num: "100",
-    });
-    codes.push(CountryCode {
-        alpha2: "MM",
-        alpha3: "MMR",
-        name: "Myanmar",
-        num: "104",
-    });
-    codes.push(CountryCode {
-        alpha2: "BI",
-        alpha3: "BDI",
-        name: "Burundi",
-        num: "108",
-    });
-    codes.push(CountryCode {
-        alpha2: "BY",
-        alpha3: "BLR",
-        name: "Belarus",
-        num: "112",
-    });
-    codes.push(CountryCode {
-        alpha2: "KH",
-        alpha3: "KHM",
-        name: "Cambodia",
-        num: "116",
-    });
-    codes.push(CountryCode {
-        alpha2: "CM",
-        alpha3: "CMR",
-        name: "Cameroon",
-        num: "120",
-    });
-    codes.push(CountryCode {
-        alpha2: "CA",
-        alpha3: "CAN",
-        name: "Canada",
-        num: "124",
-    });
-    codes.push(CountryCode {
-        alpha2: "CV",
-        alpha3: "CPV",
-        name: "Cabo Verde",
-        num: "132",
-    });
-    codes.push(CountryCode {
-        alpha2: "KY",
-        alpha3: "CYM",
-        name: "Cayman Islands",
-        num: "136",
-    });
-    codes.push(CountryCode {
-        alpha2: "CF",
-        alpha3: "CAF",
-        name: "Central African Republic",
-        num: "140",
-    });
-    codes.push(CountryCode {
-        alpha2: "LK",
-        alpha3: "LKA",
-        name: "Sri Lanka",
-        num: "144",
-    });
-    codes.push(CountryCode {
-        alpha2: "TD",
-        alpha3: "TCD",
-        name: "Chad",
-        num: "148",
-    });
-    codes.push(CountryCode {
-        alpha2: "CL",
-        alpha3: "CHL",
-        name: "Chile",
-        num: "152",
-    });
-    codes.push(CountryCode {
-        alpha2: "CN",
-        alpha3: "CHN",
-        name: "China",
-        num: "156",
-    });
-    codes.push(CountryCode {
-        alpha2: "TW",
-        alpha3: "TWN",
-        name: "Taiwan, Province of China[a]",
-        num: "158",
-    });
-    codes.push(CountryCode {
-        alpha2: "CX",
-        alpha3: "CXR",
-        name: "Christmas Island",
-        num: "162",
-    });
-    codes.push(CountryCode {
-        alpha2: "CC",
-        alpha3: "CCK",
-        name: "Cocos (Keeling) Islands",
-        num: "166",
-    });
-    codes.push(CountryCode {
-        alpha2: "CO",
-        alpha3: "COL",
-        name: "Colombia",
-        num: "170",
-    });
-    codes.push(CountryCode {
-        alpha2: "KM",
-        alpha3: "COM",
-        name: "Comoros",
-        num: "174",
-    });
-    codes.push(CountryCode {
-        alpha2: "YT",
-        alpha3: "MYT",
-        name: "Mayotte",
-        num: "175",
-    });
-    codes.push(CountryCode {
-        alpha2: "CG",
-        alpha3: "COG",
-        name: "Congo",
-        num: "178",
-    });
-    codes.push(CountryCode {
-        alpha2: "CD",
-        alpha3: "COD",
-        name: "Congo (Democratic Republic of the)",
-        num: "180",
-    });
-    codes.push(CountryCode {
-        alpha2: "CK",
-        alpha3: "COK",
-        name: "Cook Islands",
-        num: "184",
-    });
-    codes.push(CountryCode {
-        alpha2: "CR",
-        alpha3: "CRI",
-        name: "Costa Rica",
-        num: "188",
-    });
-    codes.push(CountryCode {
-        alpha2: "HR",
-        alpha3: "HRV",
-        name: "Croatia",
-        num: "191",
-    });
-    codes.push(CountryCode {
-        alpha2: "CU",
-        alpha3: "CUB",
-        name: "Cuba",
-        num: "192",
-    });
-    codes.push(CountryCode {
-        alpha2: "CY",
-        alpha3: "CYP",
-        name: "Cyprus",
-        num: "196",
-    });
-    codes.push(CountryCode {
-        alpha2: "CZ",
-        alpha3: "CZE",
-        name: "Czech Republic",
-        num: "203",
-    });
-    codes.push(CountryCode {
-        alpha2: "BJ",
-        alpha3: "BEN",
-        name: "Benin",
-        num: "204",
-    });
-    codes.push(CountryCode {
-        alpha2: "DK",
-        alpha3: "DNK",
-        name: "Denmark",
-        num: "208",
-    });
-    codes.push(CountryCode {
-        alpha2: "DM",
-        alpha3: "DMA",
-        name: "Dominica",
-        num: "212",
-    });
-    codes.push(CountryCode {
-        alpha2: "DO",
-        alpha3: "DOM",
-        name: "Dominican Republic",
-        num: "214",
-    });
-    codes.push(CountryCode {
-        alpha2: "EC",
-        alpha3: "ECU",
-        name: "Ecuador",
-        num: "218",
-    });
-    codes.push(CountryCode {
-        alpha2: "SV",
-        alpha3: "SLV",
-        name: "El Salvador",
-        num: "222",
-    });
-    codes.push(CountryCode {
-        alpha2: "GQ",
-        alpha3: "GNQ",
-        name: "Equatorial Guinea",
-        num: "226",
-    });
-    codes.push(CountryCode {
-        alpha2: "ET",
-        alpha3: "ETH",
-        name: "Ethiopia",
-        num: "231",
-    });
-    codes.push(CountryCode {
-        alpha2: "ER",
-        alpha3: "ERI",
-        name: "Eritrea",
-        num: "232",
-    });
-    codes.push(CountryCode {
-        alpha2: "EE",
-        alpha3: "EST",
-        name: "Estonia",
-        num: "233",
-    });
-    codes.push(CountryCode {
-        alpha2: "FO",
-        alpha3: "FRO",
-        name: "Faroe Islands",
-        num: "234",
-    });
-    codes.push(CountryCode {
-        alpha2: "FK",
-        alpha3: "FLK",
-        name: "Falkland Islands",
-        num: "238",
-    });
-    codes.push(CountryCode {
-        alpha2: "GS",
-        alpha3: "SGS",
-        name: "South Georgia and the South Sandwich Islands",
-        num: "239",
-    });
-    codes.push(CountryCode {
-        alpha2: "FJ",
-        alpha3: "FJI",
-        name: "Fiji",
-        num: "242",
-    });
-    codes.push(CountryCode {
-        alpha2: "FI",
-        alpha3: "FIN",
-        name: "Finland",
-        num: "246",
-    });
-    codes.push(CountryCode {
-        alpha2: "AX",
-        alpha3: "ALA",
-        name: "Åland Islands",
-        num: "248",
-    });
-    codes.push(CountryCode {
-        alpha2: "FR",
-        alpha3: "FRA",
-        name: "France",
-        num: "250",
-    });
-    codes.push(CountryCode {
-        alpha2: "GF",
-        alpha3: "GUF",
-        name: "French Guiana",
-        num: "254",
-    });
-    codes.push(CountryCode {
-        alpha2: "PF",
-        alpha3: "PYF",
-        name: "French Polynesia",
-        num: "258",
-    });
-    codes.push(CountryCode {
-        alpha2: "TF",
-        alpha3: "ATF",
-        name: "French Southern Territories",
-        num: "260",
-    });
-    codes.push(CountryCode {
-        alpha2: "DJ",
-        alpha3: "DJI",
-        name: "Djibouti",
-        num: "262",
-    });
-    codes.push(CountryCode {
-        alpha2: "GA",
-        alpha3: "GAB",
-        name: "Gabon",
-        num: "266",
-    });
-    codes.push(CountryCode {
-        alpha2: "GE",
-        alpha3: "GEO",
-        name: "Georgia",
-        num: "268",
-    });
-    codes.push(CountryCode {
-        alpha2: "GM",
-        alpha3: "GMB",
-        name: "Gambia",
-        num: "270",
-    });
-    codes.push(CountryCode {
-        alpha2: "PS",
-        alpha3: "PSE",
-        name: "Palestine, State of",
-        num: "275",
-    });
-    codes.push(CountryCode {
-        alpha2: "DE",
-        alpha3: "DEU",
-        name: "Germany",
-        num: "276",
-    });
-    codes.push(CountryCode {
-        alpha2: "GH",
-        alpha3: "GHA",
-        name: "Ghana",
-        num: "288",
-    });
-    codes.push(CountryCode {
-        alpha2: "GI",
-        alpha3: "GIB",
-        name: "Gibraltar",
-        num: "292",
-    });
-    codes.push(CountryCode {
-        alpha2: "KI",
-        alpha3: "KIR",
-        name: "Kiribati",
-        num: "296",
-    });
-    codes.push(CountryCode {
-        alpha2: "GR",
-        alpha3: "GRC",
-        name: "Greece",
-        num: "300",
-    });
-    codes.push(CountryCode {
-        alpha2: "GL",
-        alpha3: "GRL",
-        name: "Greenland",
-        num: "304",
-    });
-    codes.push(CountryCode {
-        alpha2: "GD",
-        alpha3: "GRD",
-        name: "Grenada",
-        num: "308",
-    });
-    codes.push(CountryCode {
-        alpha2: "GP",
-        alpha3: "GLP",
-        name: "Guadeloupe",
-        num: "312",
-    });
-    codes.push(CountryCode {
-        alpha2: "GU",
-        alpha3: "GUM",
-        name: "Guam",
-        num: "316",
-    });
-    codes.push(CountryCode {
-        alpha2: "GT",
-        alpha3: "GTM",
-        name: "Guatemala",
-        num: "320",
-    });
-    codes.push(CountryCode {
-        alpha2: "GN",
-        alpha3: "GIN",
-        name: "Guinea",
-        num: "324",
-    });
-    codes.push(CountryCode {
-        alpha2: "GY",
-        alpha3: "GUY",
-        name: "Guyana",
-        num: "328",
-    });
-    codes.push(CountryCode {
-        alpha2: "HT",
-        alpha3: "HTI",
-        name: "Haiti",
-        num: "332",
-    });
-    codes.push(CountryCode {
-        alpha2: "HM",
-        alpha3: "HMD",
-        name: "Heard Island and McDonald Islands",
-        num: "334",
-    });
-    codes.push(CountryCode {
-        alpha2: "VA",
-        alpha3: "VAT",
-        name: "Holy See",
-        num: "336",
-    });
-    codes.push(CountryCode {
-        alpha2: "HN",
-        alpha3: "HND",
-        name: "Honduras",
-        num: "340",
-    });
-    codes.push(CountryCode {
-        alpha2: "HK",
-        alpha3: "HKG",
-        name: "Hong Kong",
-        num: "344",
-    });
-    codes.push(CountryCode {
-        alpha2: "HU",
-        alpha3: "HUN",
-        name: "Hungary",
-        num: "348",
-    });
-    codes.push(CountryCode {
-        alpha2: "IS",
-        alpha3: "ISL",
-        name: "Iceland",
-        num: "352",
-    });
-    codes.push(CountryCode {
-        alpha2: "IN",
-        alpha3: "IND",
-        name: "India",
-        num: "356",
-    });
-    codes.push(CountryCode {
-        alpha2: "ID",
-        alpha3: "IDN",
-        name: "Indonesia",
-        num: "360",
-    });
-    codes.push(CountryCode {
-        alpha2: "IR",
-        alpha3: "IRN",
-        name: "Iran (Islamic Republic of)",
-        num: "364",
-    });
-    codes.push(CountryCode {
-        alpha2: "IQ",
-        alpha3: "IRQ",
-        name: "Iraq",
-        num: "368",
-    });
-    codes.push(CountryCode {
-        alpha2: "IE",
-        alpha3: "IRL",
-        name: "Ireland",
-        num: "372",
-    });
-    codes.push(CountryCode {
-        alpha2: "IL",
-        alpha3: "ISR",
-        name: "Israel",
-        num: "376",
-    });
-    codes.push(CountryCode {
-        alpha2: "IT",
-        alpha3: "ITA",
-        name: "Italy",
-        num: "380",
-    });
-    codes.push(CountryCode {
-        alpha2: "CI",
-        alpha3: "CIV",
-        name: "Côte d'Ivoire",
-        num: "384",
-    });
-    codes.push(CountryCode {
-        alpha2: "JM",
-        alpha3: "JAM",
-        name: "Jamaica",
-        num: "388",
-    });
-    codes.push(CountryCode {
-        alpha2: "JP",
-        alpha3: "JPN",
-        name: "Japan",
-        num: "392",
-    });
-    codes.push(CountryCode {
-        alpha2: "KZ",
-        alpha3: "KAZ",
-        name: "Kazakhstan",
-        num: "398",
-    });
-    codes.push(CountryCode {
-        alpha2: "JO",
-        alpha3: "JOR",
-        name: "Jordan",
-        num: "400",
-    });
-    codes.push(CountryCode {
-        alpha2: "KE",
-        alpha3: "KEN",
-        name: "Kenya",
-        num: "404",
-    });
-    codes.push(CountryCode {
-        alpha2: "KP",
-        alpha3: "PRK",
-        name: "Korea (Democratic People's Republic of)",
-        num: "408",
-    });
-    codes.push(CountryCode {
-        alpha2: "KR",
-        alpha3: "KOR",
-        name: "Korea (Republic of)",
-        num: "410",
-    });
-    codes.push(CountryCode {
-        alpha2: "KW",
-        alpha3: "KWT",
-        name: "Kuwait",
-        num: "414",
-    });
-    codes.push(CountryCode {
-        alpha2: "KG",
-        alpha3: "KGZ",
-        name: "Kyrgyzstan",
-        num: "417",
-    });
-    codes.push(CountryCode {
-        alpha2: "LA",
-        alpha3: "LAO",
-        name: "Lao People's Democratic Republic",
-        num: "418",
-    });
-    codes.push(CountryCode {
-        alpha2: "LB",
-        alpha3: "LBN",
-        name: "Lebanon",
-        num: "422",
-    });
-    codes.push(CountryCode {
-        alpha2: "LS",
-        alpha3: "LSO",
-        name: "Lesotho",
-        num: "426",
-    });
-    codes.push(CountryCode {
-        alpha2: "LV",
-        alpha3: "LVA",
-        name: "Latvia",
-        num: "428",
-    });
-    codes.push(CountryCode {
-        alpha2: "LR",
-        alpha3: "LBR",
-        name: "Liberia",
-        num: "430",
-    });
-    codes.push(CountryCode {
-        alpha2: "LY",
-        alpha3: "LBY",
-        name: "Libya",
-        num: "434",
-    });
-    codes.push(CountryCode {
-        alpha2: "LI",
-        alpha3: "LIE",
-        name: "Liechtenstein",
-        num: "438",
-    });
-    codes.push(CountryCode {
-        alpha2: "LT",
-        alpha3: "LTU",
-        name: "Lithuania",
-        num: "440",
-    });
-    codes.push(CountryCode {
-        alpha2: "LU",
-        alpha3: "LUX",
-        name: "Luxembourg",
-        num: "442",
-    });
-    codes.push(CountryCode {
-        alpha2: "MO",
-        alpha3: "MAC",
-        name: "Macao",
-        num: "446",
-    });
-    codes.push(CountryCode {
-        alpha2: "MG",
-        alpha3: "MDG",
-        name: "Madagascar",
-        num: "450",
-    });
-    codes.push(CountryCode {
-        alpha2: "MW",
-        alpha3: "MWI",
-        name: "Malawi",
-        num: "454",
-    });
-    codes.push(CountryCode {
-        alpha2: "MY",
-        alpha3: "MYS",
-        name: "Malaysia",
-        num: "458",
-    });
-    codes.push(CountryCode {
-        alpha2: "MV",
-        alpha3: "MDV",
-        name: "Maldives",
-        num: "462",
-    });
-    codes.push(CountryCode {
-        alpha2: "ML",
-        alpha3: "MLI",
-        name: "Mali",
-        num: "466",
-    });
-    codes.push(CountryCode {
-        alpha2: "MT",
-        alpha3: "MLT",
-        name: "Malta",
-        num: "470",
-    });
-    codes.push(CountryCode {
-        alpha2: "MQ",
-        alpha3: "MTQ",
-        name: "Martinique",
-        num: "474",
-    });
-    codes.push(CountryCode {
-        alpha2: "MR",
-        alpha3: "MRT",
-        name: "Mauritania",
-        num: "478",
-    });
-    codes.push(CountryCode {
-        alpha2: "MU",
-        alpha3: "MUS",
-        name: "Mauritius",
-        num: "480",
-    });
-    codes.push(CountryCode {
-        alpha2: "MX",
-        alpha3: "MEX",
-        name: "Mexico",
-        num: "484",
-    });
-    codes.push(CountryCode {
-        alpha2: "MC",
-        alpha3: "MCO",
-        name: "Monaco",
-        num: "492",
-    });
-    codes.push(CountryCode {
-        alpha2: "MN",
-        alpha3: "MNG",
-        name: "Mongolia",
-        num: "496",
-    });
-    codes.push(CountryCode {
-        alpha2: "MD",
-        alpha3: "MDA",
-        name: "Moldova (Republic of)",
-        num: "498",
-    });
-    codes.push(CountryCode {
-        alpha2: "ME",
-        alpha3: "MNE",
-        name: "Montenegro",
-        num: "499",
-    });
-    codes.push(CountryCode {
-        alpha2: "MS",
-        alpha3: "MSR",
-        name: "Montserrat",
-        num: "500",
-    });
-    codes.push(CountryCode {
-        alpha2: "MA",
-        alpha3: "MAR",
-        name: "Morocco",
-        num: "504",
-    });
-    codes.push(CountryCode {
-        alpha2: "MZ",
-        alpha3: "MOZ",
-        name: "Mozambique",
-        num: "508",
-    });
-    codes.push(CountryCode {
-        alpha2: "OM",
-        alpha3: "OMN",
-        name: "Oman",
-        num: "512",
-    });
-    codes.push(CountryCode {
-        alpha2: "NA",
-        alpha3: "NAM",
-        name: "Namibia",
-        num: "516",
-    });
-    codes.push(CountryCode {
-        alpha2: "NR",
-        alpha3: "NRU",
-        name: "Nauru",
-        num: "520",
-    });
-    codes.push(CountryCode {
-        alpha2: "NP",
-        alpha3: "NPL",
-        name: "Nepal",
-        num: "524",
-    });
-    codes.push(CountryCode {
-        alpha2: "NL",
-        alpha3: "NLD",
-        name: "Netherlands",
-        num: "528",
-    });
-    codes.push(CountryCode {
-        alpha2: "CW",
-        alpha3: "CUW",
-        name: "Curaçao",
-        num: "531",
-    });
-    codes.push(CountryCode {
-        alpha2: "AW",
-        alpha3: "ABW",
-        name: "Aruba",
-        num: "533",
-    });
-    codes.push(CountryCode {
-        alpha2: "SX",
-        alpha3: "SXM",
-        name: "Sint Maarten (Dutch part)",
-        num: "534",
-    });
-    codes.push(CountryCode {
-        alpha2: "BQ",
-        alpha3: "BES",
-        name: "Bonaire, Sint Eustatius and Saba",
-        num: "535",
-    });
-    codes.push(CountryCode {
-        alpha2: "NC",
-        alpha3: "NCL",
-        name: "New Caledonia",
-        num: "540",
-    });
-    codes.push(CountryCode {
-        alpha2: "VU",
-        alpha3: "VUT",
-        name: "Vanuatu",
-        num: "548",
-    });
-    codes.push(CountryCode {
-        alpha2: "NZ",
-        alpha3: "NZL",
-        name: "New Zealand",
-        num: "554",
-    });
-    codes.push(CountryCode {
-        alpha2: "NI",
-        alpha3: "NIC",
-        name: "Nicaragua",
-        num: "558",
-    });
-    codes.push(CountryCode {
-        alpha2: "NE",
-        alpha3: "NER",
-        name: "Niger",
-        num: "562",
-    });
-    codes.push(CountryCode {
-        alpha2: "NG",
-        alpha3: "NGA",
-        name: "Nigeria",
-        num: "566",
-    });
-    codes.push(CountryCode {
-        alpha2: "NU",
-        alpha3: "NIU",
-        name: "Niue",
-        num: "570",
-    });
-    codes.push(CountryCode {
-        alpha2: "NF",
-        alpha3: "NFK",
-        name: "Norfolk Island",
-        num: "574",
-    });
-    codes.push(CountryCode {
-        alpha2: "NO",
-        alpha3: "NOR",
-        name: "Norway",
-        num: "578",
-    });
-    codes.push(CountryCode {
-        alpha2: "MP",
-        alpha3: "MNP",
-        name: "Northern Mariana Islands",
-        num: "580",
-    });
-    codes.push(CountryCode {
-        alpha2: "UM",
-        alpha3: "UMI",
-        name: "United States Minor Outlying Islands",
-        num: "581",
-    });
-    codes.push(CountryCode {
-        alpha2: "FM",
-        alpha3: "FSM",
-        name: "Micronesia (Federated States of)",
-        num: "583",
-    });
-    codes.push(CountryCode {
-        alpha2: "MH",
-        alpha3: "MHL",
-        name: "Marshall Islands",
-        num: "584",
-    });
-    codes.push(CountryCode {
-        alpha2: "PW",
-        alpha3: "PLW",
-        name: "Palau",
-        num: "585",
-    });
-    codes.push(CountryCode {
-        alpha2: "PK",
-        alpha3: "PAK",
-        name: "Pakistan",
-        num: "586",
-    });
-    codes.push(CountryCode {
-        alpha2: "PA",
-        alpha3: "PAN",
-        name: "Panama",
-        num: "591",
-    });
-    codes.push(CountryCode {
-        alpha2: "PG",
-        alpha3: "PNG",
-        name: "Papua New Guinea",
-        num: "598",
-    });
-    codes.push(CountryCode {
-        alpha2: "PY",
-        alpha3: "PRY",
-        name: "Paraguay",
-        num: "600",
-    });
-    codes.push(CountryCode {
-        alpha2: "PE",
-        alpha3: "PER",
-        name: "Peru",
-        num: "604",
-    });
-    codes.push(CountryCode {
-        alpha2: "PH",
-        alpha3: "PHL",
-        name: "Philippines",
-        num: "608",
-    });
-    codes.push(CountryCode {
-        alpha2: "PN",
-        alpha3: "PCN",
-        name: "Pitcairn",
-        num: "612",
-    });
-    codes.push(CountryCode {
-        alpha2: "PL",
-        alpha3: "POL",
-        name: "Poland",
-        num: "616",
-    });
-    codes.push(CountryCode {
-        alpha2: "PT",
-        alpha3: "PRT",
-        name: "Portugal",
-        num: "620",
-    });
-    codes.push(CountryCode {
-        alpha2: "GW",
-        alpha3: "GNB",
-        name: "Guinea-Bissau",
-        num: "624",
-    });
-    codes.push(CountryCode {
-        alpha2: "TL",
-        alpha3: "TLS",
-        name: "Timor-Leste",
-        num: "626",
-    });
-    codes.push(CountryCode {
-        alpha2: "PR",
-        alpha3: "PRI",
-        name: "Puerto Rico",
-        num: "630",
-    });
-    codes.push(CountryCode {
-        alpha2: "QA",
-        alpha3: "QAT",
-        name: "Qatar",
-        num: "634",
-    });
-    codes.push(CountryCode {
-        alpha2: "RE",
-        alpha3: "REU",
-        name: "Réunion",
-        num: "638",
-    });
-    codes.push(CountryCode {
-        alpha2: "RO",
-        alpha3: "ROU",
-        name: "Romania",
-        num: "642",
-    });
-    codes.push(CountryCode {
-        alpha2: "RU",
-        alpha3: "RUS",
-        name: "Russian Federation",
-        num: "643",
-    });
-    codes.push(CountryCode {
-        alpha2: "RW",
-        alpha3: "RWA",
-        name: "Rwanda",
-        num: "646",
-    });
-    codes.push(CountryCode {
-        alpha2: "BL",
-        alpha3: "BLM",
-        name: "Saint Barthélemy",
-        num: "652",
-    });
-    codes.push(CountryCode {
-        alpha2: "SH",
-        alpha3: "SHN",
-        name: "Saint Helena, Ascension and Tristan da Cunha",
-        num: "654",
-    });
-    codes.push(CountryCode {
-        alpha2: "KN",
-        alpha3: "KNA",
-        name: "Saint Kitts and Nevis",
-        num: "659",
-    });
-    codes.push(CountryCode {
-        alpha2: "AI",
-        alpha3: "AIA",
-        name: "Anguilla",
-        num: "660",
-    });
-    codes.push(CountryCode {
-        alpha2: "LC",
-        alpha3: "LCA",
-        name: "Saint Lucia",
-        num: "662",
-    });
-    codes.push(CountryCode {
-        alpha2: "MF",
-        alpha3: "MAF",
-        name: "Saint Martin (French part)",
-        num: "663",
-    });
-    codes.push(CountryCode {
-        alpha2: "PM",
-        alpha3: "SPM",
-        name: "Saint Pierre and Miquelon",
-        num: "666",
-    });
-    codes.push(CountryCode {
-        alpha2: "VC",
-        alpha3: "VCT",
-        name: "Saint Vincent and the Grenadines",
-        num: "670",
-    });
-    codes.push(CountryCode {
-        alpha2: "SM",
-        alpha3: "SMR",
-        name: "San Marino",
-        num: "674",
-    });
-    codes.push(CountryCode {
-        alpha2: "ST",
-        alpha3: "STP",
-        name: "Sao Tome and Principe",
-        num: "678",
-    });
-    codes.push(CountryCode {
-        alpha2: "SA",
-        alpha3: "SAU",
-        name: "Saudi Arabia",
-        num: "682",
-    });
-    codes.push(CountryCode {
-        alpha2: "SN",
-        alpha3: "SEN",
-        name: "Senegal",
-        num: "686",
-    });
-    codes.push(CountryCode {
-        alpha2: "RS",
-        alpha3: "SRB",
-        name: "Serbia",
-        num: "688",
-    });
-    codes.push(CountryCode {
-        alpha2: "SC",
-        alpha3: "SYC",
-        name: "Seychelles",
-        num: "690",
-    });
-    codes.push(CountryCode {
-        alpha2: "SL",
-        alpha3: "SLE",
-        name: "Sierra Leone",
-        num: "694",
-    });
-    codes.push(CountryCode {
-        alpha2: "SG",
-        alpha3: "SGP",
-        name: "Singapore",
-        num: "702",
-    });
-    codes.push(CountryCode {
-        alpha2: "SK",
-        alpha3: "SVK",
-        name: "Slovakia",
-        num: "703",
-    });
-    codes.push(CountryCode {
-        alpha2: "VN",
-        alpha3: "VNM",
-        name: "Viet Nam",
-        num: "704",
-    });
-    codes.push(CountryCode {
-        alpha2: "SI",
-        alpha3: "SVN",
-        name: "Slovenia",
-        num: "705",
-    });
-    codes.push(CountryCode {
-        alpha2: "SO",
-        alpha3: "SOM",
-        name: "Somalia",
-        num: "706",
-    });
-    codes.push(CountryCode {
-        alpha2: "ZA",
-        alpha3: "ZAF",
-        name: "South Africa",
-        num: "710",
-    });
-    codes.push(CountryCode {
-        alpha2: "ZW",
-        alpha3: "ZWE",
-        name: "Zimbabwe",
-        num: "716",
-    });
-    codes.push(CountryCode {
-        alpha2: "ES",
-        alpha3: "ESP",
-        name: "Spain",
-        num: "724",
-    });
-    codes.push(CountryCode {
-        alpha2: "SS",
-        alpha3: "SSD",
-        name: "South Sudan",
-        num: "728",
-    });
-    codes.push(CountryCode {
-        alpha2: "SD",
-        alpha3: "SDN",
-        name: "Sudan",
-        num: "729",
-    });
-    codes.push(CountryCode {
-        alpha2: "EH",
-        alpha3: "ESH",
-        name: "Western Sahara",
-        num: "732",
-    });
-    codes.push(CountryCode {
-        alpha2: "SR",
-        alpha3: "SUR",
-        name: "Suriname",
-        num: "740",
-    });
-    codes.push(CountryCode {
-        alpha2: "SJ",
-        alpha3: "SJM",
-        name: "Svalbard and Jan Mayen",
-        num: "744",
-    });
-    codes.push(CountryCode {
-        alpha2: "SZ",
-        alpha3: "SWZ",
-        name: "Swaziland",
-        num: "748",
-    });
-    codes.push(CountryCode {
-        alpha2: "SE",
-        alpha3: "SWE",
-        name: "Sweden",
-        num: "752",
-    });
-    codes.push(CountryCode {
-        alpha2: "CH",
-        alpha3: "CHE",
-        name: "Switzerland",
-        num: "756",
-    });
-    codes.push(CountryCode {
-        alpha2: "SY",
-        alpha3: "SYR",
-        name: "Syrian Arab Republic",
-        num: "760",
-    });
-    codes.push(CountryCode {
-        alpha2: "TJ",
-        alpha3: "TJK",
-        name: "Tajikistan",
-        num: "762",
-    });
-    codes.push(CountryCode {
-        alpha2: "TH",
-        alpha3: "THA",
-        name: "Thailand",
-        num: "764",
-    });
-    codes.push(CountryCode {
-        alpha2: "TG",
-        alpha3: "TGO",
-        name: "Togo",
-        num: "768",
-    });
-    codes.push(CountryCode {
-        alpha2: "TK",
-        alpha3: "TKL",
-        name: "Tokelau",
-        num: "772",
-    });
-    codes.push(CountryCode {
-        alpha2: "TO",
-        alpha3: "TON",
-        name: "Tonga",
-        num: "776",
-    });
-    codes.push(CountryCode {
-        alpha2: "TT",
-        alpha3: "TTO",
-        name: "Trinidad and Tobago",
-        num: "780",
-    });
-    codes.push(CountryCode {
-        alpha2: "AE",
-        alpha3: "ARE",
-        name: "United Arab Emirates",
-        num: "784",
-    });
-    codes.push(CountryCode {
-        alpha2: "TN",
-        alpha3: "TUN",
-        name: "Tunisia",
-        num: "788",
-    });
-    codes.push(CountryCode {
-        alpha2: "TR",
-        alpha3: "TUR",
-        name: "Turkey",
-        num: "792",
-    });
-    codes.push(CountryCode {
-        alpha2: "TM",
-        alpha3: "TKM",
-        name: "Turkmenistan",
-        num: "795",
-    });
-    codes.push(CountryCode {
-        alpha2: "TC",
-        alpha3: "TCA",
-        name: "Turks and Caicos Islands",
-        num: "796",
-    });
-    codes.push(CountryCode {
-        alpha2: "TV",
-        alpha3: "TUV",
-        name: "Tuvalu",
-        num: "798",
-    });
-    codes.push(CountryCode {
-        alpha2: "UG",
-        alpha3: "UGA",
-        name: "Uganda",
-        num: "800",
-    });
-    codes.push(CountryCode {
-        alpha2: "UA",
-        alpha3: "UKR",
-        name: "Ukraine",
-        num: "804",
-    });
-    codes.push(CountryCode {
-        alpha2: "MK",
-        alpha3: "MKD",
-        name: "Macedonia (the former Yugoslav Republic of)",
-        num: "807",
-    });
-    codes.push(CountryCode {
-        alpha2: "EG",
-        alpha3: "EGY",
-        name: "Egypt",
-        num: "818",
-    });
-    codes.push(CountryCode {
-        alpha2: "GB",
-        alpha3: "GBR",
-        name: "United Kingdom of Great Britain and Northern Ireland",
-        num: "826",
-    });
-    codes.push(CountryCode {
-        alpha2: "GG",
-        alpha3: "GGY",
-        name: "Guernsey",
-        num: "831",
-    });
-    codes.push(CountryCode {
-        alpha2: "JE",
-        alpha3: "JEY",
-        name: "Jersey",
-        num: "832",
-    });
-    codes.push(CountryCode {
-        alpha2: "IM",
-        alpha3: "IMN",
-        name: "Isle of Man",
-        num: "833",
-    });
-    codes.push(CountryCode {
-        alpha2: "TZ",
-        alpha3: "TZA",
-        name: "Tanzania, United Republic of",
-        num: "834",
-    });
-    codes.push(CountryCode {
-        alpha2: "US",
-        alpha3: "USA",
-        name: "United States of America",
-        num: "840",
-    });
-    codes.push(CountryCode {
-        alpha2: "VI",
-        alpha3: "VIR",
-        name: "Virgin Islands (U.S.)",
-        num: "850",
-    });
-    codes.push(CountryCode {
-        alpha2: "BF",
-        alpha3: "BFA",
-        name: "Burkina Faso",
-        num: "854",
-    });
-    codes.push(CountryCode {
-        alpha2: "UY",
-        alpha3: "URY",
-        name: "Uruguay",
-        num: "858",
-    });
-    codes.push(CountryCode {
-        alpha2: "UZ",
-        alpha3: "UZB",
-        name: "Uzbekistan",
-        num: "860",
-    });
-    codes.push(CountryCode {
-        alpha2: "VE",
-        alpha3: "VEN",
-        name: "Venezuela (Bolivarian Republic of)",
-        num: "862",
-    });
-    codes.push(CountryCode {
-        alpha2: "WF",
-        alpha3: "WLF",
-        name: "Wallis and Futuna",
-        num: "876",
-    });
-    codes.push(CountryCode {
-        alpha2: "WS",
-        alpha3: "WSM",
-        name: "Samoa",
-        num: "882",
-    });
-    codes.push(CountryCode {
-        alpha2: "YE",
-        alpha3: "YEM",
-        name: "Yemen",
-        num: "887",
-    });
-    codes.push(CountryCode {
-        alpha2: "ZM",
-        alpha3: "ZMB",
-        name: "Zambia",
-        num: "894",
-    });
-    // End
+    #[test]
+    fn alpha3_table_is_sorted() {
+        let table = super::ALPHA3_SEARCH_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+    }
 
-    codes
+    #[test]
+    #[cfg(all(feature = "serde", feature = "std"))]
+    fn serde() {
+        let code = *Country::AF.info();
+        let json = serde_json::to_string(&code).unwrap();
+        assert_eq!(r#"{"country":"AF","alpha3":"AFG","numeric":4,"name":"Afghanistan"}"#, json);
+        assert_eq!(code, serde_json::from_str(&json).unwrap());
+        assert_eq!(code, serde_json::from_str(r#"{"country":"AF"}"#).unwrap());
+        assert!(serde_json::from_str::<super::CountryInfo>(r#"{"country":"AF","numeric":5}"#).is_err());
+    }
 }
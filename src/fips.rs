@@ -0,0 +1,571 @@
+// FIPS 10-4 country codes, maintained since 2008 as the US Geopolitical
+// Entities and Codes (GEC).
+//
+// The codes are two letters like ISO 3166-1 alpha-2 but often differ and
+// collide with it: GM is Germany (Gambia is GA), PO is Portugal and SZ is
+// Switzerland.  They are used by US government datasets and the CIA World
+// Factbook.
+
+use crate::Country;
+
+impl Country {
+    /// The FIPS 10-4 / GEC code of this country.
+    ///
+    /// `None` for the Åland Islands and Caribbean Netherlands, which are
+    /// part of Finland and the Netherlands in GEC, and for Palestine and the
+    /// US Minor Outlying Islands, which GEC splits into several entities.
+    pub fn fips_code(&self) -> Option<&'static str> {
+        use Country::*;
+        Some(match *self {
+            AD => "AN",
+            AE => "AE",
+            AF => "AF",
+            AG => "AC",
+            AI => "AV",
+            AL => "AL",
+            AM => "AM",
+            AO => "AO",
+            AQ => "AY",
+            AR => "AR",
+            AS => "AQ",
+            AT => "AU",
+            AU => "AS",
+            AW => "AA",
+            AZ => "AJ",
+            BA => "BK",
+            BB => "BB",
+            BD => "BG",
+            BE => "BE",
+            BF => "UV",
+            BG => "BU",
+            BH => "BA",
+            BI => "BY",
+            BJ => "BN",
+            BL => "TB",
+            BM => "BD",
+            BN => "BX",
+            BO => "BL",
+            BR => "BR",
+            BS => "BF",
+            BT => "BT",
+            BV => "BV",
+            BW => "BC",
+            BY => "BO",
+            BZ => "BH",
+            CA => "CA",
+            CC => "CK",
+            CD => "CG",
+            CF => "CT",
+            CG => "CF",
+            CH => "SZ",
+            CI => "IV",
+            CK => "CW",
+            CL => "CI",
+            CM => "CM",
+            CN => "CH",
+            CO => "CO",
+            CR => "CS",
+            CU => "CU",
+            CV => "CV",
+            CW => "UC",
+            CX => "KT",
+            CY => "CY",
+            CZ => "EZ",
+            DE => "GM",
+            DJ => "DJ",
+            DK => "DA",
+            DM => "DO",
+            DO => "DR",
+            DZ => "AG",
+            EC => "EC",
+            EE => "EN",
+            EG => "EG",
+            EH => "WI",
+            ER => "ER",
+            ES => "SP",
+            ET => "ET",
+            FI => "FI",
+            FJ => "FJ",
+            FK => "FK",
+            FM => "FM",
+            FO => "FO",
+            FR => "FR",
+            GA => "GB",
+            GB => "UK",
+            GD => "GJ",
+            GE => "GG",
+            GF => "FG",
+            GG => "GK",
+            GH => "GH",
+            GI => "GI",
+            GL => "GL",
+            GM => "GA",
+            GN => "GV",
+            GP => "GP",
+            GQ => "EK",
+            GR => "GR",
+            GS => "SX",
+            GT => "GT",
+            GU => "GQ",
+            GW => "PU",
+            GY => "GY",
+            HK => "HK",
+            HM => "HM",
+            HN => "HO",
+            HR => "HR",
+            HT => "HA",
+            HU => "HU",
+            ID => "ID",
+            IE => "EI",
+            IL => "IS",
+            IM => "IM",
+            IN => "IN",
+            IO => "IO",
+            IQ => "IZ",
+            IR => "IR",
+            IS => "IC",
+            IT => "IT",
+            JE => "JE",
+            JM => "JM",
+            JO => "JO",
+            JP => "JA",
+            KE => "KE",
+            KG => "KG",
+            KH => "CB",
+            KI => "KR",
+            KM => "CN",
+            KN => "SC",
+            KP => "KN",
+            KR => "KS",
+            KW => "KU",
+            KY => "CJ",
+            KZ => "KZ",
+            LA => "LA",
+            LB => "LE",
+            LC => "ST",
+            LI => "LS",
+            LK => "CE",
+            LR => "LI",
+            LS => "LT",
+            LT => "LH",
+            LU => "LU",
+            LV => "LG",
+            LY => "LY",
+            MA => "MO",
+            MC => "MN",
+            MD => "MD",
+            ME => "MJ",
+            MF => "RN",
+            MG => "MA",
+            MH => "RM",
+            MK => "MK",
+            ML => "ML",
+            MM => "BM",
+            MN => "MG",
+            MO => "MC",
+            MP => "CQ",
+            MQ => "MB",
+            MR => "MR",
+            MS => "MH",
+            MT => "MT",
+            MU => "MP",
+            MV => "MV",
+            MW => "MI",
+            MX => "MX",
+            MY => "MY",
+            MZ => "MZ",
+            NA => "WA",
+            NC => "NC",
+            NE => "NG",
+            NF => "NF",
+            NG => "NI",
+            NI => "NU",
+            NL => "NL",
+            NO => "NO",
+            NP => "NP",
+            NR => "NR",
+            NU => "NE",
+            NZ => "NZ",
+            OM => "MU",
+            PA => "PM",
+            PE => "PE",
+            PF => "FP",
+            PG => "PP",
+            PH => "RP",
+            PK => "PK",
+            PL => "PL",
+            PM => "SB",
+            PN => "PC",
+            PR => "RQ",
+            PT => "PO",
+            PW => "PS",
+            PY => "PA",
+            QA => "QA",
+            RE => "RE",
+            RO => "RO",
+            RS => "RI",
+            RU => "RS",
+            RW => "RW",
+            SA => "SA",
+            SB => "BP",
+            SC => "SE",
+            SD => "SU",
+            SE => "SW",
+            SG => "SN",
+            SH => "SH",
+            SI => "SI",
+            SJ => "SV",
+            SK => "LO",
+            SL => "SL",
+            SM => "SM",
+            SN => "SG",
+            SO => "SO",
+            SR => "NS",
+            SS => "OD",
+            ST => "TP",
+            SV => "ES",
+            SX => "NN",
+            SY => "SY",
+            SZ => "WZ",
+            TC => "TK",
+            TD => "CD",
+            TF => "FS",
+            TG => "TO",
+            TH => "TH",
+            TJ => "TI",
+            TK => "TL",
+            TL => "TT",
+            TM => "TX",
+            TN => "TS",
+            TO => "TN",
+            TR => "TU",
+            TT => "TD",
+            TV => "TV",
+            TW => "TW",
+            TZ => "TZ",
+            UA => "UP",
+            UG => "UG",
+            US => "US",
+            UY => "UY",
+            UZ => "UZ",
+            VA => "VT",
+            VC => "VC",
+            VE => "VE",
+            VG => "VI",
+            VI => "VQ",
+            VN => "VM",
+            VU => "NH",
+            WF => "WF",
+            WS => "WS",
+            YE => "YM",
+            YT => "MF",
+            ZA => "SF",
+            ZM => "ZA",
+            ZW => "ZI",
+            _ => return None
+        })
+    }
+
+    /// Looks up a FIPS 10-4 / GEC code.  The West Bank (WE) and Gaza Strip
+    /// (GZ) map to Palestine, Jan Mayen (JN) to Svalbard and Jan Mayen, and
+    /// the minor Pacific and Caribbean islands to `UM`.
+    pub fn from_fips(s: &str) -> Option<Country> {
+        let s = s.trim();
+        FIPS_CODE_SEARCH_TABLE.binary_search_by(|&(o, _)| crate::cmp_ignore_case(o, s)).ok()
+            .map(|i| FIPS_CODE_SEARCH_TABLE[i].1)
+    }
+}
+
+const FIPS_CODE_SEARCH_TABLE : &[(&str, Country)] = &[
+    ("AA", Country::AW),
+    ("AC", Country::AG),
+    ("AE", Country::AE),
+    ("AF", Country::AF),
+    ("AG", Country::DZ),
+    ("AJ", Country::AZ),
+    ("AL", Country::AL),
+    ("AM", Country::AM),
+    ("AN", Country::AD),
+    ("AO", Country::AO),
+    ("AQ", Country::AS),
+    ("AR", Country::AR),
+    ("AS", Country::AU),
+    ("AU", Country::AT),
+    ("AV", Country::AI),
+    ("AY", Country::AQ),
+    ("BA", Country::BH),
+    ("BB", Country::BB),
+    ("BC", Country::BW),
+    ("BD", Country::BM),
+    ("BE", Country::BE),
+    ("BF", Country::BS),
+    ("BG", Country::BD),
+    ("BH", Country::BZ),
+    ("BK", Country::BA),
+    ("BL", Country::BO),
+    ("BM", Country::MM),
+    ("BN", Country::BJ),
+    ("BO", Country::BY),
+    ("BP", Country::SB),
+    ("BQ", Country::UM),
+    ("BR", Country::BR),
+    ("BT", Country::BT),
+    ("BU", Country::BG),
+    ("BV", Country::BV),
+    ("BX", Country::BN),
+    ("BY", Country::BI),
+    ("CA", Country::CA),
+    ("CB", Country::KH),
+    ("CD", Country::TD),
+    ("CE", Country::LK),
+    ("CF", Country::CG),
+    ("CG", Country::CD),
+    ("CH", Country::CN),
+    ("CI", Country::CL),
+    ("CJ", Country::KY),
+    ("CK", Country::CC),
+    ("CM", Country::CM),
+    ("CN", Country::KM),
+    ("CO", Country::CO),
+    ("CQ", Country::MP),
+    ("CS", Country::CR),
+    ("CT", Country::CF),
+    ("CU", Country::CU),
+    ("CV", Country::CV),
+    ("CW", Country::CK),
+    ("CY", Country::CY),
+    ("DA", Country::DK),
+    ("DJ", Country::DJ),
+    ("DO", Country::DM),
+    ("DQ", Country::UM),
+    ("DR", Country::DO),
+    ("EC", Country::EC),
+    ("EG", Country::EG),
+    ("EI", Country::IE),
+    ("EK", Country::GQ),
+    ("EN", Country::EE),
+    ("ER", Country::ER),
+    ("ES", Country::SV),
+    ("ET", Country::ET),
+    ("EZ", Country::CZ),
+    ("FG", Country::GF),
+    ("FI", Country::FI),
+    ("FJ", Country::FJ),
+    ("FK", Country::FK),
+    ("FM", Country::FM),
+    ("FO", Country::FO),
+    ("FP", Country::PF),
+    ("FQ", Country::UM),
+    ("FR", Country::FR),
+    ("FS", Country::TF),
+    ("GA", Country::GM),
+    ("GB", Country::GA),
+    ("GG", Country::GE),
+    ("GH", Country::GH),
+    ("GI", Country::GI),
+    ("GJ", Country::GD),
+    ("GK", Country::GG),
+    ("GL", Country::GL),
+    ("GM", Country::DE),
+    ("GP", Country::GP),
+    ("GQ", Country::GU),
+    ("GR", Country::GR),
+    ("GT", Country::GT),
+    ("GV", Country::GN),
+    ("GY", Country::GY),
+    ("GZ", Country::PS),
+    ("HA", Country::HT),
+    ("HK", Country::HK),
+    ("HM", Country::HM),
+    ("HO", Country::HN),
+    ("HQ", Country::UM),
+    ("HR", Country::HR),
+    ("HU", Country::HU),
+    ("IC", Country::IS),
+    ("ID", Country::ID),
+    ("IM", Country::IM),
+    ("IN", Country::IN),
+    ("IO", Country::IO),
+    ("IR", Country::IR),
+    ("IS", Country::IL),
+    ("IT", Country::IT),
+    ("IV", Country::CI),
+    ("IZ", Country::IQ),
+    ("JA", Country::JP),
+    ("JE", Country::JE),
+    ("JM", Country::JM),
+    ("JN", Country::SJ),
+    ("JO", Country::JO),
+    ("JQ", Country::UM),
+    ("KE", Country::KE),
+    ("KG", Country::KG),
+    ("KN", Country::KP),
+    ("KQ", Country::UM),
+    ("KR", Country::KI),
+    ("KS", Country::KR),
+    ("KT", Country::CX),
+    ("KU", Country::KW),
+    ("KZ", Country::KZ),
+    ("LA", Country::LA),
+    ("LE", Country::LB),
+    ("LG", Country::LV),
+    ("LH", Country::LT),
+    ("LI", Country::LR),
+    ("LO", Country::SK),
+    ("LQ", Country::UM),
+    ("LS", Country::LI),
+    ("LT", Country::LS),
+    ("LU", Country::LU),
+    ("LY", Country::LY),
+    ("MA", Country::MG),
+    ("MB", Country::MQ),
+    ("MC", Country::MO),
+    ("MD", Country::MD),
+    ("MF", Country::YT),
+    ("MG", Country::MN),
+    ("MH", Country::MS),
+    ("MI", Country::MW),
+    ("MJ", Country::ME),
+    ("MK", Country::MK),
+    ("ML", Country::ML),
+    ("MN", Country::MC),
+    ("MO", Country::MA),
+    ("MP", Country::MU),
+    ("MQ", Country::UM),
+    ("MR", Country::MR),
+    ("MT", Country::MT),
+    ("MU", Country::OM),
+    ("MV", Country::MV),
+    ("MX", Country::MX),
+    ("MY", Country::MY),
+    ("MZ", Country::MZ),
+    ("NC", Country::NC),
+    ("NE", Country::NU),
+    ("NF", Country::NF),
+    ("NG", Country::NE),
+    ("NH", Country::VU),
+    ("NI", Country::NG),
+    ("NL", Country::NL),
+    ("NN", Country::SX),
+    ("NO", Country::NO),
+    ("NP", Country::NP),
+    ("NR", Country::NR),
+    ("NS", Country::SR),
+    ("NU", Country::NI),
+    ("NZ", Country::NZ),
+    ("OD", Country::SS),
+    ("PA", Country::PY),
+    ("PC", Country::PN),
+    ("PE", Country::PE),
+    ("PK", Country::PK),
+    ("PL", Country::PL),
+    ("PM", Country::PA),
+    ("PO", Country::PT),
+    ("PP", Country::PG),
+    ("PS", Country::PW),
+    ("PU", Country::GW),
+    ("QA", Country::QA),
+    ("RE", Country::RE),
+    ("RI", Country::RS),
+    ("RM", Country::MH),
+    ("RN", Country::MF),
+    ("RO", Country::RO),
+    ("RP", Country::PH),
+    ("RQ", Country::PR),
+    ("RS", Country::RU),
+    ("RW", Country::RW),
+    ("SA", Country::SA),
+    ("SB", Country::PM),
+    ("SC", Country::KN),
+    ("SE", Country::SC),
+    ("SF", Country::ZA),
+    ("SG", Country::SN),
+    ("SH", Country::SH),
+    ("SI", Country::SI),
+    ("SL", Country::SL),
+    ("SM", Country::SM),
+    ("SN", Country::SG),
+    ("SO", Country::SO),
+    ("SP", Country::ES),
+    ("ST", Country::LC),
+    ("SU", Country::SD),
+    ("SV", Country::SJ),
+    ("SW", Country::SE),
+    ("SX", Country::GS),
+    ("SY", Country::SY),
+    ("SZ", Country::CH),
+    ("TB", Country::BL),
+    ("TD", Country::TT),
+    ("TH", Country::TH),
+    ("TI", Country::TJ),
+    ("TK", Country::TC),
+    ("TL", Country::TK),
+    ("TN", Country::TO),
+    ("TO", Country::TG),
+    ("TP", Country::ST),
+    ("TS", Country::TN),
+    ("TT", Country::TL),
+    ("TU", Country::TR),
+    ("TV", Country::TV),
+    ("TW", Country::TW),
+    ("TX", Country::TM),
+    ("TZ", Country::TZ),
+    ("UC", Country::CW),
+    ("UG", Country::UG),
+    ("UK", Country::GB),
+    ("UP", Country::UA),
+    ("US", Country::US),
+    ("UV", Country::BF),
+    ("UY", Country::UY),
+    ("UZ", Country::UZ),
+    ("VC", Country::VC),
+    ("VE", Country::VE),
+    ("VI", Country::VG),
+    ("VM", Country::VN),
+    ("VQ", Country::VI),
+    ("VT", Country::VA),
+    ("WA", Country::NA),
+    ("WE", Country::PS),
+    ("WF", Country::WF),
+    ("WI", Country::EH),
+    ("WQ", Country::UM),
+    ("WS", Country::WS),
+    ("WZ", Country::SZ),
+    ("YM", Country::YE),
+    ("ZA", Country::ZM),
+    ("ZI", Country::ZW),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+
+    #[test]
+    fn fips_code() {
+        assert_eq!(Some("GM"), Country::DE.fips_code());
+        assert_eq!(Some("GA"), Country::GM.fips_code());
+        assert_eq!(Some("PO"), Country::PT.fips_code());
+        assert_eq!(Some("SZ"), Country::CH.fips_code());
+        assert_eq!(Some("UK"), Country::GB.fips_code());
+        assert_eq!(None, Country::PS.fips_code());
+    }
+
+    #[test]
+    fn from_fips() {
+        assert_eq!(Some(Country::DE), Country::from_fips("GM"));
+        assert_eq!(Some(Country::GM), Country::from_fips("ga"));
+        assert_eq!(Some(Country::PS), Country::from_fips("WE"));
+        assert_eq!(Some(Country::UM), Country::from_fips("WQ"));
+        assert_eq!(None, Country::from_fips("XX"));
+    }
+
+    #[test]
+    fn search_table_matches_codes() {
+        assert!(super::FIPS_CODE_SEARCH_TABLE.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            if let Some(code) = country.fips_code() {
+                assert_eq!(Some(country), Country::from_fips(code));
+            }
+        }
+    }
+}
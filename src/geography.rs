@@ -0,0 +1,403 @@
+// Physical geography: access to the sea, islands and enclaves.
+//
+// Coastline lengths are taken from the CIA World Factbook and include the
+// coasts of islands.  Entries the Factbook does not report separately, such
+// as the French overseas departments, have no coastline figure.
+
+use crate::Country;
+
+/// Whether a country has access to the open sea.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Landlocked {
+    /// The country has a coastline on the open sea.
+    No,
+    /// The country has no coastline.  Countries on the Caspian Sea are
+    /// counted as landlocked.
+    Landlocked,
+    /// The country is surrounded only by landlocked countries (Liechtenstein,
+    /// Uzbekistan).
+    DoublyLandlocked,
+}
+
+/// Physical classification of a country.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Geography {
+    pub landlocked: Landlocked,
+    /// The territory consists entirely of islands, possibly shared with
+    /// another country (Ireland, Haiti, Timor-Leste).
+    pub island: bool,
+    /// Length of the coastline in kilometres, if known.
+    pub coastline_km: Option<u32>,
+    /// The country is entirely surrounded by a single other country
+    /// (Lesotho, San Marino, Holy See).
+    pub enclave: bool,
+    /// The country has territory separated from its main part by other
+    /// countries (Kaliningrad, Cabinda, Nakhchivan).
+    pub exclaves: bool,
+}
+
+/// Groups of countries defined by the UN Office of the High Representative
+/// for the Least Developed Countries, Landlocked Developing Countries and
+/// Small Island Developing States.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Group {
+    /// Landlocked Developing Countries (LLDC).
+    LandlockedDeveloping,
+    /// Small Island Developing States (SIDS) which are UN members.
+    SmallIslandDeveloping,
+}
+
+impl Group {
+    pub fn members(&self) -> &'static [Country] {
+        use Country::*;
+        match *self {
+            Group::LandlockedDeveloping => &[
+                AF, AM, AZ, BF, BI, BO, BT, BW, CF, ET, KG, KZ, LA, LS, MD, MK, ML, MN, MW, NE,
+                NP, PY, RW, SS, SZ, TD, TJ, TM, UG, UZ, ZM, ZW,
+            ],
+            Group::SmallIslandDeveloping => &[
+                AG, BB, BH, BS, BZ, CU, CV, DM, DO, FJ, FM, GD, GW, GY, HT, JM, KI, KM, KN, LC,
+                MH, MU, MV, NR, PG, PW, SB, SC, SG, SR, ST, TL, TO, TT, TV, VC, VU, WS,
+            ],
+        }
+    }
+
+    pub fn contains(&self, country: Country) -> bool {
+        self.members().contains(&country)
+    }
+}
+
+impl Country {
+    pub fn geography(&self) -> Geography {
+        Geography {
+            landlocked: self.landlocked(),
+            island: self.is_island(),
+            coastline_km: self.coastline_km(),
+            enclave: matches!(*self, Country::LS | Country::SM | Country::VA),
+            exclaves: self.has_exclaves(),
+        }
+    }
+
+    pub fn is_landlocked(&self) -> bool {
+        self.landlocked() != Landlocked::No
+    }
+
+    fn landlocked(&self) -> Landlocked {
+        use Country::*;
+        match *self {
+            LI | UZ => Landlocked::DoublyLandlocked,
+            AD | AF | AM | AT | AZ | BF | BI | BO | BT | BW | BY | CF | CH | CZ | ET | HU | KG |
+            KZ | LA | LS | LU | MD | MK | ML | MN | MW | NE | NP | PY | RS | RW | SK | SM | SS |
+            SZ | TD | TJ | TM | UG | VA | ZM | ZW => Landlocked::Landlocked,
+            _ => Landlocked::No,
+        }
+    }
+
+    fn is_island(&self) -> bool {
+        use Country::*;
+        matches!(*self,
+            AG | AI | AS | AW | AX | BB | BH | BL | BM | BN | BQ | BS | BV | CC | CK | CU | CV |
+            CW | CX | CY | DM | DO | FJ | FK | FM | FO | GB | GD | GG | GL | GP | GS | GU | HM |
+            HT | ID | IE | IM | IO | IS | JE | JM | JP | KI | KM | KN | KY | LC | LK | MF | MG |
+            MH | MP | MQ | MS | MT | MU | MV | NC | NF | NR | NU | NZ | PF | PG | PH | PM | PN |
+            PR | PW | RE | SB | SC | SG | SH | SJ | ST | SX | TC | TF | TK | TL | TO | TT | TV |
+            TW | UM | VC | VG | VI | VU | WF | WS | YT)
+    }
+
+    fn has_exclaves(&self) -> bool {
+        use Country::*;
+        matches!(*self,
+            AE | AM | AO | AZ | BE | DE | ES | IT | KG | NL | OM | RU | TJ | TL | US | UZ)
+    }
+
+    fn coastline_km(&self) -> Option<u32> {
+        use Country::*;
+        match *self {
+            Unspecified => None,
+            AD => Some(0),
+            AE => Some(1318),
+            AF => Some(0),
+            AG => Some(153),
+            AI => Some(61),
+            AL => Some(362),
+            AM => Some(0),
+            AO => Some(1600),
+            AQ => Some(17968),
+            AR => Some(4989),
+            AS => Some(116),
+            AT => Some(0),
+            AU => Some(25760),
+            AW => Some(69),
+            AX => None,
+            AZ => Some(0),
+            BA => Some(20),
+            BB => Some(97),
+            BD => Some(580),
+            BE => Some(67),
+            BF => Some(0),
+            BG => Some(354),
+            BH => Some(161),
+            BI => Some(0),
+            BJ => Some(121),
+            BL => None,
+            BM => Some(103),
+            BN => Some(161),
+            BO => Some(0),
+            BQ => None,
+            BR => Some(7491),
+            BS => Some(3542),
+            BT => Some(0),
+            BV => Some(30),
+            BW => Some(0),
+            BY => Some(0),
+            BZ => Some(386),
+            CA => Some(202080),
+            CC => Some(26),
+            CD => Some(37),
+            CF => Some(0),
+            CG => Some(169),
+            CH => Some(0),
+            CI => Some(515),
+            CK => Some(120),
+            CL => Some(6435),
+            CM => Some(402),
+            CN => Some(14500),
+            CO => Some(3208),
+            CR => Some(1290),
+            CU => Some(3735),
+            CV => Some(965),
+            CW => Some(364),
+            CX => Some(139),
+            CY => Some(648),
+            CZ => Some(0),
+            DE => Some(2389),
+            DJ => Some(314),
+            DK => Some(7314),
+            DM => Some(148),
+            DO => Some(1288),
+            DZ => Some(998),
+            EC => Some(2237),
+            EE => Some(3794),
+            EG => Some(2450),
+            EH => Some(1110),
+            ER => Some(2234),
+            ES => Some(4964),
+            ET => Some(0),
+            FI => Some(1250),
+            FJ => Some(1129),
+            FK => Some(1288),
+            FM => Some(6112),
+            FO => Some(1117),
+            FR => Some(3427),
+            GA => Some(885),
+            GB => Some(12429),
+            GD => Some(121),
+            GE => Some(310),
+            GF => Some(378),
+            GG => Some(50),
+            GH => Some(539),
+            GI => Some(12),
+            GL => Some(44087),
+            GM => Some(80),
+            GN => Some(320),
+            GP => None,
+            GQ => Some(296),
+            GR => Some(13676),
+            GS => None,
+            GT => Some(400),
+            GU => Some(126),
+            GW => Some(350),
+            GY => Some(459),
+            HK => Some(733),
+            HM => Some(102),
+            HN => Some(823),
+            HR => Some(5835),
+            HT => Some(1771),
+            HU => Some(0),
+            ID => Some(54716),
+            IE => Some(1448),
+            IL => Some(273),
+            IM => Some(160),
+            IN => Some(7000),
+            IO => Some(698),
+            IQ => Some(58),
+            IR => Some(2440),
+            IS => Some(4970),
+            IT => Some(7600),
+            JE => Some(70),
+            JM => Some(1022),
+            JO => Some(26),
+            JP => Some(29751),
+            KE => Some(536),
+            KG => Some(0),
+            KH => Some(443),
+            KI => Some(1143),
+            KM => Some(340),
+            KN => Some(135),
+            KP => Some(2495),
+            KR => Some(2413),
+            KW => Some(499),
+            KY => Some(160),
+            KZ => Some(0),
+            LA => Some(0),
+            LB => Some(225),
+            LC => Some(158),
+            LI => Some(0),
+            LK => Some(1340),
+            LR => Some(579),
+            LS => Some(0),
+            LT => Some(90),
+            LU => Some(0),
+            LV => Some(498),
+            LY => Some(1770),
+            MA => Some(1835),
+            MC => Some(4),
+            MD => Some(0),
+            ME => Some(294),
+            MF => None,
+            MG => Some(4828),
+            MH => Some(370),
+            MK => Some(0),
+            ML => Some(0),
+            MM => Some(1930),
+            MN => Some(0),
+            MO => Some(41),
+            MP => Some(1482),
+            MQ => None,
+            MR => Some(754),
+            MS => Some(40),
+            MT => Some(197),
+            MU => Some(177),
+            MV => Some(644),
+            MW => Some(0),
+            MX => Some(9330),
+            MY => Some(4675),
+            MZ => Some(2470),
+            NA => Some(1572),
+            NC => Some(2254),
+            NE => Some(0),
+            NF => Some(32),
+            NG => Some(853),
+            NI => Some(910),
+            NL => Some(451),
+            NO => Some(25148),
+            NP => Some(0),
+            NR => Some(30),
+            NU => Some(64),
+            NZ => Some(15134),
+            OM => Some(2092),
+            PA => Some(2490),
+            PE => Some(2414),
+            PF => Some(2525),
+            PG => Some(5152),
+            PH => Some(36289),
+            PK => Some(1046),
+            PL => Some(440),
+            PM => Some(120),
+            PN => Some(51),
+            PR => Some(501),
+            PS => Some(40),
+            PT => Some(1793),
+            PW => Some(1519),
+            PY => Some(0),
+            QA => Some(563),
+            RE => None,
+            RO => Some(225),
+            RS => Some(0),
+            RU => Some(37653),
+            RW => Some(0),
+            SA => Some(2640),
+            SB => Some(5313),
+            SC => Some(491),
+            SD => Some(853),
+            SE => Some(3218),
+            SG => Some(193),
+            SH => None,
+            SI => Some(47),
+            SJ => Some(3587),
+            SK => Some(0),
+            SL => Some(402),
+            SM => Some(0),
+            SN => Some(531),
+            SO => Some(3025),
+            SR => Some(386),
+            SS => Some(0),
+            ST => Some(209),
+            SV => Some(307),
+            SX => None,
+            SY => Some(193),
+            SZ => Some(0),
+            TC => Some(389),
+            TD => Some(0),
+            TF => None,
+            TG => Some(56),
+            TH => Some(3219),
+            TJ => Some(0),
+            TK => Some(101),
+            TL => Some(706),
+            TM => Some(0),
+            TN => Some(1148),
+            TO => Some(419),
+            TR => Some(7200),
+            TT => Some(362),
+            TV => Some(24),
+            TW => Some(1566),
+            TZ => Some(1424),
+            UA => Some(2782),
+            UG => Some(0),
+            UM => None,
+            US => Some(19924),
+            UY => Some(660),
+            UZ => Some(0),
+            VA => Some(0),
+            VC => Some(84),
+            VE => Some(2800),
+            VG => Some(80),
+            VI => Some(188),
+            VN => Some(3444),
+            VU => Some(2528),
+            WF => Some(129),
+            WS => Some(403),
+            YE => Some(1906),
+            YT => Some(185),
+            ZA => Some(2798),
+            ZM => Some(0),
+            ZW => Some(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ Group, Landlocked };
+
+    #[test]
+    fn geography() {
+        let ch = Country::CH.geography();
+        assert_eq!(Landlocked::Landlocked, ch.landlocked);
+        assert_eq!(Some(0), ch.coastline_km);
+        assert_eq!(Landlocked::DoublyLandlocked, Country::LI.geography().landlocked);
+        assert!(Country::JP.geography().island);
+        assert!(!Country::PL.geography().island);
+        assert!(Country::SM.geography().enclave);
+        assert!(Country::RU.geography().exclaves);
+    }
+
+    #[test]
+    fn coastline_agrees_with_landlocked() {
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            if country.is_landlocked() {
+                assert_eq!(Some(0), country.geography().coastline_km, "{}", country);
+            }
+        }
+    }
+
+    #[test]
+    fn groups() {
+        assert!(Group::LandlockedDeveloping.contains(Country::BO));
+        assert!(!Group::LandlockedDeveloping.contains(Country::CH));
+        assert!(Group::LandlockedDeveloping.members().iter().all(|c| c.is_landlocked()));
+        assert!(Group::SmallIslandDeveloping.contains(Country::FJ));
+    }
+}
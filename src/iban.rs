@@ -0,0 +1,371 @@
+// International Bank Account Numbers (ISO 13616).
+//
+// Country lengths and BBAN structures follow the SWIFT IBAN registry.  The
+// BBAN structure uses the registry notation: "4!n" is four digits, "a" an
+// upper case letter and "c" a letter or a digit.
+//
+// Dependent territories without their own entry in the registry, such as
+// Guadeloupe or Jersey, use the IBAN country code of their sovereign state,
+// see `Country::iban_country`.
+
+use std::{ fmt, str };
+use std::error::Error;
+use std::ops::Range;
+
+use crate::Country;
+
+/// The structure of a country's IBAN as published in the SWIFT registry.
+///
+/// Ranges are byte offsets into the BBAN.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Structure {
+    pub length: usize,
+    pub bban: &'static str,
+    pub bank_code: Range<usize>,
+    pub branch_code: Option<Range<usize>>,
+    pub account_number: Range<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IbanError {
+    /// The IBAN contains characters other than ASCII letters, digits and
+    /// spaces.
+    InvalidCharacter,
+    /// The first two letters are not an ISO 3166-1 country code.
+    InvalidCountry(String),
+    /// The country does not use IBANs.
+    UnsupportedCountry(Country),
+    InvalidLength { expected: usize, found: usize },
+    /// The BBAN does not match the country's structure.
+    InvalidFormat,
+    /// The mod-97 check digits do not match.
+    InvalidChecksum,
+}
+
+impl Error for IbanError {
+    fn description(&self) -> &str { "error parsing IBAN" }
+}
+
+impl fmt::Display for IbanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A validated IBAN in its electronic format, without spaces.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Iban {
+    country: Country,
+    code: String,
+}
+
+impl Iban {
+    pub fn country(&self) -> Country {
+        self.country
+    }
+
+    pub fn check_digits(&self) -> &str {
+        &self.code[2..4]
+    }
+
+    pub fn bban(&self) -> &str {
+        &self.code[4..]
+    }
+
+    pub fn bank_code(&self) -> &str {
+        &self.bban()[self.structure().bank_code]
+    }
+
+    pub fn branch_code(&self) -> Option<&str> {
+        self.structure().branch_code.map(|range| &self.bban()[range])
+    }
+
+    pub fn account_number(&self) -> &str {
+        &self.bban()[self.structure().account_number]
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.code
+    }
+
+    fn structure(&self) -> Structure {
+        self.country.iban_structure().expect("validated IBAN")
+    }
+}
+
+/// Formats the IBAN in groups of four characters, as printed on paper.
+impl fmt::Display for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, group) in self.code.as_bytes().chunks(4).enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(str::from_utf8(group).map_err(|_| fmt::Error)?)?;
+        }
+        Ok(())
+    }
+}
+
+impl str::FromStr for Iban {
+    type Err = IbanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code: String = s.chars().filter(|c| *c != ' ').map(|c| c.to_ascii_uppercase()).collect();
+        if code.len() < 4 || !code.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(IbanError::InvalidCharacter);
+        }
+
+        let country = code[..2].parse::<Country>()
+            .ok()
+            .filter(|c| *c != Country::Unspecified)
+            .ok_or_else(|| IbanError::InvalidCountry(code[..2].to_string()))?;
+        let structure = country.iban_structure().ok_or(IbanError::UnsupportedCountry(country))?;
+
+        if code.len() != structure.length {
+            return Err(IbanError::InvalidLength { expected: structure.length, found: code.len() });
+        }
+        if !code[2..4].bytes().all(|b| b.is_ascii_digit()) || !matches(structure.bban, &code[4..]) {
+            return Err(IbanError::InvalidFormat);
+        }
+        if checksum(&code) != 1 {
+            return Err(IbanError::InvalidChecksum);
+        }
+
+        Ok(Iban { country, code })
+    }
+}
+
+/// Validates an IBAN, which may contain spaces.
+pub fn validate(s: &str) -> Result<Iban, IbanError> {
+    s.parse()
+}
+
+// Matches a BBAN against its registry structure, e.g. "4!a6!n8!n".
+fn matches(structure: &str, bban: &str) -> bool {
+    let mut rest = bban.as_bytes();
+    let mut spec = structure.as_bytes();
+    while let Some(bang) = spec.iter().position(|&b| b == b'!') {
+        let len: usize = match str::from_utf8(&spec[..bang]).ok().and_then(|n| n.parse().ok()) {
+            Some(len) => len,
+            None => return false
+        };
+        let kind = match spec.get(bang + 1) {
+            Some(&kind) => kind,
+            None => return false
+        };
+        if rest.len() < len {
+            return false;
+        }
+        let valid = rest[..len].iter().all(|b| match kind {
+            b'n' => b.is_ascii_digit(),
+            b'a' => b.is_ascii_uppercase(),
+            _ => b.is_ascii_digit() || b.is_ascii_uppercase(),
+        });
+        if !valid {
+            return false;
+        }
+        rest = &rest[len..];
+        spec = &spec[bang + 2..];
+    }
+    spec.is_empty() && rest.is_empty()
+}
+
+// ISO 7064 mod 97-10 over the IBAN with its first four characters moved to
+// the end and letters replaced by 10..35.
+fn checksum(code: &str) -> u32 {
+    code[4..].bytes().chain(code[..4].bytes()).fold(0, |acc, b| {
+        let value = (b as char).to_digit(36).unwrap_or(0);
+        if value < 10 { (acc * 10 + value) % 97 } else { (acc * 100 + value) % 97 }
+    })
+}
+
+fn s(length: usize, bban: &'static str, bank_code: Range<usize>, branch_code: Option<Range<usize>>,
+     account_number: Range<usize>) -> Structure {
+    Structure { length, bban, bank_code, branch_code, account_number }
+}
+
+impl Country {
+    /// The IBAN structure for this country, or `None` if the country is not
+    /// in the SWIFT IBAN registry.
+    pub fn iban_structure(&self) -> Option<Structure> {
+        use Country::*;
+        Some(match *self {
+            AD => s(24, "4!n4!n12!c", 0..4, Some(4..8), 8..20),
+            AE => s(23, "3!n16!n", 0..3, None, 3..19),
+            AL => s(28, "8!n16!c", 0..3, Some(3..7), 8..24),
+            AT => s(20, "5!n11!n", 0..5, None, 5..16),
+            AZ => s(28, "4!a20!c", 0..4, None, 4..24),
+            BA => s(20, "3!n3!n8!n2!n", 0..3, Some(3..6), 6..14),
+            BE => s(16, "3!n7!n2!n", 0..3, None, 3..10),
+            BG => s(22, "4!a4!n2!n8!c", 0..4, Some(4..8), 10..18),
+            BH => s(22, "4!a14!c", 0..4, None, 4..18),
+            BI => s(27, "5!n5!n11!n2!n", 0..5, Some(5..10), 10..21),
+            BR => s(29, "8!n5!n10!n1!a1!c", 0..8, Some(8..13), 13..23),
+            BY => s(28, "4!c4!n16!c", 0..4, None, 8..24),
+            CH => s(21, "5!n12!c", 0..5, None, 5..17),
+            CR => s(22, "4!n14!n", 0..4, None, 4..18),
+            CY => s(28, "3!n5!n16!c", 0..3, Some(3..8), 8..24),
+            CZ => s(24, "4!n6!n10!n", 0..4, None, 4..20),
+            DE => s(22, "8!n10!n", 0..8, None, 8..18),
+            DJ => s(27, "5!n5!n11!n2!n", 0..5, Some(5..10), 10..21),
+            DK => s(18, "4!n9!n1!n", 0..4, None, 4..14),
+            DO => s(28, "4!c20!n", 0..4, None, 4..24),
+            EE => s(20, "2!n2!n11!n1!n", 0..2, None, 2..16),
+            EG => s(29, "4!n4!n17!n", 0..4, Some(4..8), 8..25),
+            ES => s(24, "4!n4!n1!n1!n10!n", 0..4, Some(4..8), 10..20),
+            FI => s(18, "3!n11!n", 0..3, None, 3..14),
+            FK => s(18, "2!a12!n", 0..2, None, 2..14),
+            FO => s(18, "4!n9!n1!n", 0..4, None, 4..14),
+            FR => s(27, "5!n5!n11!c2!n", 0..5, Some(5..10), 10..21),
+            GB => s(22, "4!a6!n8!n", 0..4, Some(4..10), 10..18),
+            GE => s(22, "2!a16!n", 0..2, None, 2..18),
+            GI => s(23, "4!a15!c", 0..4, None, 4..19),
+            GL => s(18, "4!n9!n1!n", 0..4, None, 4..14),
+            GR => s(27, "3!n4!n16!c", 0..3, Some(3..7), 7..23),
+            GT => s(28, "4!c20!c", 0..4, None, 4..24),
+            HN => s(28, "4!a20!n", 0..4, None, 4..24),
+            HR => s(21, "7!n10!n", 0..7, None, 7..17),
+            HU => s(28, "3!n4!n1!n15!n1!n", 0..3, Some(3..7), 8..23),
+            IE => s(22, "4!a6!n8!n", 0..4, Some(4..10), 10..18),
+            IL => s(23, "3!n3!n13!n", 0..3, Some(3..6), 6..19),
+            IQ => s(23, "4!a3!n12!n", 0..4, Some(4..7), 7..19),
+            IS => s(26, "4!n2!n6!n10!n", 0..2, Some(2..4), 4..12),
+            IT => s(27, "1!a5!n5!n12!c", 1..6, Some(6..11), 11..23),
+            JO => s(30, "4!a4!n18!c", 0..4, Some(4..8), 8..26),
+            KW => s(30, "4!a22!c", 0..4, None, 4..26),
+            KZ => s(20, "3!n13!c", 0..3, None, 3..16),
+            LB => s(28, "4!n20!c", 0..4, None, 4..24),
+            LC => s(32, "4!a24!c", 0..4, None, 4..28),
+            LI => s(21, "5!n12!c", 0..5, None, 5..17),
+            LT => s(20, "5!n11!n", 0..5, None, 5..16),
+            LU => s(20, "3!n13!c", 0..3, None, 3..16),
+            LV => s(21, "4!a13!c", 0..4, None, 4..17),
+            LY => s(25, "3!n3!n15!n", 0..3, Some(3..6), 6..21),
+            MC => s(27, "5!n5!n11!c2!n", 0..5, Some(5..10), 10..21),
+            MD => s(24, "2!c18!c", 0..2, None, 2..20),
+            ME => s(22, "3!n13!n2!n", 0..3, None, 3..16),
+            MK => s(19, "3!n10!c2!n", 0..3, None, 3..13),
+            MN => s(20, "4!n12!n", 0..4, None, 4..16),
+            MR => s(27, "5!n5!n11!n2!n", 0..5, Some(5..10), 10..21),
+            MT => s(31, "4!a5!n18!c", 0..4, Some(4..9), 9..27),
+            MU => s(30, "4!a2!n2!n12!n3!n3!a", 0..6, Some(6..8), 8..20),
+            NI => s(28, "4!a20!n", 0..4, None, 4..24),
+            NL => s(18, "4!a10!n", 0..4, None, 4..14),
+            NO => s(15, "4!n6!n1!n", 0..4, None, 4..10),
+            OM => s(23, "3!n16!c", 0..3, None, 3..19),
+            PK => s(24, "4!a16!c", 0..4, None, 4..20),
+            PL => s(28, "8!n16!n", 0..8, None, 8..24),
+            PS => s(29, "4!a21!c", 0..4, None, 4..25),
+            PT => s(25, "4!n4!n11!n2!n", 0..4, Some(4..8), 8..19),
+            QA => s(29, "4!a21!c", 0..4, None, 4..25),
+            RO => s(24, "4!a16!c", 0..4, None, 4..20),
+            RS => s(22, "3!n13!n2!n", 0..3, None, 3..16),
+            RU => s(33, "9!n5!n15!c", 0..9, Some(9..14), 14..29),
+            SA => s(24, "2!n18!c", 0..2, None, 2..20),
+            SC => s(31, "4!a2!n2!n16!n3!a", 0..6, Some(6..8), 8..24),
+            SD => s(18, "2!n12!n", 0..2, None, 2..14),
+            SE => s(24, "3!n16!n1!n", 0..3, None, 3..20),
+            SI => s(19, "5!n8!n2!n", 0..5, None, 5..13),
+            SK => s(24, "4!n6!n10!n", 0..4, None, 4..20),
+            SM => s(27, "1!a5!n5!n12!c", 1..6, Some(6..11), 11..23),
+            SO => s(23, "4!n3!n12!n", 0..4, Some(4..7), 7..19),
+            ST => s(25, "4!n4!n11!n2!n", 0..4, Some(4..8), 8..19),
+            SV => s(28, "4!a20!n", 0..4, None, 4..24),
+            TL => s(23, "3!n14!n2!n", 0..3, None, 3..17),
+            TN => s(24, "2!n3!n13!n2!n", 0..2, Some(2..5), 5..18),
+            TR => s(26, "5!n1!n16!c", 0..5, None, 6..22),
+            UA => s(29, "6!n19!c", 0..6, None, 6..25),
+            VA => s(22, "3!n15!n", 0..3, None, 3..18),
+            VG => s(24, "4!a16!n", 0..4, None, 4..20),
+            YE => s(30, "4!a4!n18!c", 0..4, Some(4..8), 8..26),
+            _ => return None
+        })
+    }
+
+    /// The country code used in IBANs of accounts in this country: the
+    /// country itself or, for territories covered by another country's entry
+    /// in the SWIFT registry, that country, e.g. `FR` for Guadeloupe.  `None`
+    /// if the country does not use IBANs.
+    pub fn iban_country(&self) -> Option<Country> {
+        use Country::*;
+        match *self {
+            AX => Some(FI),
+            BL | GF | GP | MF | MQ | NC | PF | PM | RE | TF | WF | YT => Some(FR),
+            GG | IM | JE => Some(GB),
+            country => country.iban_structure().map(|_| country),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ validate, IbanError };
+
+    #[test]
+    fn valid() {
+        for iban in &[
+            "GB82 WEST 1234 5698 7654 32",
+            "DE89 3704 0044 0532 0130 00",
+            "PL61 1090 1014 0000 0712 1981 2874",
+            "FR14 2004 1010 0505 0001 3M02 606",
+            "CH93 0076 2011 6238 5295 7",
+            "NO93 8601 1117 947",
+            "IT60 X054 2811 1010 0000 0123 456",
+            "NL91 ABNA 0417 1643 00",
+            "MU17 BOMM 0101 1010 3030 0200 000M UR",
+            "BR18 0036 0305 0000 1000 9795 493C 1",
+        ] {
+            let parsed = validate(iban).unwrap();
+            assert_eq!(*iban, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn bban() {
+        let iban = validate("gb82west12345698765432").unwrap();
+        assert_eq!(Country::GB, iban.country());
+        assert_eq!("82", iban.check_digits());
+        assert_eq!("WEST", iban.bank_code());
+        assert_eq!(Some("123456"), iban.branch_code());
+        assert_eq!("98765432", iban.account_number());
+
+        let iban = validate("ES91 2100 0418 4502 0005 1332").unwrap();
+        assert_eq!("2100", iban.bank_code());
+        assert_eq!(Some("0418"), iban.branch_code());
+        assert_eq!("0200051332", iban.account_number());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(IbanError::InvalidChecksum), validate("GB83 WEST 1234 5698 7654 32"));
+        assert_eq!(Err(IbanError::InvalidLength { expected: 22, found: 21 }), validate("GB82 WEST 1234 5698 7654 3"));
+        assert_eq!(Err(IbanError::InvalidFormat), validate("GB82 1234 1234 5698 7654 32"));
+        assert_eq!(Err(IbanError::InvalidCountry("XX".to_string())), validate("XX82 WEST 1234 5698 7654 32"));
+        assert_eq!(Err(IbanError::UnsupportedCountry(Country::US)), validate("US12 3456"));
+        assert_eq!(Err(IbanError::InvalidCharacter), validate("GB82-WEST"));
+    }
+
+    #[test]
+    fn registry_structures() {
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            if let Some(structure) = country.iban_structure() {
+                let bban: String = structure.bban.split_terminator(char::is_lowercase)
+                    .map(|part| "A".repeat(part.trim_end_matches('!').parse().unwrap()))
+                    .collect();
+                assert_eq!(structure.length - 4, bban.len(), "{}", country);
+                assert!(structure.account_number.end <= bban.len(), "{}", country);
+            }
+        }
+    }
+
+    #[test]
+    fn iban_countries() {
+        assert_eq!(Some(Country::FR), Country::GP.iban_country());
+        assert_eq!(Some(Country::GB), Country::JE.iban_country());
+        assert_eq!(Some(Country::FI), Country::AX.iban_country());
+        assert_eq!(Some(Country::PL), Country::PL.iban_country());
+        assert_eq!(None, Country::US.iban_country());
+        assert_eq!(None, Country::Unspecified.iban_country());
+    }
+}
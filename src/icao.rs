@@ -0,0 +1,365 @@
+// ICAO Doc 9303 machine readable travel documents.
+//
+// Travel documents identify the issuing state and the nationality of the
+// holder with three-letter codes.  These are ISO 3166-1 alpha-3 codes with
+// a few exceptions: Germany is "D", British nationals other than citizens
+// have codes of their own, and international organizations, stateless
+// persons and refugees have reserved codes.
+
+use std::fmt;
+use std::error::Error;
+use std::str::FromStr;
+
+use crate::Country;
+
+/// An ICAO 9303 issuing state or nationality code.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IcaoCode {
+    Country(Country),
+    /// GBD, British Overseas Territories Citizen.
+    BritishOverseasTerritoriesCitizen,
+    /// GBN, British National (Overseas).
+    BritishNationalOverseas,
+    /// GBO, British Overseas Citizen.
+    BritishOverseasCitizen,
+    /// GBP, British Protected Person.
+    BritishProtectedPerson,
+    /// GBS, British Subject.
+    BritishSubject,
+    /// EUE, European Union.
+    EuropeanUnion,
+    /// UNO, United Nations Organization.
+    UnitedNations,
+    /// UNA, a specialized agency of the United Nations.
+    UnitedNationsAgency,
+    /// UNK, resident of Kosovo with a document of the UN mission.
+    UnitedNationsKosovo,
+    /// XXA, stateless person.
+    Stateless,
+    /// XXB, refugee under the 1951 Convention.
+    Refugee,
+    /// XXC, refugee other than under the 1951 Convention.
+    OtherRefugee,
+    /// XXX, unspecified nationality.
+    Unspecified,
+}
+
+impl IcaoCode {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            IcaoCode::Country(country) => country.icao_code().unwrap_or("XXX"),
+            IcaoCode::BritishOverseasTerritoriesCitizen => "GBD",
+            IcaoCode::BritishNationalOverseas => "GBN",
+            IcaoCode::BritishOverseasCitizen => "GBO",
+            IcaoCode::BritishProtectedPerson => "GBP",
+            IcaoCode::BritishSubject => "GBS",
+            IcaoCode::EuropeanUnion => "EUE",
+            IcaoCode::UnitedNations => "UNO",
+            IcaoCode::UnitedNationsAgency => "UNA",
+            IcaoCode::UnitedNationsKosovo => "UNK",
+            IcaoCode::Stateless => "XXA",
+            IcaoCode::Refugee => "XXB",
+            IcaoCode::OtherRefugee => "XXC",
+            IcaoCode::Unspecified => "XXX",
+        }
+    }
+
+    /// The country of the code: `GB` for all British nationals and `None`
+    /// for organizations, stateless persons and refugees.
+    pub fn country(&self) -> Option<Country> {
+        match *self {
+            IcaoCode::Country(country) => Some(country),
+            IcaoCode::BritishOverseasTerritoriesCitizen
+            | IcaoCode::BritishNationalOverseas
+            | IcaoCode::BritishOverseasCitizen
+            | IcaoCode::BritishProtectedPerson
+            | IcaoCode::BritishSubject => Some(Country::GB),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for IcaoCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<Country> for IcaoCode {
+    fn from(country: Country) -> IcaoCode {
+        IcaoCode::Country(country)
+    }
+}
+
+impl FromStr for IcaoCode {
+    type Err = MrzError;
+
+    /// Parses a code as it appears in a machine readable zone, with or
+    /// without the filler, e.g. "D<<" or "D".
+    fn from_str(s: &str) -> Result<IcaoCode, MrzError> {
+        let code = s.trim_end_matches('<');
+        Ok(match code {
+            "D" => IcaoCode::Country(Country::DE),
+            "GBD" => IcaoCode::BritishOverseasTerritoriesCitizen,
+            "GBN" => IcaoCode::BritishNationalOverseas,
+            "GBO" => IcaoCode::BritishOverseasCitizen,
+            "GBP" => IcaoCode::BritishProtectedPerson,
+            "GBS" => IcaoCode::BritishSubject,
+            "EUE" => IcaoCode::EuropeanUnion,
+            "UNO" => IcaoCode::UnitedNations,
+            "UNA" => IcaoCode::UnitedNationsAgency,
+            "UNK" => IcaoCode::UnitedNationsKosovo,
+            "XXA" => IcaoCode::Stateless,
+            "XXB" => IcaoCode::Refugee,
+            "XXC" => IcaoCode::OtherRefugee,
+            "XXX" => IcaoCode::Unspecified,
+            _ => Country::from_alpha3(code)
+                .map(IcaoCode::Country)
+                .ok_or_else(|| MrzError::InvalidCode(s.to_string()))?
+        })
+    }
+}
+
+impl Country {
+    /// The ICAO 9303 code of this country, the alpha-3 code except for
+    /// Germany ("D").
+    pub fn icao_code(&self) -> Option<&'static str> {
+        match *self {
+            Country::Unspecified => None,
+            Country::DE => Some("D"),
+            country => Some(country.alpha3()),
+        }
+    }
+}
+
+/// The size of a machine readable travel document.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MrzFormat {
+    /// Identity cards, three lines of 30 characters.
+    TD1,
+    /// Identity cards and visas, two lines of 36 characters.
+    TD2,
+    /// Passports, two lines of 44 characters.
+    TD3,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MrzError {
+    InvalidLength,
+    InvalidCharacter,
+    InvalidCode(String),
+    /// The check digit of the named field does not match.
+    InvalidCheckDigit(&'static str),
+}
+
+impl Error for MrzError {
+    fn description(&self) -> &str { "error parsing machine readable zone" }
+}
+
+impl fmt::Display for MrzError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// The machine readable zone of a travel document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mrz {
+    pub format: MrzFormat,
+    /// "P" for passports, "I" or "ID" for identity cards and so on.
+    pub document_type: String,
+    pub issuing_state: IcaoCode,
+    pub surname: String,
+    pub given_names: String,
+    pub document_number: String,
+    pub nationality: IcaoCode,
+    /// YYMMDD
+    pub birth_date: String,
+    /// 'M', 'F' or 'X'.
+    pub sex: char,
+    /// YYMMDD
+    pub expiry_date: String,
+    pub optional_data: String,
+}
+
+impl Mrz {
+    /// The issuing country, `None` for documents issued by organizations.
+    pub fn issuing_country(&self) -> Option<Country> {
+        self.issuing_state.country()
+    }
+}
+
+/// Parses the machine readable zone of a TD1, TD2 or TD3 document and
+/// validates its check digits.
+///
+/// Lines are separated by line breaks; without them the format is told by
+/// the total length.
+pub fn parse_mrz(s: &str) -> Result<Mrz, MrzError> {
+    let mut lines: Vec<&str> = s.split_whitespace().collect();
+    if lines.is_empty() {
+        return Err(MrzError::InvalidLength);
+    }
+    if lines.len() == 1 {
+        let s = lines[0];
+        let width = match s.len() {
+            90 => 30,
+            72 => 36,
+            88 => 44,
+            _ => return Err(MrzError::InvalidLength),
+        };
+        if !s.is_ascii() {
+            return Err(MrzError::InvalidCharacter);
+        }
+        lines = (0..s.len() / width).map(|i| &s[i * width..(i + 1) * width]).collect();
+    }
+    if !lines.iter().all(|l| l.bytes().all(|b| b.is_ascii_digit() || b.is_ascii_uppercase() || b == b'<')) {
+        return Err(MrzError::InvalidCharacter);
+    }
+
+    match (lines.len(), lines[0].len()) {
+        (3, 30) if lines[1].len() == 30 && lines[2].len() == 30 => {
+            let (l1, l2, l3) = (lines[0], lines[1], lines[2]);
+            check("document number", &l1[5..14], &l1[14..15])?;
+            check("birth date", &l2[0..6], &l2[6..7])?;
+            check("expiry date", &l2[8..14], &l2[14..15])?;
+            check("composite", &[&l1[5..30], &l2[0..7], &l2[8..15], &l2[18..29]].concat(), &l2[29..30])?;
+            let (surname, given_names) = names(l3);
+            Ok(Mrz {
+                format: MrzFormat::TD1,
+                document_type: field(&l1[0..2]),
+                issuing_state: l1[2..5].parse()?,
+                surname,
+                given_names,
+                document_number: field(&l1[5..14]),
+                nationality: l2[15..18].parse()?,
+                birth_date: l2[0..6].to_string(),
+                sex: sex(&l2[7..8]),
+                expiry_date: l2[8..14].to_string(),
+                optional_data: [field(&l1[15..30]), field(&l2[18..29])].concat(),
+            })
+        },
+        (2, width @ 36) | (2, width @ 44) if lines[1].len() == width => {
+            let (l1, l2) = (lines[0], lines[1]);
+            let optional = if width == 44 { 28..42 } else { 28..35 };
+            check("document number", &l2[0..9], &l2[9..10])?;
+            check("birth date", &l2[13..19], &l2[19..20])?;
+            check("expiry date", &l2[21..27], &l2[27..28])?;
+            if width == 44 {
+                check("personal number", &l2[28..42], &l2[42..43])?;
+            }
+            check("composite", &[&l2[0..10], &l2[13..20], &l2[21..width - 1]].concat(), &l2[width - 1..])?;
+            let (surname, given_names) = names(&l1[5..]);
+            Ok(Mrz {
+                format: if width == 36 { MrzFormat::TD2 } else { MrzFormat::TD3 },
+                document_type: field(&l1[0..2]),
+                issuing_state: l1[2..5].parse()?,
+                surname,
+                given_names,
+                document_number: field(&l2[0..9]),
+                nationality: l2[10..13].parse()?,
+                birth_date: l2[13..19].to_string(),
+                sex: sex(&l2[20..21]),
+                expiry_date: l2[21..27].to_string(),
+                optional_data: field(&l2[optional]),
+            })
+        },
+        _ => Err(MrzError::InvalidLength)
+    }
+}
+
+// Verifies a check digit, computed with the weights 7, 3, 1.  An empty
+// field may have a filler instead of a zero.
+fn check(name: &'static str, data: &str, digit: &str) -> Result<(), MrzError> {
+    let sum: u32 = data.bytes().zip([7, 3, 1].iter().cycle()).map(|(b, w)| w * match b {
+        b'0'..=b'9' => (b - b'0') as u32,
+        b'A'..=b'Z' => (b - b'A') as u32 + 10,
+        _ => 0,
+    }).sum();
+    let expected = (b'0' + (sum % 10) as u8) as char;
+    match digit {
+        "<" if data.bytes().all(|b| b == b'<') => Ok(()),
+        _ if digit.starts_with(expected) => Ok(()),
+        _ => Err(MrzError::InvalidCheckDigit(name)),
+    }
+}
+
+fn field(s: &str) -> String {
+    s.trim_end_matches('<').replace('<', " ")
+}
+
+fn names(s: &str) -> (String, String) {
+    let s = s.trim_end_matches('<');
+    match s.split_once("<<") {
+        Some((surname, given_names)) => (field(surname), field(given_names)),
+        None => (field(s), String::new()),
+    }
+}
+
+fn sex(s: &str) -> char {
+    match s {
+        "M" => 'M',
+        "F" => 'F',
+        _ => 'X',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ parse_mrz, IcaoCode, MrzError, MrzFormat };
+
+    #[test]
+    fn codes() {
+        assert_eq!(Some("D"), Country::DE.icao_code());
+        assert_eq!(Some("POL"), Country::PL.icao_code());
+        assert_eq!(Some("GBR"), Country::GB.icao_code());
+        assert_eq!(Ok(IcaoCode::Country(Country::DE)), "D<<".parse());
+        assert_eq!(Ok(IcaoCode::Country(Country::PL)), "POL".parse());
+        assert_eq!(Ok(IcaoCode::BritishOverseasCitizen), "GBO".parse());
+        assert_eq!(Some(Country::GB), IcaoCode::BritishNationalOverseas.country());
+        assert_eq!(None, IcaoCode::UnitedNations.country());
+        assert_eq!("XXA", IcaoCode::Stateless.to_string());
+        assert!("UTO".parse::<IcaoCode>().is_err());
+    }
+
+    #[test]
+    fn td3() {
+        let mrz = parse_mrz("P<D<<MUSTERMANN<<ERIKA<<<<<<<<<<<<<<<<<<<<<<\n\
+                             C01X00T478D<<6408125F2702283<<<<<<<<<<<<<<<4").unwrap();
+        assert_eq!(MrzFormat::TD3, mrz.format);
+        assert_eq!("P", mrz.document_type);
+        assert_eq!(Some(Country::DE), mrz.issuing_country());
+        assert_eq!(IcaoCode::Country(Country::DE), mrz.nationality);
+        assert_eq!("MUSTERMANN", mrz.surname);
+        assert_eq!("ERIKA", mrz.given_names);
+        assert_eq!("C01X00T47", mrz.document_number);
+        assert_eq!("640812", mrz.birth_date);
+        assert_eq!('F', mrz.sex);
+        assert_eq!("270228", mrz.expiry_date);
+    }
+
+    #[test]
+    fn td1_and_td2() {
+        let mrz = parse_mrz("I<POLABA3000004<<<<<<<<<<<<<<<\n\
+                             8501019M3001019POL<<<<<<<<<<<4\n\
+                             KOWALSKI<<JAN<<<<<<<<<<<<<<<<<").unwrap();
+        assert_eq!(MrzFormat::TD1, mrz.format);
+        assert_eq!(Some(Country::PL), mrz.issuing_country());
+        assert_eq!("ABA300000", mrz.document_number);
+        assert_eq!("KOWALSKI", mrz.surname);
+
+        let mrz = parse_mrz("I<GBRSMITH<<JOHN<<<<<<<<<<<<<<<<<<<<1234567897GBO9001011M2512314<<<<<<<6").unwrap();
+        assert_eq!(MrzFormat::TD2, mrz.format);
+        assert_eq!(IcaoCode::BritishOverseasCitizen, mrz.nationality);
+        assert_eq!(Some(Country::GB), mrz.nationality.country());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(MrzError::InvalidCheckDigit("birth date")),
+                   parse_mrz("P<D<<MUSTERMANN<<ERIKA<<<<<<<<<<<<<<<<<<<<<<\n\
+                              C01X00T478D<<6408135F2702283<<<<<<<<<<<<<<<4"));
+        assert_eq!(Err(MrzError::InvalidLength), parse_mrz("P<D<<MUSTERMANN"));
+        assert_eq!(Err(MrzError::InvalidCharacter), parse_mrz("p<d<<mustermann\nc01x00t478"));
+    }
+}
@@ -0,0 +1,524 @@
+// IP address to country lookup from a local database file.
+//
+// Two formats are understood: MaxMind DB files (GeoIP2 / GeoLite2 Country,
+// DB-IP and similar) and CSV files of address ranges, one range per line as
+// `start,end,country` with the addresses written either in the usual
+// notation or as decimal integers, as in the IP2Location and DB-IP lite
+// downloads.  The file is memory-mapped; MaxMind DB files are searched in
+// place, CSV files are converted once into a sorted list of intervals.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::net::{ IpAddr, Ipv4Addr };
+use std::path::Path;
+use std::sync::OnceLock;
+
+use memmap2::Mmap;
+
+use crate::Country;
+
+const METADATA_MARKER : &[u8] = b"\xab\xcd\xefMaxMind.com";
+
+#[derive(Debug)]
+pub enum IpDatabaseError {
+    Io(io::Error),
+    /// The MaxMind DB metadata or search tree is malformed.
+    InvalidDatabase(&'static str),
+    /// The CSV line with the given (1-based) number cannot be read.
+    InvalidLine(usize),
+}
+
+impl Error for IpDatabaseError {
+    fn description(&self) -> &str { "error reading IP database" }
+}
+
+impl fmt::Display for IpDatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl From<io::Error> for IpDatabaseError {
+    fn from(e: io::Error) -> IpDatabaseError {
+        IpDatabaseError::Io(e)
+    }
+}
+
+pub struct IpDatabase {
+    index: Index,
+}
+
+enum Index {
+    MaxMind(MaxMind),
+    /// Non-overlapping `(start, end, country)` ranges sorted by start, with
+    /// IPv4 addresses stored as IPv4-mapped IPv6 addresses.
+    Ranges(Vec<(u128, u128, Country)>),
+}
+
+struct MaxMind {
+    map: Mmap,
+    node_count: usize,
+    record_size: usize,
+    ip_version: u64,
+    /// The node reached after the 96 zero bits of an IPv4 address in an IPv6
+    /// tree.
+    ipv4_start: usize,
+    data_start: usize,
+}
+
+static DEFAULT : OnceLock<IpDatabase> = OnceLock::new();
+
+impl IpDatabase {
+    /// Opens a MaxMind DB or CSV file.  The format is detected from the
+    /// content.
+    ///
+    /// The file is memory-mapped and MaxMind DB files are read from the map
+    /// on every lookup.  Truncating or rewriting the file while the database
+    /// is open is undefined behaviour and may crash the process; replace the
+    /// file by renaming a new one over it instead.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<IpDatabase, IpDatabaseError> {
+        let file = File::open(path)?;
+        // Safety: the file is only read, callers must not truncate it while
+        // the database is in use.
+        let map = unsafe { Mmap::map(&file)? };
+        // The metadata is in the last 128 KiB of a MaxMind DB file.
+        let tail = map.len().saturating_sub(128 * 1024);
+        let index = match rfind(&map[tail..], METADATA_MARKER) {
+            Some(i) => {
+                let metadata = tail + i + METADATA_MARKER.len();
+                Index::MaxMind(MaxMind::new(map, metadata)?)
+            },
+            None => Index::Ranges(parse_csv(&map)?),
+        };
+        Ok(IpDatabase { index })
+    }
+
+    /// The country of the address, `None` when the address is not in the
+    /// database or its country is not an ISO 3166 country (anonymous proxies,
+    /// satellite providers and the like).
+    pub fn lookup(&self, ip: IpAddr) -> Option<Country> {
+        match self.index {
+            Index::MaxMind(ref db) => db.lookup(ip),
+            Index::Ranges(ref ranges) => {
+                let ip = to_u128(ip);
+                let i = ranges.partition_point(|&(start, _, _)| start <= ip);
+                match i.checked_sub(1).map(|i| ranges[i]) {
+                    Some((_, end, country)) if ip <= end => Some(country),
+                    _ => None,
+                }
+            },
+        }
+    }
+
+    /// Makes this database the one used by `Country::from_ip`.  It can be set
+    /// only once, the database is given back if one is already set.
+    pub fn set_default(self) -> Result<(), IpDatabase> {
+        DEFAULT.set(self)
+    }
+}
+
+impl Country {
+    /// The country of an IP address according to the database installed with
+    /// `IpDatabase::set_default`.  Returns `None` when no database is set.
+    pub fn from_ip(ip: IpAddr) -> Option<Country> {
+        DEFAULT.get().and_then(|db| db.lookup(ip))
+    }
+}
+
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+fn to_u128(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(ip) => u128::from(ip.to_ipv6_mapped()),
+        IpAddr::V6(ip) => u128::from(ip),
+    }
+}
+
+fn parse_csv(bytes: &[u8]) -> Result<Vec<(u128, u128, Country)>, IpDatabaseError> {
+    let text = std::str::from_utf8(bytes).map_err(|_| IpDatabaseError::InvalidLine(1))?;
+    let mut ranges = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue
+        }
+        let mut fields = line.split(',').map(|f| f.trim().trim_matches('"'));
+        let (start, end, code) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(start), Some(end), Some(code)) => (start, end, code),
+            _ => return Err(IpDatabaseError::InvalidLine(n + 1)),
+        };
+        let (start, end) = match (parse_address(start), parse_address(end)) {
+            (Some(start), Some(end)) if start <= end => (start, end),
+            // A header line.
+            _ if n == 0 => continue,
+            _ => return Err(IpDatabaseError::InvalidLine(n + 1)),
+        };
+        // Unassigned ranges are marked with "-", "ZZ" or an empty code.
+        match code.parse::<Country>() {
+            Ok(Country::Unspecified) | Err(_) => {},
+            Ok(country) => ranges.push((start, end, country)),
+        }
+    }
+    ranges.sort_unstable_by_key(|&(start, _, _)| start);
+    Ok(ranges)
+}
+
+fn parse_address(s: &str) -> Option<u128> {
+    if let Ok(ip) = s.parse::<IpAddr>() {
+        return Some(to_u128(ip))
+    }
+    // Decimal addresses up to 2^32 are IPv4, as in IP2Location files.
+    let n = s.parse::<u128>().ok()?;
+    match u32::try_from(n) {
+        Ok(n) => Some(to_u128(IpAddr::V4(Ipv4Addr::from(n)))),
+        Err(_) => Some(n),
+    }
+}
+
+/// A value decoded from the MaxMind DB data section.
+enum Value<'a> {
+    String(&'a str),
+    UInt(u64),
+    /// A map with the number of entries and the offset of its first key.
+    Map(usize, usize),
+    Other,
+}
+
+impl MaxMind {
+    fn new(map: Mmap, metadata: usize) -> Result<MaxMind, IpDatabaseError> {
+        let invalid = IpDatabaseError::InvalidDatabase;
+        let mut db = MaxMind { map, node_count: 0, record_size: 0, ip_version: 0, ipv4_start: 0, data_start: 0 };
+        let field = |db: &MaxMind, key: &str| match db.find(&db.map[metadata..], 0, &[key]) {
+            Some(Value::UInt(n)) => Some(n),
+            _ => None,
+        };
+        db.node_count = field(&db, "node_count").ok_or(invalid("missing node_count"))? as usize;
+        db.record_size = field(&db, "record_size").ok_or(invalid("missing record_size"))? as usize;
+        db.ip_version = field(&db, "ip_version").ok_or(invalid("missing ip_version"))?;
+        if ![24, 28, 32].contains(&db.record_size) {
+            return Err(invalid("unsupported record size"))
+        }
+        let tree_size = db.node_count * db.record_size / 4;
+        db.data_start = tree_size + 16;
+        if db.data_start > metadata {
+            return Err(invalid("search tree larger than file"))
+        }
+        if db.ip_version == 6 {
+            for _ in 0..96 {
+                if db.ipv4_start >= db.node_count {
+                    break
+                }
+                db.ipv4_start = db.record(db.ipv4_start, 0);
+            }
+        }
+        Ok(db)
+    }
+
+    fn lookup(&self, ip: IpAddr) -> Option<Country> {
+        let (bits, len) : ([u8; 16], usize) = match ip {
+            IpAddr::V4(ip) => (ip.to_ipv6_compatible().octets(), 32),
+            IpAddr::V6(_) if self.ip_version == 4 => return None,
+            IpAddr::V6(ip) => (ip.octets(), 128),
+        };
+        let mut node = if len == 32 { self.ipv4_start } else { 0 };
+        for i in 128 - len..128 {
+            if node >= self.node_count {
+                break
+            }
+            let bit = (bits[i / 8] >> (7 - i % 8)) & 1;
+            node = self.record(node, bit as usize);
+        }
+        if node <= self.node_count {
+            return None
+        }
+        // Records pointing into the 16 byte separator are corrupt.
+        let offset = (node - self.node_count).checked_sub(16)?;
+        let data = self.map.get(self.data_start..)?;
+        for path in [&["country", "iso_code"], &["registered_country", "iso_code"]] {
+            if let Some(Value::String(code)) = self.find(data, offset, path) {
+                return code.parse().ok().filter(|&country| country != Country::Unspecified)
+            }
+        }
+        None
+    }
+
+    fn record(&self, node: usize, side: usize) -> usize {
+        let bytes = self.record_size / 4;
+        let n = &self.map[node * bytes..(node + 1) * bytes];
+        let be = |b: &[u8]| b.iter().fold(0usize, |acc, &b| acc << 8 | b as usize);
+        match (self.record_size, side) {
+            (28, 0) => (n[3] as usize & 0xf0) << 20 | be(&n[0..3]),
+            (28, _) => (n[3] as usize & 0x0f) << 24 | be(&n[4..7]),
+            (_, 0) => be(&n[..bytes / 2]),
+            (_, _) => be(&n[bytes / 2..]),
+        }
+    }
+
+    /// Follows a path of map keys from the value at `offset`.
+    fn find<'a>(&self, data: &'a [u8], offset: usize, path: &[&str]) -> Option<Value<'a>> {
+        let mut value = decode(data, offset)?;
+        for key in path {
+            let (len, mut at) = match value {
+                Value::Map(len, at) => (len, at),
+                _ => return None,
+            };
+            let mut found = None;
+            for _ in 0..len {
+                let k = decode(data, at)?;
+                let v = skip(data, at, MAX_DEPTH)?;
+                if matches!(k, Value::String(k) if k == *key) {
+                    found = Some(decode(data, v)?);
+                    break
+                }
+                at = skip(data, v, MAX_DEPTH)?;
+            }
+            value = found?;
+        }
+        Some(value)
+    }
+}
+
+/// The deepest nesting of maps and arrays read from a MaxMind DB.
+const MAX_DEPTH : usize = 32;
+
+/// The type, size and payload offset of the value at `offset`.  The size of
+/// a pointer is its target.
+fn header(data: &[u8], offset: usize) -> Option<(u8, usize, usize)> {
+    let control = *data.get(offset)?;
+    let mut at = offset + 1;
+    let mut kind = control >> 5;
+    if kind == 1 {
+        let extra = (control >> 3 & 3) as usize + 1;
+        let bytes = data.get(at..at + extra)?;
+        let value = bytes.iter().fold(0usize, |acc, &b| acc << 8 | b as usize);
+        let low = (control & 7) as usize;
+        let target = match extra {
+            1 => low << 8 | value,
+            2 => (low << 16 | value) + 2048,
+            3 => (low << 24 | value) + 526336,
+            _ => value,
+        };
+        return Some((kind, target, at + extra))
+    }
+    if kind == 0 {
+        kind = 7 + *data.get(at)?;
+        at += 1;
+    }
+    let mut size = (control & 0x1f) as usize;
+    if size >= 29 {
+        let extra = size - 28;
+        let bytes = data.get(at..at + extra)?;
+        let value = bytes.iter().fold(0usize, |acc, &b| acc << 8 | b as usize);
+        size = [29, 285, 65821][extra - 1] + value;
+        at += extra;
+    }
+    Some((kind, size, at))
+}
+
+/// Decodes the value at `offset`, following a pointer.  A pointer to a
+/// pointer is invalid.
+fn decode(data: &[u8], offset: usize) -> Option<Value<'_>> {
+    let (mut kind, mut size, mut at) = header(data, offset)?;
+    if kind == 1 {
+        (kind, size, at) = header(data, size)?;
+        if kind == 1 {
+            return None
+        }
+    }
+    match kind {
+        2 => Some(Value::String(std::str::from_utf8(data.get(at..at + size)?).ok()?)),
+        5 | 6 | 9 => {
+            let n = data.get(at..at + size)?.iter().fold(0u64, |acc, &b| acc << 8 | b as u64);
+            Some(Value::UInt(n))
+        },
+        7 => Some(Value::Map(size, at)),
+        _ => Some(Value::Other),
+    }
+}
+
+/// The offset just past the value at `offset`.  Pointers are not followed
+/// and only maps and arrays are walked, up to `depth` levels.
+fn skip(data: &[u8], offset: usize, depth: usize) -> Option<usize> {
+    let (kind, size, mut at) = header(data, offset)?;
+    match kind {
+        1 => {},
+        3 => at += 8,
+        15 => at += 4,
+        7 | 11 => {
+            let items = if kind == 7 { size * 2 } else { size };
+            for _ in 0..items {
+                at = skip(data, at, depth.checked_sub(1)?)?;
+            }
+        },
+        // Boolean, data cache container and end marker have no payload.
+        12..=14 => {},
+        _ => at += size,
+    }
+    (at <= data.len()).then_some(at)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::net::IpAddr;
+    use std::path::PathBuf;
+
+    use crate::Country;
+    use super::IpDatabase;
+
+    fn temp_file(name: &str, content: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("iso_country_{}_{}", std::process::id(), name));
+        std::fs::File::create(&path).unwrap().write_all(content).unwrap();
+        path
+    }
+
+    fn string(s: &str) -> Vec<u8> {
+        let mut v = vec![0x40 | s.len() as u8];
+        v.extend_from_slice(s.as_bytes());
+        v
+    }
+
+    fn map(entries: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut v = vec![0xe0 | entries.len() as u8];
+        for (k, value) in entries {
+            v.extend(string(k));
+            v.extend(value);
+        }
+        v
+    }
+
+    // A 24 bit IPv6 tree with the given networks, each with a record
+    // {"country": {"iso_code": code}}.
+    fn mmdb(networks: &[(u128, u32, &str)]) -> Vec<u8> {
+        let networks : Vec<_> = networks.iter()
+            .map(|&(network, len, code)| (network, len, map(&[("country", map(&[("iso_code", string(code))]))])))
+            .collect();
+        mmdb_with(&networks)
+    }
+
+    // A 24 bit IPv6 tree with the given networks and encoded records.
+    fn mmdb_with(networks: &[(u128, u32, Vec<u8>)]) -> Vec<u8> {
+        let mut nodes : Vec<[Option<usize>; 2]> = vec![[None, None]];
+        let mut leaves : Vec<(usize, usize, usize)> = Vec::new();
+        let mut data : Vec<u8> = Vec::new();
+        for (network, len, record) in networks {
+            let (network, len) = (*network, *len);
+            let mut node = 0;
+            for i in 0..len - 1 {
+                let bit = (network >> (127 - i) & 1) as usize;
+                node = match nodes[node][bit] {
+                    Some(next) => next,
+                    None => {
+                        nodes.push([None, None]);
+                        nodes[node][bit] = Some(nodes.len() - 1);
+                        nodes.len() - 1
+                    },
+                };
+            }
+            leaves.push((node, (network >> (128 - len) & 1) as usize, data.len()));
+            data.extend(record);
+        }
+        let count = nodes.len();
+        let mut records : Vec<[usize; 2]> = nodes.iter()
+            .map(|n| [n[0].unwrap_or(count), n[1].unwrap_or(count)])
+            .collect();
+        for (node, bit, offset) in leaves {
+            records[node][bit] = count + 16 + offset;
+        }
+        let mut file = Vec::new();
+        for r in records {
+            for side in r {
+                file.extend_from_slice(&(side as u32).to_be_bytes()[1..]);
+            }
+        }
+        file.extend([0; 16]);
+        file.extend(data);
+        file.extend_from_slice(super::METADATA_MARKER);
+        file.extend(map(&[
+            ("node_count", vec![0xc4, (count >> 24) as u8, (count >> 16) as u8, (count >> 8) as u8, count as u8]),
+            ("record_size", vec![0xa1, 24]),
+            ("ip_version", vec![0xa1, 6]),
+        ]));
+        file
+    }
+
+    #[test]
+    fn maxmind() {
+        let v4 = |ip: &str| u128::from(ip.parse::<std::net::Ipv4Addr>().unwrap().to_ipv6_compatible());
+        let v6 = |ip: &str| u128::from(ip.parse::<std::net::Ipv6Addr>().unwrap());
+        let path = temp_file("test.mmdb", &mmdb(&[
+            (v4("81.0.0.0"), 104, "PL"),
+            (v4("8.8.8.0"), 120, "US"),
+            (v6("2001:db8::"), 32, "DE"),
+        ]));
+        let db = IpDatabase::open(&path).unwrap();
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+        assert_eq!(Some(Country::PL), db.lookup(ip("81.2.3.4")));
+        assert_eq!(Some(Country::US), db.lookup(ip("8.8.8.8")));
+        assert_eq!(None, db.lookup(ip("8.8.9.8")));
+        assert_eq!(Some(Country::DE), db.lookup(ip("2001:db8::1")));
+        assert_eq!(None, db.lookup(ip("2001:db9::1")));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn corrupt_maxmind() {
+        // A /32 network gives a tree of 32 nodes; point the right record of
+        // the root into the separator.
+        let mut file = mmdb(&[(0x2001_0db8 << 96, 32, "DE")]);
+        file[3..6].copy_from_slice(&(32u32 + 5).to_be_bytes()[1..]);
+        let path = temp_file("corrupt.mmdb", &file);
+        let db = IpDatabase::open(&path).unwrap();
+        assert_eq!(None, db.lookup("8000::1".parse().unwrap()));
+        assert_eq!(Some(Country::DE), db.lookup("2001:db8::1".parse().unwrap()));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn hostile_maxmind() {
+        let db = |record: Vec<u8>| {
+            let path = temp_file("hostile.mmdb", &mmdb_with(&[(0x2001_0db8 << 96, 32, record)]));
+            let db = IpDatabase::open(&path).unwrap();
+            std::fs::remove_file(path).unwrap();
+            db.lookup("2001:db8::1".parse().unwrap())
+        };
+        // A pointer to itself at the start of the data section.
+        assert_eq!(None, db(vec![0x20, 0x00]));
+        let nested = |depth| (0..depth).fold(string("x"), |value, _| map(&[("x", value)]));
+        let country = map(&[("iso_code", string("DE"))]);
+        assert_eq!(Some(Country::DE), db(map(&[("city", nested(8)), ("country", country.clone())])));
+        assert_eq!(None, db(map(&[("city", nested(40)), ("country", country)])));
+    }
+
+    #[test]
+    fn csv() {
+        let path = temp_file("test.csv", concat!(
+            "\"ip_from\",\"ip_to\",\"country_code\",\"country_name\"\n",
+            "\"16777216\",\"16777471\",\"AU\",\"Australia\"\n",
+            "\"16777472\",\"16778239\",\"-\",\"-\"\n",
+            "1.0.3.0,1.0.3.255,\n",
+            "1.0.4.0,1.0.7.255,AU\n",
+            "2a00:1450::,2a00:1450:ffff:ffff:ffff:ffff:ffff:ffff,IE\n",
+        ).as_bytes());
+        let db = IpDatabase::open(&path).unwrap();
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+        assert_eq!(Some(Country::AU), db.lookup(ip("1.0.0.1")));
+        assert_eq!(None, db.lookup(ip("1.0.1.1")));
+        assert_eq!(None, db.lookup(ip("1.0.3.1")));
+        assert_eq!(Some(Country::AU), db.lookup(ip("1.0.5.1")));
+        assert_eq!(Some(Country::IE), db.lookup(ip("2a00:1450:4001::1")));
+        assert_eq!(None, db.lookup(ip("::1")));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn invalid_csv() {
+        let path = temp_file("invalid.csv", b"1.0.0.0,1.0.0.255,AU\nnot an address,1.0.1.0,AU\n");
+        assert!(matches!(IpDatabase::open(&path), Err(super::IpDatabaseError::InvalidLine(2))));
+        std::fs::remove_file(path).unwrap();
+    }
+}
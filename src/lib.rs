@@ -8,17 +8,49 @@
 // | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
 
 
-use std::{ fmt, str };
-use std::error::Error;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+use core::{ cmp::Ordering, fmt, str };
+
+#[cfg(feature = "std")]
+pub mod accept_language;
+#[cfg(feature = "std")]
+pub mod address;
+pub mod conventions;
 pub mod data;
+pub mod geography;
+#[cfg(feature = "std")]
+pub mod iban;
+#[cfg(feature = "ip")]
+pub mod ip;
+#[cfg(feature = "std")]
+pub mod icao;
+#[cfg(feature = "std")]
+pub mod locale;
+#[cfg(feature = "std")]
+pub mod phone;
+#[cfg(feature = "std")]
+pub mod postal_code;
+#[cfg(feature = "std")]
+pub mod rir;
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod serde;
+#[cfg(feature = "std")]
+pub mod vat;
+pub mod sovereignty;
+pub mod sports;
+mod fips;
+mod literal;
+mod tz;
+mod vehicle;
 
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CountryParseError {
-    InvalidCountryCode(String)
+    InvalidCountryCode
 }
 
-impl Error for CountryParseError {
+#[cfg(feature = "std")]
+impl std::error::Error for CountryParseError {
     fn description(&self) -> &str { "error parsing country code" }
 }
 
@@ -28,13 +60,18 @@ impl fmt::Display for CountryParseError {
     }
 }
 
+/// Compares a table key with a code, ignoring the case of the code.
+pub(crate) fn cmp_ignore_case(key: &str, code: &str) -> Ordering {
+    key.bytes().cmp(code.bytes().map(|b| b.to_ascii_uppercase()))
+}
+
 impl str::FromStr for Country {
     type Err = CountryParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match COUNTRY_CODE_SEARCH_TABLE.binary_search_by(|&(o, _)| o.cmp(s)) {
             Ok(pos) => Ok(COUNTRY_CODE_SEARCH_TABLE[pos].1),
-            Err(_)  => Err(CountryParseError::InvalidCountryCode(s.to_string()))
+            Err(_)  => Err(CountryParseError::InvalidCountryCode)
         }
     }
 }
@@ -822,24 +859,69 @@ pub enum Country {
     ZW = 716,
 }
 
+impl Country {
+    /// All countries, ordered by alpha-2 code.  `Unspecified` is not included.
+    pub const ALL : [Country; 249] = {
+        use Country::*;
+        [
+            AD, AE, AF, AG, AI, AL, AM, AO, AQ, AR, AS, AT, AU, AW, AX, AZ, BA, BB, BD, BE, BF, BG, BH, BI, BJ,
+            BL, BM, BN, BO, BQ, BR, BS, BT, BV, BW, BY, BZ, CA, CC, CD, CF, CG, CH, CI, CK, CL, CM, CN, CO, CR,
+            CU, CV, CW, CX, CY, CZ, DE, DJ, DK, DM, DO, DZ, EC, EE, EG, EH, ER, ES, ET, FI, FJ, FK, FM, FO, FR,
+            GA, GB, GD, GE, GF, GG, GH, GI, GL, GM, GN, GP, GQ, GR, GS, GT, GU, GW, GY, HK, HM, HN, HR, HT, HU,
+            ID, IE, IL, IM, IN, IO, IQ, IR, IS, IT, JE, JM, JO, JP, KE, KG, KH, KI, KM, KN, KP, KR, KW, KY, KZ,
+            LA, LB, LC, LI, LK, LR, LS, LT, LU, LV, LY, MA, MC, MD, ME, MF, MG, MH, MK, ML, MM, MN, MO, MP, MQ,
+            MR, MS, MT, MU, MV, MW, MX, MY, MZ, NA, NC, NE, NF, NG, NI, NL, NO, NP, NR, NU, NZ, OM, PA, PE, PF,
+            PG, PH, PK, PL, PM, PN, PR, PS, PT, PW, PY, QA, RE, RO, RS, RU, RW, SA, SB, SC, SD, SE, SG, SH, SI,
+            SJ, SK, SL, SM, SN, SO, SR, SS, ST, SV, SX, SY, SZ, TC, TD, TF, TG, TH, TJ, TK, TL, TM, TN, TO, TR,
+            TT, TV, TW, TZ, UA, UG, UM, US, UY, UZ, VA, VC, VE, VG, VI, VN, VU, WF, WS, YE, YT, ZA, ZM, ZW,
+        ]
+    };
+
+    /// Iterates over `Country::ALL`.
+    pub fn iter() -> core::iter::Copied<core::slice::Iter<'static, Country>> {
+        Country::ALL.iter().copied()
+    }
+
+    /// Orders countries by alpha-2 code, for `sort_by`.  `Unspecified` has an
+    /// empty code and comes first.
+    pub fn cmp_alpha2(&self, other: &Country) -> Ordering {
+        self.alpha2().cmp(other.alpha2())
+    }
+
+    /// Orders countries by alpha-3 code.  `Unspecified` has an empty code and
+    /// comes first.
+    pub fn cmp_alpha3(&self, other: &Country) -> Ordering {
+        self.alpha3().cmp(other.alpha3())
+    }
+
+    /// Orders countries by numeric code, the same as `Ord`.  `Unspecified`
+    /// has code 0 and comes first.
+    pub fn cmp_numeric(&self, other: &Country) -> Ordering {
+        self.numeric().cmp(&other.numeric())
+    }
+
+    /// Orders countries by English name.  Names are compared by their
+    /// characters, so "Åland Islands" comes last.  `Unspecified` has an empty
+    /// name and comes first.
+    pub fn cmp_name(&self, other: &Country) -> Ordering {
+        self.name().cmp(other.name())
+    }
+}
+
 #[cfg(feature = "serde")]
-impl serde::Serialize for Country {
-   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer
+impl ::serde::Serialize for Country {
+   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: ::serde::Serializer
    {
-       let code = COUNTRY_CODE_SEARCH_TABLE.binary_search_by(|&(_, s)| s.cmp(self))
-           .map(|pos| COUNTRY_CODE_SEARCH_TABLE[pos].0);
-       let code = code.expect("impossible happened!");
-       serializer.serialize_str(code)
+       serializer.serialize_str(self.alpha2())
    }
 }
 
 #[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for Country {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-      use serde::de::Visitor;
-      use serde::de::Unexpected;
-      use std::fmt;
-      use std::str::FromStr;
+impl<'de> ::serde::Deserialize<'de> for Country {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::serde::Deserializer<'de> {
+      use ::serde::de::Visitor;
+      use ::serde::de::Unexpected;
+      use core::str::FromStr;
       struct CountryVisitor;
 
       impl <'de> Visitor<'de> for CountryVisitor {
@@ -849,7 +931,7 @@ impl<'de> serde::Deserialize<'de> for Country {
                   formatter.write_str("valid 2 letter country code")
             }
 
-            fn visit_str<E>(self, value: &str) -> Result<Country, E> where E: serde::de::Error {
+            fn visit_str<E>(self, value: &str) -> Result<Country, E> where E: ::serde::de::Error {
                   match Country::from_str(value) {
                         Ok(country) => Ok(country),
                         Err(_) => Err(E::invalid_value(Unexpected::Str(value), &"2 letter country code")),
@@ -861,7 +943,7 @@ impl<'de> serde::Deserialize<'de> for Country {
     }
 }
 
-const COUNTRY_CODE_SEARCH_TABLE : &'static [(&'static str, Country)] = &[
+const COUNTRY_CODE_SEARCH_TABLE : &[(&str, Country)] = &[
     ("",    Country::Unspecified),
     ("AD",  Country::AD),
     ("AE",  Country::AE),
@@ -1134,12 +1216,44 @@ mod tests {
         assert_s!("");
     }
 
+    #[test]
+    fn parse_error() {
+        assert_eq!(Err(super::CountryParseError::InvalidCountryCode), "XX".parse::<Country>());
+        assert_eq!(Err(super::CountryParseError::InvalidCountryCode), "pl".parse::<Country>());
+    }
+
     #[test]
     fn name() {
         assert_eq!("Poland", Country::PL.name());
         assert_eq!("", Country::Unspecified.name());
     }
 
+    #[test]
+    fn all() {
+        assert_eq!(super::COUNTRY_CODE_SEARCH_TABLE.len() - 1, Country::ALL.len());
+        assert!(Country::ALL.windows(2).all(|w| w[0].alpha2() < w[1].alpha2()));
+        assert_eq!(Country::ALL.len(), Country::iter().len());
+        assert_eq!(Some(Country::ZW), Country::iter().next_back());
+        assert!(Country::iter().all(|c| c.to_string().parse::<Country>().ok() == Some(c)));
+    }
+
+    #[test]
+    fn orderings() {
+        let mut countries = Country::ALL;
+        countries.sort_by(Country::cmp_name);
+        assert_eq!(Country::AF, countries[0]);
+        countries.sort_by(Country::cmp_alpha3);
+        assert_eq!(Country::AW, countries[0]);
+        countries.sort_by(Country::cmp_numeric);
+        assert_eq!(Country::AF, countries[0]);
+        assert_eq!(Country::ZM, countries[countries.len() - 1]);
+        countries.sort_by(Country::cmp_alpha2);
+        assert_eq!(Country::ALL, countries);
+        for cmp in [Country::cmp_alpha2, Country::cmp_alpha3, Country::cmp_numeric, Country::cmp_name] {
+            assert_eq!(core::cmp::Ordering::Less, cmp(&Country::Unspecified, &Country::AD));
+        }
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serde() {
@@ -0,0 +1,140 @@
+// Const lookups, usable in constants and behind the `country!` macro.
+
+use core::cmp::Ordering;
+
+use crate::Country;
+
+/// Creates a `Country` from an alpha-2 code, alpha-3 code or numeric code at
+/// compile time: `country!("PL")`, `country!("POL")` or `country!(616)`.
+/// Unknown codes fail compilation.
+///
+/// ```compile_fail
+/// let _ = iso_country::country!("XY");
+/// ```
+#[macro_export]
+macro_rules! country {
+    ($code:literal) => {{
+        const COUNTRY : $crate::Country = match $crate::Country::from_code_const(concat!($code).as_bytes()) {
+            Some(country) => country,
+            None => panic!(concat!("unknown country code ", stringify!($code))),
+        };
+        COUNTRY
+    }};
+}
+
+const fn cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return if a[i] < b[i] { Ordering::Less } else { Ordering::Greater }
+        }
+        i += 1;
+    }
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+const fn search(table: &[(&str, Country)], code: &[u8]) -> Option<Country> {
+    let (mut low, mut high) = (0, table.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        match cmp(table[mid].0.as_bytes(), code) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Some(table[mid].1),
+        }
+    }
+    None
+}
+
+impl Country {
+    /// `Country::from_str` for constants: `from_alpha2_const(b"PL")`.
+    pub const fn from_alpha2_const(code: &[u8]) -> Option<Country> {
+        if code.len() != 2 {
+            return None
+        }
+        search(crate::COUNTRY_CODE_SEARCH_TABLE, code)
+    }
+
+    pub const fn from_alpha3_const(code: &[u8]) -> Option<Country> {
+        search(crate::data::ALPHA3_SEARCH_TABLE, code)
+    }
+
+    pub const fn from_numeric_const(numeric: u16) -> Option<Country> {
+        let table = crate::data::COUNTRIES;
+        let (mut low, mut high) = (0, table.len());
+        while low < high {
+            let mid = (low + high) / 2;
+            if table[mid].numeric < numeric {
+                low = mid + 1;
+            } else if table[mid].numeric > numeric {
+                high = mid;
+            } else {
+                return Some(table[mid].country)
+            }
+        }
+        None
+    }
+
+    /// An alpha-2 code, alpha-3 code or decimal numeric code, as used by the
+    /// `country!` macro.
+    pub const fn from_code_const(code: &[u8]) -> Option<Country> {
+        if code.is_empty() || !code[0].is_ascii_digit() {
+            return match code.len() {
+                2 => Country::from_alpha2_const(code),
+                _ => Country::from_alpha3_const(code),
+            }
+        }
+        let mut numeric : u16 = 0;
+        let mut i = 0;
+        while i < code.len() {
+            if i >= 3 || !code[i].is_ascii_digit() {
+                return None
+            }
+            numeric = numeric * 10 + (code[i] - b'0') as u16;
+            i += 1;
+        }
+        Country::from_numeric_const(numeric)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+
+    const POLAND : Country = crate::country!("PL");
+
+    #[test]
+    fn const_lookups() {
+        assert_eq!(Country::PL, POLAND);
+        assert_eq!(Some(Country::PL), Country::from_alpha2_const(b"PL"));
+        assert_eq!(None, Country::from_alpha2_const(b""));
+        assert_eq!(None, Country::from_alpha2_const(b"XY"));
+        assert_eq!(Some(Country::PL), Country::from_alpha3_const(b"POL"));
+        assert_eq!(None, Country::from_alpha3_const(b"PO"));
+        assert_eq!(Some(Country::AF), Country::from_numeric_const(4));
+        assert_eq!(None, Country::from_numeric_const(0));
+    }
+
+    #[test]
+    fn macro_forms() {
+        assert_eq!(Country::DE, crate::country!("DE"));
+        assert_eq!(Country::DE, crate::country!("DEU"));
+        assert_eq!(Country::DE, crate::country!(276));
+        assert_eq!(Country::AF, crate::country!("004"));
+    }
+
+    #[test]
+    fn matches_runtime_lookups() {
+        for country in Country::iter() {
+            assert_eq!(Some(country), Country::from_alpha2_const(country.alpha2().as_bytes()));
+            assert_eq!(Some(country), Country::from_alpha3_const(country.alpha3().as_bytes()));
+            assert_eq!(Some(country), Country::from_numeric_const(country.numeric()));
+        }
+    }
+}
@@ -0,0 +1,479 @@
+// Locale identifiers.
+//
+// Accepts BCP 47 language tags ("en-GB", "zh-Hant-TW", "es-419") as well as
+// POSIX locale names ("de_AT.UTF-8", "sr_RS@latin") and extracts the region
+// subtag.
+
+use std::str::FromStr;
+
+use crate::Country;
+
+/// The region subtag of a locale.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Region {
+    Country(Country),
+    /// A UN M49 area code, e.g. 419 for Latin America and the Caribbean.
+    M49(u16),
+}
+
+impl Region {
+    pub fn country(&self) -> Option<Country> {
+        match *self {
+            Region::Country(country) => Some(country),
+            Region::M49(_) => None,
+        }
+    }
+}
+
+/// The language, script and region subtags of a locale identifier.
+///
+/// Variants, extensions and POSIX codesets are skipped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Locale<'a> {
+    pub language: &'a str,
+    pub script: Option<&'a str>,
+    pub region: Option<Region>,
+}
+
+/// Parses a BCP 47 language tag or a POSIX locale name.
+///
+/// Returns `None` if the identifier has no valid language subtag, which is
+/// also the case for the POSIX "C" locale.
+pub fn parse(s: &str) -> Option<Locale<'_>> {
+    let s = s.trim();
+    let end = s.find(['.', '@']).unwrap_or(s.len());
+    let mut subtags = s[..end].split(['-', '_']).peekable();
+
+    let language = subtags.next()?;
+    if !is_alpha(language, 2, 8) || language.eq_ignore_ascii_case("posix") {
+        return None;
+    }
+
+    let mut extlangs = 0;
+    while language.len() <= 3 && extlangs < 3 && subtags.peek().is_some_and(|t| is_alpha(t, 3, 3)) {
+        subtags.next();
+        extlangs += 1;
+    }
+
+    let script = subtags.next_if(|t| is_alpha(t, 4, 4));
+    let region = subtags.next().and_then(region);
+
+    Some(Locale { language, script, region })
+}
+
+fn region(subtag: &str) -> Option<Region> {
+    if is_alpha(subtag, 2, 2) {
+        let code = subtag.to_ascii_uppercase();
+        return Country::from_str(&code).ok().map(Region::Country);
+    }
+    if subtag.len() != 3 || !subtag.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let num: u16 = subtag.parse().ok()?;
+    if M49_AREAS.contains(&num) {
+        return Some(Region::M49(num));
+    }
+    crate::COUNTRY_CODE_SEARCH_TABLE.iter()
+        .find(|&&(_, country)| country != Country::Unspecified && country as u16 == num)
+        .map(|&(_, country)| Region::Country(country))
+}
+
+/// The most likely country for a language, following CLDR likely subtags
+/// ("en" is United States, "pt" is Brazil).
+///
+/// The script is taken into account where it implies a different region,
+/// e.g. Traditional Chinese ("zh-Hant") is Taiwan.
+pub fn likely_country(language: &str, script: Option<&str>) -> Option<Country> {
+    let language = language.to_ascii_lowercase();
+    if let Some(script) = script {
+        let script = script.to_ascii_lowercase();
+        let country = match (language.as_str(), script.as_str()) {
+            ("zh", "hant") => Some(Country::TW),
+            ("pa", "arab") | ("sd", "arab") => Some(Country::PK),
+            ("uz", "arab") => Some(Country::AF),
+            ("az", "arab") => Some(Country::IR),
+            ("mn", "mong") => Some(Country::CN),
+            ("sd", "deva") => Some(Country::IN),
+            _ => None
+        };
+        if country.is_some() {
+            return country;
+        }
+    }
+    match LIKELY_COUNTRY_SEARCH_TABLE.binary_search_by(|&(o, _)| o.cmp(&language)) {
+        Ok(pos) => Some(LIKELY_COUNTRY_SEARCH_TABLE[pos].1),
+        Err(_)  => None
+    }
+}
+
+fn is_alpha(s: &str, min: usize, max: usize) -> bool {
+    s.len() >= min && s.len() <= max && s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+// M49 area codes registered as BCP 47 region subtags.
+const M49_AREAS : &[u16] = &[
+    1, 2, 3, 5, 9, 11, 13, 14, 15, 17, 18, 19, 21, 29, 30, 34, 35, 39, 53, 54, 57, 61, 142, 143,
+    145, 150, 151, 154, 155, 202, 419,
+];
+
+const LIKELY_COUNTRY_SEARCH_TABLE : &[(&str, Country)] = &[
+    ("af", Country::ZA), ("ak", Country::GH), ("am", Country::ET), ("ar", Country::EG),
+    ("as", Country::IN), ("az", Country::AZ), ("be", Country::BY), ("bg", Country::BG),
+    ("bi", Country::VU), ("bm", Country::ML), ("bn", Country::BD), ("bo", Country::CN),
+    ("br", Country::FR), ("bs", Country::BA), ("ca", Country::ES), ("ch", Country::GU),
+    ("ckb", Country::IQ), ("cs", Country::CZ), ("cy", Country::GB), ("da", Country::DK),
+    ("de", Country::DE), ("dv", Country::MV), ("dz", Country::BT), ("ee", Country::GH),
+    ("el", Country::GR), ("en", Country::US), ("es", Country::ES), ("et", Country::EE),
+    ("eu", Country::ES), ("fa", Country::IR), ("ff", Country::SN), ("fi", Country::FI),
+    ("fil", Country::PH), ("fj", Country::FJ), ("fo", Country::FO), ("fr", Country::FR),
+    ("fy", Country::NL), ("ga", Country::IE), ("gd", Country::GB), ("gl", Country::ES),
+    ("gn", Country::PY), ("gu", Country::IN), ("gv", Country::IM), ("ha", Country::NG),
+    ("haw", Country::US), ("he", Country::IL), ("hi", Country::IN), ("hr", Country::HR),
+    ("ht", Country::HT), ("hu", Country::HU), ("hy", Country::AM), ("id", Country::ID),
+    ("ig", Country::NG), ("in", Country::ID), ("is", Country::IS), ("it", Country::IT),
+    ("iw", Country::IL), ("ja", Country::JP), ("jv", Country::ID), ("ka", Country::GE),
+    ("kea", Country::CV), ("kk", Country::KZ), ("kl", Country::GL), ("km", Country::KH),
+    ("kn", Country::IN), ("ko", Country::KR), ("ks", Country::IN), ("ku", Country::TR),
+    ("ky", Country::KG), ("la", Country::VA), ("lb", Country::LU), ("lg", Country::UG),
+    ("ln", Country::CD), ("lo", Country::LA), ("lt", Country::LT), ("lu", Country::CD),
+    ("lv", Country::LV), ("mfe", Country::MU), ("mg", Country::MG), ("mh", Country::MH),
+    ("mi", Country::NZ), ("mk", Country::MK), ("ml", Country::IN), ("mn", Country::MN),
+    ("mr", Country::IN), ("ms", Country::MY), ("mt", Country::MT), ("my", Country::MM),
+    ("na", Country::NR), ("nb", Country::NO), ("nd", Country::ZW), ("ne", Country::NP),
+    ("nl", Country::NL), ("nn", Country::NO), ("no", Country::NO), ("ny", Country::MW),
+    ("om", Country::ET), ("or", Country::IN), ("pa", Country::IN), ("pap", Country::CW),
+    ("pl", Country::PL), ("ps", Country::AF), ("pt", Country::BR), ("qu", Country::PE),
+    ("rm", Country::CH), ("rn", Country::BI), ("ro", Country::RO), ("ru", Country::RU),
+    ("rw", Country::RW), ("sd", Country::PK), ("se", Country::NO), ("sg", Country::CF),
+    ("si", Country::LK), ("sk", Country::SK), ("sl", Country::SI), ("sm", Country::WS),
+    ("sn", Country::ZW), ("so", Country::SO), ("sq", Country::AL), ("sr", Country::RS),
+    ("ss", Country::ZA), ("st", Country::ZA), ("sv", Country::SE), ("sw", Country::TZ),
+    ("ta", Country::IN), ("te", Country::IN), ("tet", Country::TL), ("tg", Country::TJ),
+    ("th", Country::TH), ("ti", Country::ET), ("tk", Country::TM), ("tl", Country::PH),
+    ("tn", Country::ZA), ("to", Country::TO), ("tpi", Country::PG), ("tr", Country::TR),
+    ("ts", Country::ZA), ("tt", Country::RU), ("ug", Country::CN), ("uk", Country::UA),
+    ("ur", Country::PK), ("uz", Country::UZ), ("ve", Country::ZA), ("vi", Country::VN),
+    ("wo", Country::SN), ("xh", Country::ZA), ("yo", Country::NG), ("zgh", Country::MA),
+    ("zh", Country::CN), ("zu", Country::ZA),
+];
+
+impl Country {
+    /// The region of a BCP 47 language tag or POSIX locale name.  Numeric
+    /// subtags map to an M49 area unless they are a country's numeric code.
+    pub fn from_locale(s: &str) -> Option<Region> {
+        parse(s)?.region
+    }
+
+    /// Common BCP 47 locales for this country, most widely used first.
+    pub fn locales(&self) -> &'static [&'static str] {
+        use Country::*;
+        match *self {
+            Unspecified => &[],
+            AD => &["ca-AD"],
+            AE => &["ar-AE", "en-AE"],
+            AF => &["ps-AF", "fa-AF"],
+            AG => &["en-AG"],
+            AI => &["en-AI"],
+            AL => &["sq-AL"],
+            AM => &["hy-AM"],
+            AO => &["pt-AO"],
+            AQ => &[],
+            AR => &["es-AR"],
+            AS => &["en-AS", "sm-AS"],
+            AT => &["de-AT"],
+            AU => &["en-AU"],
+            AW => &["nl-AW", "pap-AW"],
+            AX => &["sv-AX"],
+            AZ => &["az-AZ"],
+            BA => &["bs-BA", "hr-BA", "sr-BA"],
+            BB => &["en-BB"],
+            BD => &["bn-BD"],
+            BE => &["nl-BE", "fr-BE", "de-BE"],
+            BF => &["fr-BF"],
+            BG => &["bg-BG"],
+            BH => &["ar-BH"],
+            BI => &["rn-BI", "fr-BI", "en-BI"],
+            BJ => &["fr-BJ", "yo-BJ"],
+            BL => &["fr-BL"],
+            BM => &["en-BM"],
+            BN => &["ms-BN"],
+            BO => &["es-BO", "qu-BO"],
+            BQ => &["nl-BQ", "pap-BQ"],
+            BR => &["pt-BR"],
+            BS => &["en-BS"],
+            BT => &["dz-BT"],
+            BV => &[],
+            BW => &["en-BW", "tn-BW"],
+            BY => &["be-BY", "ru-BY"],
+            BZ => &["en-BZ", "es-BZ"],
+            CA => &["en-CA", "fr-CA"],
+            CC => &["en-CC", "ms-CC"],
+            CD => &["fr-CD", "ln-CD", "sw-CD"],
+            CF => &["fr-CF", "sg-CF"],
+            CG => &["fr-CG", "ln-CG"],
+            CH => &["de-CH", "fr-CH", "it-CH", "rm-CH"],
+            CI => &["fr-CI"],
+            CK => &["en-CK"],
+            CL => &["es-CL"],
+            CM => &["fr-CM", "en-CM"],
+            CN => &["zh-CN"],
+            CO => &["es-CO"],
+            CR => &["es-CR"],
+            CU => &["es-CU"],
+            CV => &["pt-CV", "kea-CV"],
+            CW => &["nl-CW", "pap-CW"],
+            CX => &["en-CX"],
+            CY => &["el-CY", "tr-CY"],
+            CZ => &["cs-CZ"],
+            DE => &["de-DE"],
+            DJ => &["fr-DJ", "ar-DJ", "so-DJ"],
+            DK => &["da-DK"],
+            DM => &["en-DM"],
+            DO => &["es-DO"],
+            DZ => &["ar-DZ", "fr-DZ"],
+            EC => &["es-EC"],
+            EE => &["et-EE"],
+            EG => &["ar-EG"],
+            EH => &["ar-EH"],
+            ER => &["ti-ER", "ar-ER", "en-ER"],
+            ES => &["es-ES", "ca-ES", "gl-ES", "eu-ES"],
+            ET => &["am-ET", "om-ET", "ti-ET", "so-ET"],
+            FI => &["fi-FI", "sv-FI"],
+            FJ => &["en-FJ", "fj-FJ"],
+            FK => &["en-FK"],
+            FM => &["en-FM"],
+            FO => &["fo-FO"],
+            FR => &["fr-FR"],
+            GA => &["fr-GA"],
+            GB => &["en-GB", "cy-GB", "gd-GB"],
+            GD => &["en-GD"],
+            GE => &["ka-GE"],
+            GF => &["fr-GF"],
+            GG => &["en-GG"],
+            GH => &["en-GH", "ak-GH", "ee-GH"],
+            GI => &["en-GI"],
+            GL => &["kl-GL", "da-GL"],
+            GM => &["en-GM"],
+            GN => &["fr-GN"],
+            GP => &["fr-GP"],
+            GQ => &["es-GQ", "fr-GQ", "pt-GQ"],
+            GR => &["el-GR"],
+            GS => &["en-GS"],
+            GT => &["es-GT"],
+            GU => &["en-GU", "ch-GU"],
+            GW => &["pt-GW"],
+            GY => &["en-GY"],
+            HK => &["zh-HK", "en-HK"],
+            HM => &[],
+            HN => &["es-HN"],
+            HR => &["hr-HR"],
+            HT => &["ht-HT", "fr-HT"],
+            HU => &["hu-HU"],
+            ID => &["id-ID", "jv-ID"],
+            IE => &["en-IE", "ga-IE"],
+            IL => &["he-IL", "ar-IL"],
+            IM => &["en-IM", "gv-IM"],
+            IN => &[
+                "hi-IN", "en-IN", "bn-IN", "te-IN", "mr-IN", "ta-IN", "ur-IN", "gu-IN", "kn-IN",
+                "ml-IN", "or-IN", "pa-IN",
+            ],
+            IO => &["en-IO"],
+            IQ => &["ar-IQ", "ckb-IQ"],
+            IR => &["fa-IR"],
+            IS => &["is-IS"],
+            IT => &["it-IT"],
+            JE => &["en-JE"],
+            JM => &["en-JM"],
+            JO => &["ar-JO"],
+            JP => &["ja-JP"],
+            KE => &["sw-KE", "en-KE"],
+            KG => &["ky-KG", "ru-KG"],
+            KH => &["km-KH"],
+            KI => &["en-KI"],
+            KM => &["ar-KM", "fr-KM"],
+            KN => &["en-KN"],
+            KP => &["ko-KP"],
+            KR => &["ko-KR"],
+            KW => &["ar-KW"],
+            KY => &["en-KY"],
+            KZ => &["kk-KZ", "ru-KZ"],
+            LA => &["lo-LA"],
+            LB => &["ar-LB", "fr-LB"],
+            LC => &["en-LC"],
+            LI => &["de-LI"],
+            LK => &["si-LK", "ta-LK"],
+            LR => &["en-LR"],
+            LS => &["st-LS", "en-LS"],
+            LT => &["lt-LT"],
+            LU => &["lb-LU", "fr-LU", "de-LU"],
+            LV => &["lv-LV"],
+            LY => &["ar-LY"],
+            MA => &["ar-MA", "fr-MA", "zgh-MA"],
+            MC => &["fr-MC"],
+            MD => &["ro-MD", "ru-MD"],
+            ME => &["sr-Latn-ME"],
+            MF => &["fr-MF"],
+            MG => &["mg-MG", "fr-MG"],
+            MH => &["en-MH", "mh-MH"],
+            MK => &["mk-MK", "sq-MK"],
+            ML => &["fr-ML", "bm-ML"],
+            MM => &["my-MM"],
+            MN => &["mn-MN"],
+            MO => &["zh-MO", "pt-MO"],
+            MP => &["en-MP"],
+            MQ => &["fr-MQ"],
+            MR => &["ar-MR"],
+            MS => &["en-MS"],
+            MT => &["mt-MT", "en-MT"],
+            MU => &["en-MU", "fr-MU", "mfe-MU"],
+            MV => &["dv-MV"],
+            MW => &["en-MW", "ny-MW"],
+            MX => &["es-MX"],
+            MY => &["ms-MY", "en-MY", "zh-MY", "ta-MY"],
+            MZ => &["pt-MZ"],
+            NA => &["en-NA", "af-NA"],
+            NC => &["fr-NC"],
+            NE => &["fr-NE", "ha-NE"],
+            NF => &["en-NF"],
+            NG => &["en-NG", "ha-NG", "yo-NG", "ig-NG"],
+            NI => &["es-NI"],
+            NL => &["nl-NL", "fy-NL"],
+            NO => &["nb-NO", "nn-NO"],
+            NP => &["ne-NP"],
+            NR => &["en-NR", "na-NR"],
+            NU => &["en-NU"],
+            NZ => &["en-NZ", "mi-NZ"],
+            OM => &["ar-OM"],
+            PA => &["es-PA"],
+            PE => &["es-PE", "qu-PE"],
+            PF => &["fr-PF"],
+            PG => &["en-PG", "tpi-PG"],
+            PH => &["fil-PH", "en-PH"],
+            PK => &["ur-PK", "en-PK"],
+            PL => &["pl-PL"],
+            PM => &["fr-PM"],
+            PN => &["en-PN"],
+            PR => &["es-PR", "en-PR"],
+            PS => &["ar-PS"],
+            PT => &["pt-PT"],
+            PW => &["en-PW"],
+            PY => &["es-PY", "gn-PY"],
+            QA => &["ar-QA"],
+            RE => &["fr-RE"],
+            RO => &["ro-RO"],
+            RS => &["sr-RS", "sr-Latn-RS"],
+            RU => &["ru-RU"],
+            RW => &["rw-RW", "en-RW", "fr-RW"],
+            SA => &["ar-SA"],
+            SB => &["en-SB"],
+            SC => &["fr-SC", "en-SC"],
+            SD => &["ar-SD", "en-SD"],
+            SE => &["sv-SE"],
+            SG => &["en-SG", "zh-SG", "ms-SG", "ta-SG"],
+            SH => &["en-SH"],
+            SI => &["sl-SI"],
+            SJ => &["nb-SJ"],
+            SK => &["sk-SK"],
+            SL => &["en-SL"],
+            SM => &["it-SM"],
+            SN => &["fr-SN", "wo-SN"],
+            SO => &["so-SO", "ar-SO"],
+            SR => &["nl-SR"],
+            SS => &["en-SS"],
+            ST => &["pt-ST"],
+            SV => &["es-SV"],
+            SX => &["nl-SX", "en-SX"],
+            SY => &["ar-SY"],
+            SZ => &["en-SZ", "ss-SZ"],
+            TC => &["en-TC"],
+            TD => &["fr-TD", "ar-TD"],
+            TF => &["fr-TF"],
+            TG => &["fr-TG", "ee-TG"],
+            TH => &["th-TH"],
+            TJ => &["tg-TJ"],
+            TK => &["en-TK"],
+            TL => &["pt-TL", "tet-TL"],
+            TM => &["tk-TM"],
+            TN => &["ar-TN", "fr-TN"],
+            TO => &["to-TO", "en-TO"],
+            TR => &["tr-TR"],
+            TT => &["en-TT"],
+            TV => &["en-TV"],
+            TW => &["zh-TW"],
+            TZ => &["sw-TZ", "en-TZ"],
+            UA => &["uk-UA", "ru-UA"],
+            UG => &["en-UG", "sw-UG", "lg-UG"],
+            UM => &["en-UM"],
+            US => &["en-US", "es-US"],
+            UY => &["es-UY"],
+            UZ => &["uz-UZ"],
+            VA => &["it-VA", "la-VA"],
+            VC => &["en-VC"],
+            VE => &["es-VE"],
+            VG => &["en-VG"],
+            VI => &["en-VI"],
+            VN => &["vi-VN"],
+            VU => &["bi-VU", "en-VU", "fr-VU"],
+            WF => &["fr-WF"],
+            WS => &["sm-WS", "en-WS"],
+            YE => &["ar-YE"],
+            YT => &["fr-YT"],
+            ZA => &["en-ZA", "zu-ZA", "xh-ZA", "af-ZA"],
+            ZM => &["en-ZM"],
+            ZW => &["en-ZW", "sn-ZW", "nd-ZW"],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ likely_country, parse, Region };
+
+    #[test]
+    fn from_locale() {
+        let country = |s| Country::from_locale(s).and_then(|r| r.country());
+        assert_eq!(Some(Country::GB), country("en-GB"));
+        assert_eq!(Some(Country::AT), country("de_AT.UTF-8"));
+        assert_eq!(Some(Country::TW), country("zh-Hant-TW"));
+        assert_eq!(Some(Country::RS), country("sr-Latn-RS"));
+        assert_eq!(Some(Country::RS), country("sr_RS@latin"));
+        assert_eq!(Some(Country::HK), country("zh-yue-HK"));
+        assert_eq!(Some(Country::PL), country("pl-616"));
+        assert_eq!(Some(Region::M49(419)), Country::from_locale("es-419"));
+        assert_eq!(None, Country::from_locale("en"));
+        assert_eq!(None, Country::from_locale("C.UTF-8"));
+        assert_eq!(None, Country::from_locale("POSIX"));
+    }
+
+    #[test]
+    fn parse_subtags() {
+        let locale = parse("zh-Hant-TW").unwrap();
+        assert_eq!("zh", locale.language);
+        assert_eq!(Some("Hant"), locale.script);
+        let locale = parse("de-CH-1996").unwrap();
+        assert_eq!(None, locale.script);
+        assert_eq!(Some(Region::Country(Country::CH)), locale.region);
+    }
+
+    #[test]
+    fn likely() {
+        assert_eq!(Some(Country::PL), likely_country("pl", None));
+        assert_eq!(Some(Country::US), likely_country("EN", None));
+        assert_eq!(Some(Country::TW), likely_country("zh", Some("Hant")));
+        assert_eq!(Some(Country::CN), likely_country("zh", Some("Hans")));
+        assert_eq!(None, likely_country("tlh", None));
+        let table = super::LIKELY_COUNTRY_SEARCH_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn locales() {
+        assert_eq!(&["de-CH", "fr-CH", "it-CH", "rm-CH"], Country::CH.locales());
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            for locale in country.locales() {
+                assert_eq!(Some(Region::Country(country)), Country::from_locale(locale));
+            }
+        }
+    }
+}
@@ -0,0 +1,1833 @@
+// Telephone numbering plans.
+//
+// The data is taken from Google's libphonenumber metadata.  Number ranges
+// are kept as the regular expressions used there, which only use digits,
+// "\d", character classes, groups, alternatives and counted repetition, so
+// a small matcher is enough to evaluate them.
+
+use std::fmt;
+use std::error::Error;
+
+use crate::Country;
+
+/// The numbering plan of a country.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PhonePlan {
+    /// The country calling code, e.g. 48 for Poland.
+    pub calling_code: u16,
+    /// The prefix dialed to reach another country, e.g. "00".
+    pub international_prefix: &'static str,
+    /// All prefixes which may be dialed to reach another country, as a
+    /// regular expression.
+    pub international_prefixes: &'static str,
+    /// The prefix dialed before a national number within the country,
+    /// e.g. "0" in the United Kingdom.
+    pub trunk_prefix: Option<&'static str>,
+    /// The possible lengths of a national significant number.
+    pub lengths: &'static [u8],
+    /// All valid national significant numbers, as a regular expression.
+    pub pattern: &'static str,
+    /// Fixed-line number ranges, as a regular expression.
+    pub fixed_line: &'static str,
+    /// Mobile number ranges, as a regular expression.
+    pub mobile: &'static str,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NumberType {
+    FixedLine,
+    Mobile,
+    /// Fixed-line and mobile numbers share the same ranges, as in the
+    /// United States.
+    FixedLineOrMobile,
+    /// Toll-free, premium rate, VoIP, pager and other numbers.
+    Other,
+}
+
+impl PhonePlan {
+    /// Whether a national significant number is a valid number.
+    pub fn is_valid(&self, number: &str) -> bool {
+        self.has_length(number) && full_match(self.pattern, number)
+    }
+
+    fn has_length(&self, number: &str) -> bool {
+        u8::try_from(number.len()).is_ok_and(|len| self.lengths.contains(&len))
+    }
+
+    pub fn is_fixed_line(&self, number: &str) -> bool {
+        self.is_valid(number) && full_match(self.fixed_line, number)
+    }
+
+    pub fn is_mobile(&self, number: &str) -> bool {
+        self.is_valid(number) && full_match(self.mobile, number)
+    }
+
+    /// The type of a national significant number, or `None` if the number
+    /// is not valid.
+    pub fn number_type(&self, number: &str) -> Option<NumberType> {
+        if !self.is_valid(number) {
+            return None;
+        }
+        Some(match (full_match(self.fixed_line, number), full_match(self.mobile, number)) {
+            (true, true) => NumberType::FixedLineOrMobile,
+            (true, false) => NumberType::FixedLine,
+            (false, true) => NumberType::Mobile,
+            (false, false) => NumberType::Other,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// "+48123456789"
+    E164,
+    /// "12 345 67 89"
+    National,
+    /// "+48 12 345 67 89"
+    International,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PhoneError {
+    /// The country has no numbering plan of its own (e.g. Antarctica).
+    NoPlan,
+    InvalidCharacter,
+    /// The number has a calling code of another country.
+    WrongCountry,
+    InvalidLength,
+    InvalidNumber,
+}
+
+impl Error for PhoneError {
+    fn description(&self) -> &str { "error parsing phone number" }
+}
+
+impl fmt::Display for PhoneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Parses a phone number dialed in or to a country and returns its national
+/// significant number.
+///
+/// The number may be given in international form ("+48 12 345 67 89" or
+/// "0048 12 345 67 89") or in national form, with or without the trunk
+/// prefix.  Spaces, dashes, dots, slashes and parentheses are ignored.
+pub fn parse_phone(country: Country, number: &str) -> Result<String, PhoneError> {
+    let plan = country.phone_plan().ok_or(PhoneError::NoPlan)?;
+    let number = number.trim();
+    let (international, rest) = match number.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, number),
+    };
+    if !rest.chars().all(|c| c.is_ascii_digit() || " -./()".contains(c)) {
+        return Err(PhoneError::InvalidCharacter);
+    }
+    let digits: String = rest.chars().filter(|c| c.is_ascii_digit()).collect();
+    let code = plan.calling_code.to_string();
+
+    if international {
+        return match digits.strip_prefix(&code) {
+            Some(national) => validate(&plan, national),
+            None => Err(PhoneError::WrongCountry),
+        };
+    }
+    for end in ends(&parse(plan.international_prefixes), digits.as_bytes(), 0).into_iter().rev() {
+        if end == 0 {
+            continue;
+        }
+        if let Some(national) = digits[end..].strip_prefix(&code) {
+            if plan.is_valid(national) {
+                return Ok(national.to_string());
+            }
+        }
+    }
+    if let Some(national) = plan.trunk_prefix.and_then(|prefix| digits.strip_prefix(prefix)) {
+        if plan.is_valid(national) {
+            return Ok(national.to_string());
+        }
+    }
+    validate(&plan, &digits)
+}
+
+/// Parses a phone number like [`parse_phone`] and formats it.
+pub fn format_phone(country: Country, number: &str, format: Format) -> Result<String, PhoneError> {
+    let number = parse_phone(country, number)?;
+    let plan = country.phone_plan().ok_or(PhoneError::NoPlan)?;
+    let code = plan.calling_code;
+    let layout = number_formats(country).iter().find(|&&NumberFormat(pattern, leading_digits, ..)| {
+        (leading_digits.is_empty() || !ends(&parse(leading_digits), number.as_bytes(), 0).is_empty())
+            && full_match(pattern, &number)
+    });
+    Ok(match (format, layout) {
+        (Format::E164, _) | (Format::International, None) => format!("+{}{}", code, number),
+        (Format::National, None) => number,
+        (Format::International, Some(layout)) => match layout.3 {
+            Some("NA") => format!("+{}{}", code, number),
+            Some(international) => format!("+{} {}", code, layout.apply(international, &number)),
+            None => format!("+{} {}", code, layout.apply(layout.2, &number)),
+        },
+        (Format::National, Some(layout)) => match layout.4 {
+            Some(rule) => layout.apply(&layout.2.replacen("$1", &rule.replace("$FG", "$1"), 1), &number),
+            None => layout.apply(layout.2, &number),
+        },
+    })
+}
+
+fn validate(plan: &PhonePlan, number: &str) -> Result<String, PhoneError> {
+    if !plan.has_length(number) {
+        Err(PhoneError::InvalidLength)
+    } else if !full_match(plan.pattern, number) {
+        Err(PhoneError::InvalidNumber)
+    } else {
+        Ok(number.to_string())
+    }
+}
+
+// The pattern splitting the number into groups, the leading digits the
+// format applies to, the national format, the international format ("NA"
+// if the number is not formatted internationally) and the rule for
+// placing the trunk prefix before the first group ("$FG").
+struct NumberFormat(&'static str, &'static str, &'static str, Option<&'static str>, Option<&'static str>);
+
+impl NumberFormat {
+    // Splits the number into the groups of the pattern and substitutes them
+    // for "$1", "$2", ... in the format.
+    fn apply(&self, format: &str, number: &str) -> String {
+        let groups = parse_sequence(&mut self.0.as_bytes());
+        let mut bounds = vec![];
+        split(&groups, number.as_bytes(), 0, &mut bounds);
+        let mut result = format.to_string();
+        for (i, window) in bounds.windows(2).enumerate().rev() {
+            result = result.replace(&format!("${}", i + 1), &number[window[0]..window[1]]);
+        }
+        result
+    }
+}
+
+fn split(nodes: &[Node], s: &[u8], pos: usize, bounds: &mut Vec<usize>) -> bool {
+    bounds.push(pos);
+    let found = match nodes.split_first() {
+        None => pos == s.len(),
+        Some((node, rest)) => ends(std::slice::from_ref(node), s, pos).into_iter().rev()
+            .any(|end| split(rest, s, end, bounds)),
+    };
+    if !found {
+        bounds.pop();
+    }
+    found
+}
+
+#[derive(Debug)]
+enum Node {
+    // A set of digits as a bit mask.
+    Digits(u16),
+    Group(Vec<Vec<Node>>),
+    Repeat(Box<Node>, usize, usize),
+}
+
+fn full_match(pattern: &str, s: &str) -> bool {
+    ends(&parse(pattern), s.as_bytes(), 0).contains(&s.len())
+}
+
+fn parse(pattern: &str) -> Vec<Node> {
+    let mut bytes = pattern.as_bytes();
+    let alternatives = parse_alternatives(&mut bytes);
+    debug_assert!(bytes.is_empty(), "{}", pattern);
+    vec![Node::Group(alternatives)]
+}
+
+fn parse_alternatives(s: &mut &[u8]) -> Vec<Vec<Node>> {
+    let mut alternatives = vec![parse_sequence(s)];
+    while let Some((b'|', rest)) = s.split_first() {
+        *s = rest;
+        alternatives.push(parse_sequence(s));
+    }
+    alternatives
+}
+
+fn parse_sequence(s: &mut &[u8]) -> Vec<Node> {
+    let mut nodes = vec![];
+    while let Some((&c, rest)) = s.split_first() {
+        let node = match c {
+            b'|' | b')' => break,
+            b'0'..=b'9' => {
+                *s = rest;
+                Node::Digits(1 << (c - b'0'))
+            },
+            b'\\' => {
+                debug_assert_eq!(Some(&b'd'), rest.first());
+                *s = &rest[1..];
+                Node::Digits(0x3ff)
+            },
+            b'[' => {
+                let close = rest.iter().position(|&c| c == b']').unwrap_or(rest.len());
+                let mut set = 0;
+                let mut i = 0;
+                while i < close {
+                    let (lo, hi) = match rest.get(i + 1) {
+                        Some(b'-') if i + 2 < close => (rest[i], rest[i + 2]),
+                        _ => (rest[i], rest[i]),
+                    };
+                    for d in lo..=hi {
+                        set |= 1 << (d - b'0');
+                    }
+                    i += if lo == hi && rest.get(i + 1) != Some(&b'-') { 1 } else { 3 };
+                }
+                *s = rest.get(close + 1..).unwrap_or(&[]);
+                Node::Digits(set)
+            },
+            b'(' => {
+                *s = rest.strip_prefix(b"?:").unwrap_or(rest);
+                let alternatives = parse_alternatives(s);
+                *s = s.get(1..).unwrap_or(&[]);
+                Node::Group(alternatives)
+            },
+            _ => panic!("unsupported pattern character {:?}", c as char),
+        };
+        nodes.push(parse_repeat(s, node));
+    }
+    nodes
+}
+
+fn parse_repeat(s: &mut &[u8], node: Node) -> Node {
+    match s.split_first() {
+        Some((b'?', rest)) => {
+            *s = rest;
+            Node::Repeat(Box::new(node), 0, 1)
+        },
+        Some((b'{', rest)) => {
+            let close = rest.iter().position(|&c| c == b'}').unwrap_or(rest.len());
+            let bounds = std::str::from_utf8(&rest[..close]).unwrap_or("");
+            let (min, max) = bounds.split_once(',').unwrap_or((bounds, bounds));
+            *s = rest.get(close + 1..).unwrap_or(&[]);
+            Node::Repeat(Box::new(node), min.parse().unwrap_or(0), max.parse().unwrap_or(0))
+        },
+        _ => node,
+    }
+}
+
+// The positions at which a match of `nodes` starting at `start` may end, in
+// ascending order.
+fn ends(nodes: &[Node], s: &[u8], start: usize) -> Vec<usize> {
+    let mut positions = vec![start];
+    for node in nodes {
+        let mut next = vec![];
+        for &pos in &positions {
+            match node {
+                Node::Digits(set) => {
+                    if let Some(&c) = s.get(pos) {
+                        if c.is_ascii_digit() && set & (1 << (c - b'0')) != 0 {
+                            next.push(pos + 1);
+                        }
+                    }
+                },
+                Node::Group(alternatives) => {
+                    for alternative in alternatives {
+                        next.extend(ends(alternative, s, pos));
+                    }
+                },
+                Node::Repeat(node, min, max) => {
+                    let mut current = vec![pos];
+                    for count in 0..=*max {
+                        if count >= *min {
+                            next.extend(&current);
+                        }
+                        if count == *max || current.is_empty() {
+                            break;
+                        }
+                        let mut following = vec![];
+                        for &p in &current {
+                            following.extend(ends(std::slice::from_ref(&**node), s, p));
+                        }
+                        following.sort_unstable();
+                        following.dedup();
+                        current = following;
+                    }
+                },
+            }
+        }
+        next.sort_unstable();
+        next.dedup();
+        positions = next;
+    }
+    positions
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn p(calling_code: u16, international_prefixes: &'static str, international_prefix: &'static str,
+           trunk_prefix: Option<&'static str>, lengths: &'static [u8],
+           pattern: &'static str, fixed_line: &'static str, mobile: &'static str) -> PhonePlan {
+    PhonePlan { calling_code, international_prefix, international_prefixes, trunk_prefix, lengths, pattern, fixed_line, mobile }
+}
+
+impl Country {
+    /// The telephone numbering plan of this country, or `None` for
+    /// uninhabited territories without one.
+    pub fn phone_plan(&self) -> Option<PhonePlan> {
+        use Country::*;
+        Some(match *self {
+            Unspecified => return None,
+            AD => p(376, "00", "00", None, &[6, 8, 9], r"(?:1|6\d)\d{7}|[135-9]\d{5}", r"[78]\d{5}", r"690\d{6}|[356]\d{5}"),
+            AE => p(971, "00", "00", Some("0"), &[5, 6, 7, 8, 9, 10, 11, 12], r"(?:[4-7]\d|9[0-689])\d{7}|800\d{2,9}|[2-4679]\d{7}", r"[2-4679][2-8]\d{6}", r"5[024-68]\d{7}"),
+            AF => p(93, "00", "00", Some("0"), &[9], r"[2-7]\d{8}", r"(?:[25][0-8]|[34][0-4]|6[0-5])[2-9]\d{6}", r"7\d{8}"),
+            AG => p(1, "011", "011", Some("1"), &[10], r"(?:268|[58]\d\d|900)\d{7}", r"268(?:4(?:6[0-38]|84)|56[0-2])\d{4}", r"268(?:464|7(?:1[3-9]|[28]\d|3[0246]|64|7[0-689]))\d{4}"),
+            AI => p(1, "011", "011", Some("1"), &[10], r"(?:264|[58]\d\d|900)\d{7}", r"264(?:292|4(?:6[12]|9[78]))\d{4}", r"264(?:235|4(?:69|76)|5(?:3[6-9]|8[1-4])|7(?:29|72))\d{4}"),
+            AL => p(355, "00", "00", Some("0"), &[6, 7, 8, 9], r"(?:700\d\d|900)\d{3}|8\d{5,7}|(?:[2-5]|6\d)\d{7}", r"4505[0-2]\d{3}|(?:[2358][16-9]\d[2-9]|4410)\d{4}|(?:[2358][2-5][2-9]|4(?:[2-57-9][2-9]|6\d))\d{5}", r"6(?:[78][2-9]|9\d)\d{6}"),
+            AM => p(374, "00", "00", Some("0"), &[8], r"(?:[1-489]\d|55|60|77)\d{6}", r"(?:(?:1[0-25]|47)\d|2(?:2[2-46]|3[1-8]|4[2-69]|5[2-7]|6[1-9]|8[1-7])|3[12]2)\d{5}", r"(?:33|4[1349]|55|77|88|9[13-9])\d{6}"),
+            AO => p(244, "00", "00", None, &[9], r"[29]\d{8}", r"2\d(?:[0134][25-9]|[25-9]\d)\d{5}", r"9[1-79]\d{7}"),
+            AQ => return None,
+            AR => p(54, "00", "00", Some("0"), &[10, 11], r"(?:11|[89]\d\d)\d{8}|[2368]\d{9}", r"3(?:7(?:1[15]|81)|8(?:21|4[16]|69|9[12]))[46]\d{5}|(?:2(?:2(?:2[59]|44|52)|3(?:26|44)|47[35]|9(?:[07]2|2[26]|34|46))|3327)[45]\d{5}|(?:2(?:657|9(?:54|66))|3(?:48[27]|7(?:55|77)|8(?:65|78)))[2-8]\d{5}|(?:2(?:284|3(?:02|23)|477|622|920)|3(?:4(?:46|89|92)|541))[2-7]\d{5}|(?:(?:11[1-8]|670)\d|2(?:2(?:0[45]|1[2-6]|3[3-6])|3(?:[06]4|7[45])|494|6(?:04|1[2-8]|[36][45]|4[3-6])|80[45]|9(?:[17][4-6]|[48][45]|9[3-6]))|3(?:364|4(?:1[2-8]|[25][4-6]|3[3-6]|84)|5(?:1[2-9]|[38][4-6])|6(?:2[45]|44)|7[069][45]|8(?:0[45]|1[2-7]|3[4-6]|5[3-6]|7[2-6]|8[3-68])))\d{6}|(?:2(?:2(?:62|81)|320|9(?:42|83))|3(?:329|4(?:62|7[16])|5(?:43|64)|7(?:18|5[17])))[2-6]\d{5}|2(?:2(?:21|4[23]|6[145]|7[1-4]|8[356]|9[267])|3(?:16|3[13-8]|43|5[346-8]|9[3-5])|6(?:2[46]|4[78]|5[1568])|9(?:03|2[1457-9]|3[1356]|4[08]|[56][23]|82))4\d{5}|(?:2(?:257|3(?:24|46|92)|9(?:01|23|64))|3(?:4(?:42|64)|5(?:25|37|4[47]|71)|7(?:35|72)|825))[3-6]\d{5}|(?:2(?:2(?:02|2[3467]|4[156]|5[45]|6[6-8]|91)|3(?:1[47]|25|[45][25]|96)|47[48]|625|932)|3(?:38[2578]|4(?:0[0-24-9]|3[78]|4[457]|58|6[035-9]|72|83|9[136-8])|5(?:2[124]|[368][23]|4[2689]|7[2-6])|7(?:16|2[15]|3[14]|4[13]|5[468]|7[3-5]|8[26])|8(?:2[67]|3[278]|4[3-5]|5[78]|6[1-378]|[78]7|94)))[4-6]\d{5}", r"93(?:7(?:1[15]|81)|8(?:21|4[16]|69|9[12]))[46]\d{5}|9(?:2(?:2(?:2[59]|44|52)|3(?:26|44)|47[35]|9(?:[07]2|2[26]|34|46))|3327)[45]\d{5}|9(?:2(?:657|9(?:54|66))|3(?:48[27]|7(?:55|77)|8(?:65|78)))[2-8]\d{5}|9(?:2(?:284|3(?:02|23)|477|622|920)|3(?:4(?:46|89|92)|541))[2-7]\d{5}|(?:675\d|9(?:11[1-8]\d|2(?:2(?:0[45]|1[2-6]|3[3-6])|3(?:[06]4|7[45])|494|6(?:04|1[2-8]|[36][45]|4[3-6])|80[45]|9(?:[17][4-6]|[48][45]|9[3-6]))|3(?:364|4(?:1[2-8]|[25][4-6]|3[3-6]|84)|5(?:1[2-9]|[38][4-6])|6(?:2[45]|44)|7[069][45]|8(?:0[45]|1[2-7]|3[4-6]|5[3-6]|7[2-6]|8[3-68]))))\d{6}|9(?:2(?:2(?:62|81)|320|9(?:42|83))|3(?:329|4(?:62|7[16])|5(?:43|64)|7(?:18|5[17])))[2-6]\d{5}|92(?:2(?:21|4[23]|6[145]|7[1-4]|8[356]|9[267])|3(?:16|3[13-8]|43|5[346-8]|9[3-5])|6(?:2[46]|4[78]|5[1568])|9(?:03|2[1457-9]|3[1356]|4[08]|[56][23]|82))4\d{5}|9(?:2(?:257|3(?:24|46|92)|9(?:01|23|64))|3(?:4(?:42|64)|5(?:25|37|4[47]|71)|7(?:35|72)|825))[3-6]\d{5}|9(?:2(?:2(?:02|2[3467]|4[156]|5[45]|6[6-8]|91)|3(?:1[47]|25|[45][25]|96)|47[48]|625|932)|3(?:38[2578]|4(?:0[0-24-9]|3[78]|4[457]|58|6[035-9]|72|83|9[136-8])|5(?:2[124]|[368][23]|4[2689]|7[2-6])|7(?:16|2[15]|3[14]|4[13]|5[468]|7[3-5]|8[26])|8(?:2[67]|3[278]|4[3-5]|5[78]|6[1-378]|[78]7|94)))[4-6]\d{5}"),
+            AS => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|684|900)\d{7}", r"6846(?:22|33|44|55|77|88|9[19])\d{4}", r"684(?:2(?:48|5[2468]|7[26])|7(?:3[13]|70|82))\d{4}"),
+            AT => p(43, "00", "00", Some("0"), &[4, 5, 6, 7, 8, 9, 10, 11, 12, 13], r"1\d{3,12}|2\d{6,12}|43(?:(?:0\d|5[02-9])\d{3,9}|2\d{4,5}|[3467]\d{4}|8\d{4,6}|9\d{4,7})|5\d{4,12}|8\d{7,12}|9\d{8,12}|(?:[367]\d|4[0-24-9])\d{4,11}", r"1(?:11\d|[2-9]\d{3,11})|(?:316|463)\d{3,10}|648[34]\d{3,9}|(?:51|66|73)2\d{3,10}|(?:2(?:1[467]|2[13-8]|5[2357]|6[1-46-8]|7[1-8]|8[124-7]|9[1458])|3(?:1[1-578]|3[23568]|4[5-7]|5[1378]|6[1-38]|8[3-68])|4(?:2[1-8]|35|7[1368]|8[2457])|5(?:2[1-8]|3[357]|4[147]|5[12578]|6[37])|6(?:13|2[1-47]|4[135-7]|5[468])|7(?:2[1-8]|35|4[13478]|5[68]|6[16-8]|7[1-6]|9[45]))\d{4,10}", r"6(?:485|(?:5[0-3579]|6[013-9]|[7-9]\d)\d)\d{3,9}"),
+            AU => p(61, "001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011", "0011", Some("0"), &[5, 6, 7, 8, 9, 10, 12], r"1(?:[0-79]\d{7}(?:\d(?:\d{2})?)?|8[0-24-9]\d{7})|[2-478]\d{8}|1\d{4,7}", r"(?:(?:241|349)0\d\d|8(?:51(?:0(?:0[03-9]|[12479]\d|3[2-9]|5[0-8]|6[1-9]|8[0-7])|1(?:[0235689]\d|1[0-69]|4[0-589]|7[0-47-9])|2(?:0[0-79]|[18][13579]|2[14-9]|3[0-46-9]|[4-6]\d|7[89]|9[0-4])|[34]\d\d)|91(?:(?:[0-58]\d|6[0135-9])\d|7(?:0[0-24-9]|[1-9]\d)|9(?:[0-46-9]\d|5[0-79]))))\d{3}|(?:2(?:[0-26-9]\d|3[0-8]|4[02-9]|5[0135-9])|3(?:[0-3589]\d|4[0-578]|6[1-9]|7[0-35-9])|7(?:[013-57-9]\d|2[0-8])|8(?:55|6[0-8]|[78]\d|9[02-9]))\d{6}", r"4(?:79[01]|83[0-36-9]|95[0-3])\d{5}|4(?:[0-36]\d|4[047-9]|[58][0-24-9]|7[02-8]|9[0-47-9])\d{6}"),
+            AW => p(297, "00", "00", None, &[7], r"(?:[25-79]\d\d|800)\d{4}", r"5(?:2\d|8[1-9])\d{4}", r"(?:290|5[69]\d|6(?:[03]0|22|4[0-2]|[69]\d)|7(?:[34]\d|7[07])|9(?:6[45]|9[4-8]))\d{4}"),
+            AX => p(358, "00|99(?:[01469]|5(?:[14]1|3[23]|5[59]|77|88|9[09]))", "00", Some("0"), &[5, 6, 7, 8, 9, 10, 11, 12], r"2\d{4,9}|35\d{4,5}|(?:60\d\d|800)\d{4,6}|7\d{5,11}|(?:[14]\d|3[0-46-9]|50)\d{4,8}", r"18[1-8]\d{3,6}", r"4946\d{2,6}|(?:4[0-8]|50)\d{4,8}"),
+            AZ => p(994, "00", "00", Some("0"), &[9], r"365\d{6}|(?:[124579]\d|60|88)\d{7}", r"(?:2[12]428|3655[02])\d{4}|(?:2(?:22[0-79]|63[0-28])|3654)\d{5}|(?:(?:1[28]|46)\d|2(?:[014-6]2|[23]3))\d{6}", r"36554\d{4}|(?:[16]0|4[04]|5[015]|7[07]|99)\d{7}"),
+            BA => p(387, "00", "00", Some("0"), &[8, 9], r"6\d{8}|(?:[35689]\d|49|70)\d{6}", r"(?:3(?:[05-79][2-9]|1[4579]|[23][24-9]|4[2-4689]|8[2457-9])|49[2-579]|5(?:0[2-49]|[13][2-9]|[268][2-4679]|4[4689]|5[2-79]|7[2-69]|9[2-4689]))\d{5}", r"6040\d{5}|6(?:03|[1-356]|44|7\d)\d{6}"),
+            BB => p(1, "011", "011", Some("1"), &[10], r"(?:246|[58]\d\d|900)\d{7}", r"246521[0369]\d{3}|246(?:2(?:2[78]|7[0-4])|4(?:1[024-6]|2\d|3[2-9])|5(?:20|[34]\d|54|7[1-3])|6(?:2\d|38)|7[35]7|9(?:1[89]|63))\d{4}", r"246(?:(?:2(?:[3568]\d|4[0-57-9])|3(?:5[2-9]|6[0-6])|4(?:46|5\d)|69[5-7]|8(?:[2-5]\d|83))\d|52(?:1[147]|20))\d{3}"),
+            BD => p(880, "00", "00", Some("0"), &[6, 7, 8, 9, 10], r"[1-469]\d{9}|8[0-79]\d{7,8}|[2-79]\d{8}|[2-9]\d{7}|[3-9]\d{6}|[57-9]\d{5}", r"(?:4(?:31\d\d|423)|5222)\d{3}(?:\d{2})?|8332[6-9]\d\d|(?:3(?:03[56]|224)|4(?:22[25]|653))\d{3,4}|(?:3(?:42[47]|529|823)|4(?:027|525|65(?:28|8))|562|6257|7(?:1(?:5[3-5]|6[12]|7[156]|89)|22[589]56|32|42675|52(?:[25689](?:56|8)|[347]8)|71(?:6[1267]|75|89)|92374)|82(?:2[59]|32)56|9(?:03[23]56|23(?:256|373)|31|5(?:1|2[4589]56)))\d{3}|(?:3(?:02[348]|22[35]|324|422)|4(?:22[67]|32[236-9]|6(?:2[46]|5[57])|953)|5526|6(?:024|6655)|81)\d{4,5}|(?:2(?:7(?:1[0-267]|2[0-289]|3[0-29]|4[01]|5[1-3]|6[013]|7[0178]|91)|8(?:0[125]|1[1-6]|2[0157-9]|3[1-69]|41|6[1-35]|7[1-5]|8[1-8]|9[0-6])|9(?:0[0-2]|1[0-4]|2[568]|3[3-6]|5[5-7]|6[0136-9]|7[0-7]|8[014-9]))|3(?:0(?:2[025-79]|3[2-4])|181|22[12]|32[2356]|824)|4(?:02[09]|22[348]|32[045]|523|6(?:27|54))|666(?:22|53)|7(?:22[57-9]|42[56]|82[35])8|8(?:0[124-9]|2(?:181|2[02-4679]8)|4[12]|[5-7]2)|9(?:[04]2|2(?:2|328)|81))\d{4}|(?:2(?:[23]\d|[45])\d\d|3(?:1(?:2[5-7]|[5-7])|425|822)|4(?:033|1\d|[257]1|332|4(?:2[246]|5[25])|6(?:2[35]|56|62)|8(?:23|54)|92[2-5])|5(?:02[03489]|22[457]|32[35-79]|42[46]|6(?:[18]|53)|724|826)|6(?:023|2(?:2[2-5]|5[3-5]|8)|32[3478]|42[34]|52[47]|6(?:[18]|6(?:2[34]|5[24]))|[78]2[2-5]|92[2-6])|7(?:02|21\d|[3-589]1|6[12]|72[24])|8(?:217|3[12]|[5-7]1)|9[24]1)\d{5}|(?:(?:3[2-8]|5[2-57-9]|6[03-589])1|4[4689][18])\d{5}|[59]1\d{5}", r"(?:1[13-9]\d|644)\d{7}|(?:3[78]|44|66)[02-9]\d{7}"),
+            BE => p(32, "00", "00", Some("0"), &[8, 9], r"4\d{8}|[1-9]\d{7}", r"80[2-8]\d{5}|(?:1[0-69]|[23][2-8]|4[23]|5\d|6[013-57-9]|71|8[1-79]|9[2-4])\d{6}", r"4[5-9]\d{7}"),
+            BF => p(226, "00", "00", None, &[8], r"(?:[025-7]\d|44)\d{6}", r"2(?:0(?:49|5[23]|6[5-7]|9[016-9])|4(?:4[569]|5[4-6]|6[5-7]|7[0179])|5(?:[34]\d|50|6[5-7]))\d{4}", r"(?:0[1-7]|44|5[0-8]|[67]\d)\d{6}"),
+            BG => p(359, "00", "00", Some("0"), &[6, 7, 8, 9, 12], r"00800\d{7}|[2-7]\d{6,7}|[89]\d{6,8}|2\d{5}", r"2\d{5,7}|(?:43[1-6]|70[1-9])\d{4,5}|(?:[36]\d|4[124-7]|[57][1-9]|8[1-6]|9[1-7])\d{5,6}", r"(?:43[07-9]|99[69]\d)\d{5}|(?:8[7-9]|98)\d{7}"),
+            BH => p(973, "00", "00", None, &[8], r"[136-9]\d{7}", r"(?:1(?:3[1356]|6[0156]|7\d)\d|6(?:1[16]\d|500|6(?:0\d|3[12]|44|55|7[7-9]|88)|9[69][69])|7(?:[07]\d\d|1(?:11|78)))\d{4}", r"(?:3(?:[0-79]\d|8[0-57-9])\d|6(?:3(?:00|33|6[16])|441|6(?:3[03-9]|[69]\d|7[0-689])))\d{4}"),
+            BI => p(257, "00", "00", None, &[8], r"(?:[267]\d|31)\d{6}", r"(?:22|31)\d{6}", r"(?:29|6[124-9]|7[125-9])\d{6}"),
+            BJ => p(229, "00", "00", None, &[8, 10], r"(?:01\d|8)\d{7}", r"012\d{7}", r"01(?:2[5-9]|[4-69]\d)\d{6}"),
+            BL => p(590, "00", "00", Some("0"), &[9], r"(?:590\d|7090)\d{5}|(?:69|80|9\d)\d{7}", r"590(?:2[7-9]|3[3-7]|5[12]|87)\d{4}", r"(?:69(?:0\d\d|1(?:2[2-9]|3[0-5])|4(?:0[89]|1[2-6]|9\d)|6(?:1[016-9]|5[0-4]|[67]\d))|7090[0-4])\d{4}"),
+            BM => p(1, "011", "011", Some("1"), &[10], r"(?:441|[58]\d\d|900)\d{7}", r"441(?:[46]\d\d|5(?:4\d|60|89))\d{4}", r"441(?:[2378]\d|5[0-39]|9[02])\d{5}"),
+            BN => p(673, "00", "00", None, &[7], r"[2-578]\d{6}", r"22[0-7]\d{4}|(?:2[013-9]|[34]\d|5[0-25-9])\d{5}", r"(?:22[89]|[78]\d\d)\d{4}"),
+            BO => p(591, r"00(?:1\d)?", "00", Some("0"), &[8, 9], r"8001\d{5}|(?:[2-467]\d|50)\d{6}", r"(?:2(?:2\d\d|5(?:11|[258]\d|9[67])|6(?:12|2\d|9[34])|8(?:2[34]|39|62))|3(?:3\d\d|4(?:6\d|8[24])|8(?:25|42|5[257]|86|9[25])|9(?:[27]\d|3[2-4]|4[248]|5[24]|6[2-6]))|4(?:4\d\d|6(?:11|[24689]\d|72)))\d{4}", r"[67]\d{7}"),
+            BQ => p(599, "00", "00", None, &[7], r"(?:[34]1|7\d)\d{5}", r"(?:318[023]|41(?:6[023]|70)|7(?:1[578]|2[05]|50)\d)\d{3}", r"(?:31(?:8[14-8]|9[14578])|416[14-9]|7(?:0[01]|7[07]|8\d|9[056])\d)\d{3}"),
+            BR => p(55, "00(?:1[245]|2[1-35]|31|4[13]|[56]5|99)", "0012", Some("0"), &[8, 9, 10, 11], r"[1-467]\d{9,10}|55[0-46-9]\d{8}|[34]\d{7}|55\d{7,8}|(?:5[0-46-9]|[89]\d)\d{7,9}", r"(?:[14689][1-9]|2[12478]|3[1-578]|5[13-5]|7[13-579])[2-5]\d{7}", r"(?:[14689][1-9]|2[12478]|3[1-578]|5[13-5]|7[13-579])(?:7|9\d)\d{7}"),
+            BS => p(1, "011", "011", Some("1"), &[10], r"(?:242|[58]\d\d|900)\d{7}", r"242(?:3(?:02|[236][1-9]|4[0-24-9]|5[0-68]|7[347]|8[0-4]|9[2-467])|461|502|6(?:0[1-5]|12|2[013]|[45]0|7[67]|8[78]|9[89])|7(?:02|88))\d{4}", r"242(?:3(?:5[79]|7[56]|95)|4(?:[23][1-9]|4[1-35-9]|5[1-8]|6[2-8]|7\d|81)|5(?:2[45]|3[35]|44|5[1-46-9]|65|77)|6[34]6|7(?:27|38)|8(?:0[1-9]|1[02-9]|2\d|3[0-4]|[89]9))\d{4}"),
+            BT => p(975, "00", "00", None, &[7, 8], r"[178]\d{7}|[2-8]\d{6}", r"(?:2[3-6]|[34][5-7]|5[236]|6[2-46]|7[246]|8[2-4])\d{5}", r"(?:1[67]|[78]7)\d{6}"),
+            BV => return None,
+            BW => p(267, "00", "00", None, &[7, 8, 10], r"(?:0800|(?:[37]|800)\d)\d{6}|(?:[2-6]\d|90)\d{5}", r"(?:2(?:4[0-48]|6[0-24]|9[0578])|3(?:1[0-35-9]|55|[69]\d|7[013]|81)|4(?:6[03]|7[1267]|9[0-5])|5(?:3[03489]|4[0489]|7[1-47]|88|9[0-49])|6(?:2[1-35]|5[149]|8[013467]))\d{4}", r"(?:321|7[1-8]\d)\d{5}"),
+            BY => p(375, "810", "810", Some("8"), &[6, 7, 8, 9, 10, 11], r"(?:[12]\d|33|44|902)\d{7}|8(?:0[0-79]\d{5,7}|[1-7]\d{9})|8(?:1[0-489]|[5-79]\d)\d{7}|8[1-79]\d{6,7}|8[0-79]\d{5}|8\d{5}", r"(?:1(?:5(?:1[1-5]|[24]\d|6[2-4]|9[1-7])|6(?:[235]\d|4[1-7])|7\d\d)|2(?:1(?:[246]\d|3[0-35-9]|5[1-9])|2(?:[235]\d|4[0-8])|3(?:[26]\d|3[02-79]|4[024-7]|5[03-7])))\d{5}", r"(?:2(?:5[5-79]|9[1-9])|(?:33|44)\d)\d{6}"),
+            BZ => p(501, "00", "00", None, &[7, 11], r"(?:0800\d|[2-8])\d{6}", r"(?:2(?:[02]\d|36|[68]0)|[3-58](?:[02]\d|[68]0)|7(?:[02]\d|32|[68]0))\d{4}", r"6[0-35-7]\d{5}"),
+            CA => p(1, "011", "011", Some("1"), &[7, 10], r"[2-9]\d{9}|3\d{6}", r"(?:2(?:04|[23]6|[48]9|5[07]|63)|3(?:06|43|54|6[578]|82)|4(?:03|1[68]|[26]8|3[178]|50|74)|5(?:06|1[49]|48|79|8[147])|6(?:04|[18]3|39|47|72)|7(?:0[59]|42|53|78|8[02])|8(?:[06]7|19|25|7[39])|9(?:0[25]|42))[2-9]\d{6}", r"(?:2(?:04|[23]6|[48]9|5[07]|63)|3(?:06|43|54|6[578]|82)|4(?:03|1[68]|[26]8|3[178]|50|74)|5(?:06|1[49]|48|79|8[147])|6(?:04|[18]3|39|47|72)|7(?:0[59]|42|53|78|8[02])|8(?:[06]7|19|25|7[39])|9(?:0[25]|42))[2-9]\d{6}"),
+            CC => p(61, "001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011", "0011", Some("0"), &[6, 7, 8, 9, 10, 12], r"1(?:[0-79]\d{8}(?:\d{2})?|8[0-24-9]\d{7})|[148]\d{8}|1\d{5,7}", r"8(?:51(?:0(?:02|31|60|89)|1(?:18|76)|223)|91(?:0(?:1[0-2]|29)|1(?:[28]2|50|79)|2(?:10|64)|3(?:[06]8|22)|4[29]8|62\d|70[23]|959))\d{3}", r"4(?:79[01]|83[0-36-9]|95[0-3])\d{5}|4(?:[0-36]\d|4[047-9]|[58][0-24-9]|7[02-8]|9[0-47-9])\d{6}"),
+            CD => p(243, "00", "00", Some("0"), &[7, 8, 9, 10], r"(?:(?:[189]|5\d)\d|2)\d{7}|[1-68]\d{6}", r"(?:(?:12|573)\d\d|276)\d{5}|[1-6]\d{6}", r"88\d{5}|(?:8[0-69]|9[017-9])\d{7}"),
+            CF => p(236, "00", "00", None, &[8], r"(?:[27]\d{3}|8776)\d{4}", r"2[12]\d{6}", r"7[02-7]\d{6}"),
+            CG => p(242, "00", "00", None, &[9], r"222\d{6}|(?:0\d|80)\d{7}", r"222[1-589]\d{5}", r"026(?:1[0-5]|6[6-9])\d{4}|0(?:[14-6]\d\d|2(?:40|5[5-8]|6[07-9]))\d{5}"),
+            CH => p(41, "00", "00", Some("0"), &[9, 12], r"8\d{11}|[2-9]\d{8}", r"(?:2[12467]|3[1-4]|4[134]|5[256]|6[12]|[7-9]1)\d{7}", r"(?:6[89]|7[235-9])\d{7}"),
+            CI => p(225, "00", "00", None, &[10], r"[02]\d{9}", r"2(?:[15]\d{3}|7(?:2(?:0[23]|1[2357]|2[245]|3[45]|4[3-5])|3(?:06|1[69]|[2-6]7)))\d{5}", r"0[157]\d{8}"),
+            CK => p(682, "00", "00", None, &[5], r"[2-578]\d{4}", r"(?:2\d|3[13-7]|4[1-5])\d{3}", r"[578]\d{4}"),
+            CL => p(56, "(?:0|1(?:1[0-69]|2[02-5]|5[13-58]|69|7[0167]|8[018]))0", "00", None, &[9, 10, 11], r"12300\d{6}|6\d{9,10}|[2-9]\d{8}", r"2(?:1982[0-6]|3314[05-9])\d{3}|(?:2(?:1(?:160|962)|3(?:(?:2\d|50)\d|3(?:[034679]\d|1[0-35-9]|2[1-9]|5[0-24-9]|8[0-389])|600)|646[59])|(?:600|80[1-9])\d\d|9(?:(?:10[0-2]|7[1-9]\d)\d|3(?:[0-57-9]\d\d|6(?:0[02-9]|[1-9]\d))|6(?:[0-8]\d\d|9(?:[02-79]\d|1[05-9]))|9(?:[03-9]\d\d|1(?:[0235-9]\d|4[0-24-9])|2(?:[0-79]\d|8[0-46-9]))))\d{4}|(?:22|3[2-5]|[47][1-35]|5[1-3578]|6[13-57]|8[1-9]|9[2458])\d{7}", r"2(?:1982[0-6]|3314[05-9])\d{3}|(?:2(?:1(?:160|962)|3(?:(?:2\d|50)\d|3(?:[034679]\d|1[0-35-9]|2[1-9]|5[0-24-9]|8[0-389])|600)|646[59])|80[1-8]\d\d|9(?:(?:10[0-2]|7[1-9]\d)\d|3(?:[0-57-9]\d\d|6(?:0[02-9]|[1-9]\d))|6(?:[0-8]\d\d|9(?:[02-79]\d|1[05-9]))|9(?:[03-9]\d\d|1(?:[0235-9]\d|4[0-24-9])|2(?:[0-79]\d|8[0-46-9]))))\d{4}|(?:22|3[2-5]|[47][1-35]|5[1-3578]|6[13-57]|8[1-9]|9[2458])\d{7}"),
+            CM => p(237, "00", "00", None, &[8, 9], r"[26]\d{8}|88\d{6,7}", r"2(?:22|33)\d{6}", r"(?:24[23]|6(?:[25-9]\d|40))\d{6}"),
+            CN => p(86, r"00|1(?:[12]\d|79)\d\d00", "00", Some("0"), &[7, 8, 9, 10, 11, 12], r"(?:(?:1[03-689]|2\d)\d\d|6)\d{8}|1\d{10}|[126]\d{6}(?:\d(?:\d{2})?)?|86\d{5,6}|(?:[3-579]\d|8[0-57-9])\d{5,9}", r"(?:10(?:[02-79]\d\d|[18](?:0[1-9]|[1-9]\d))|2(?:[02-57-9]\d{3}|1(?:[18](?:0[1-9]|[1-9]\d)|[2-79]\d\d))|(?:41[03]|8078|9(?:78|94))\d\d)\d{5}|(?:10|2[0-57-9])(?:1(?:00|23)\d\d|95\d{3,4})|(?:41[03]|9(?:78|94))(?:100\d\d|95\d{3,4})|8078123|(?:43[35]|754|851)\d{7,8}|(?:43[35]|754|851)(?:1(?:00\d|23)\d|95\d{3,4})|(?:3(?:11|7[179])|4(?:[15]1|3[12])|5(?:1\d|2[37]|3[12]|51|7[13-79]|9[15])|7(?:[39]1|5[57]|6[09])|8(?:71|98))(?:[02-8]\d{7}|1(?:0(?:0\d\d(?:\d{3})?|[1-9]\d{5})|[13-9]\d{6}|2(?:[0-24-9]\d{5}|3\d(?:\d{4})?))|9(?:[0-46-9]\d{6}|5\d{3}(?:\d(?:\d{2})?)?))|(?:3(?:1[02-9]|35|49|5\d|7[02-68]|9[1-68])|4(?:1[24-9]|2[179]|3[46-9]|5[2-9]|6[47-9]|7\d|8[23])|5(?:3[03-9]|4[36]|5[02-9]|6[1-46]|7[028]|80|9[2-46-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[17]\d|2[248]|3[04-9]|4[3-6]|5[0-3689]|6[2368]|9[02-9])|8(?:1[236-8]|2[5-7]|3\d|5[2-9]|7[02-9]|8[36-8]|9[1-7])|9(?:0[1-3689]|1[1-79]|3\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))(?:[02-8]\d{6}|1(?:0(?:0\d\d(?:\d{2})?|[1-9]\d{4})|[13-9]\d{5}|2(?:[0-24-9]\d{4}|3\d(?:\d{3})?))|9(?:[0-46-9]\d{5}|5\d{3,5}))", r"1740[0-5]\d{6}|1(?:[38]\d|4[57]|[59][0-35-9]|6[25-7]|7[0-35-8])\d{8}"),
+            CO => p(57, "00(?:4(?:[14]4|56)|[579])", "00414", Some("0"), &[8, 10, 11], r"(?:46|60\d\d)\d{6}|(?:1\d|[39])\d{9}", r"601055(?:[0-4]\d|50)\d\d|6010(?:[0-4]\d|5[0-4])\d{4}|(?:46|60(?:[18][1-9]|[24-7][2-9]))\d{6}", r"333301[0-5]\d{3}|3333(?:00|2[5-9]|[3-9]\d)\d{4}|(?:3(?:(?:0[0-5]|1\d|5[01]|70)\d|2(?:[0-3]\d|4[1-9])|3(?:00|3[0-24-9]))|9(?:101|408))\d{6}"),
+            CR => p(506, "00", "00", None, &[8, 10], r"(?:8\d|90)\d{8}|(?:[24-8]\d{3}|3005)\d{4}", r"210[7-9]\d{4}|2(?:[024-7]\d|1[1-9])\d{5}", r"(?:3005\d|6500[01])\d{3}|(?:5[07]|6[0-4]|7[0-3]|8[3-9])\d{6}"),
+            CU => p(53, "119", "119", Some("0"), &[6, 7, 8, 10], r"(?:[2-7]|8\d\d)\d{7}|[2-47]\d{6}|[34]\d{5}", r"(?:3[23]|4[89])\d{4,6}|(?:31|4[36]|8(?:0[25]|78)\d)\d{6}|(?:2[1-4]|4[1257]|7\d)\d{5,6}", r"(?:5\d|6[2-4])\d{6}"),
+            CV => p(238, "0", "0", None, &[7], r"(?:[2-59]\d\d|800)\d{4}", r"2(?:2[1-7]|3[0-8]|4[12]|5[1256]|6\d|7[1-3]|8[1-5])\d{4}", r"(?:36|5[1-389]|9\d)\d{5}"),
+            CW => p(599, "00", "00", None, &[7, 8], r"(?:[34]1|60|(?:7|9\d)\d)\d{5}", r"9(?:4(?:3[0-5]|4[14]|6\d)|50\d|7(?:2[014]|3[02-9]|4[4-9]|6[357]|77|8[7-9])|8(?:3[39]|[46]\d|7[01]|8[57-9]))\d{4}", r"953[01]\d{4}|9(?:5[12467]|6[5-9])\d{5}"),
+            CX => p(61, "001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011", "0011", Some("0"), &[6, 7, 8, 9, 10, 12], r"1(?:[0-79]\d{8}(?:\d{2})?|8[0-24-9]\d{7})|[148]\d{8}|1\d{5,7}", r"8(?:51(?:0(?:01|30|59|88)|1(?:17|46|75)|2(?:22|35))|91(?:00[6-9]|1(?:[28]1|49|78)|2(?:09|63)|3(?:12|26|75)|4(?:56|97)|64\d|7(?:0[01]|1[0-2])|958))\d{3}", r"4(?:79[01]|83[0-36-9]|95[0-3])\d{5}|4(?:[0-36]\d|4[047-9]|[58][0-24-9]|7[02-8]|9[0-47-9])\d{6}"),
+            CY => p(357, "00", "00", None, &[8], r"(?:[279]\d|[58]0)\d{6}", r"2[2-6]\d{6}", r"9(?:10|[4-79]\d)\d{5}"),
+            CZ => p(420, "00", "00", None, &[9, 10, 11, 12], r"(?:[2-578]\d|60)\d{7}|9\d{8,11}", r"(?:2\d|3[1257-9]|4[16-9]|5[13-9])\d{7}", r"7(?:060\d|19(?:[0-5]\d|6[0-6]))\d{4}|(?:60[1-8]|7(?:0[2-5]|[2379]\d))\d{6}"),
+            DE => p(49, "00", "00", Some("0"), &[4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], r"[2579]\d{5,14}|49(?:[34]0|69|8\d)\d\d?|49(?:37|49|60|7[089]|9\d)\d{1,3}|49(?:2[024-9]|3[2-689]|7[1-7])\d{1,8}|(?:1|[368]\d|4[0-8])\d{3,13}|49(?:[015]\d|2[13]|31|[46][1-8])\d{1,9}", r"32\d{9,11}|49[1-6]\d{10}|322\d{6}|49[0-7]\d{3,9}|(?:[34]0|[68]9)\d{3,13}|(?:2(?:0[1-689]|[1-3569]\d|4[0-8]|7[1-7]|8[0-7])|3(?:[3569]\d|4[0-79]|7[1-7]|8[1-8])|4(?:1[02-9]|[2-48]\d|5[0-6]|6[0-8]|7[0-79])|5(?:0[2-8]|[124-6]\d|[38][0-8]|[79][0-7])|6(?:0[02-9]|[1-358]\d|[47][0-8]|6[1-9])|7(?:0[2-8]|1[1-9]|[27][0-7]|3\d|[4-6][0-8]|8[0-5]|9[013-7])|8(?:0[2-9]|1[0-79]|2\d|3[0-46-9]|4[0-6]|5[013-9]|6[1-8]|7[0-8]|8[0-24-6])|9(?:0[6-9]|[1-4]\d|[589][0-7]|6[0-8]|7[0-467]))\d{3,12}", r"1(?:(?:5(?:[0-25-9]\d\d|3(?:10|33))|7[26-9]\d\d)\d{6}|6[023]\d{7,8})|17\d{8}"),
+            DJ => p(253, "00", "00", None, &[8], r"(?:2\d|77)\d{6}", r"2(?:1[2-5]|7[45])\d{5}", r"77\d{6}"),
+            DK => p(45, "00", "00", None, &[8], r"[2-9]\d{7}", r"(?:2(?:[0-59][1-9]|[6-8]\d)|3(?:[0-3][1-9]|4[13]|5[1-58]|6[1347-9]|7\d|8[1-8]|9[1-79])|4(?:[0-25][1-9]|[34][2-9]|6[13-579]|7[13579]|8[1-47]|9[127])|5(?:[0-36][1-9]|4[146-9]|5[3-57-9]|7[568]|8[1-358]|9[1-69])|6(?:[0135][1-9]|2[1-68]|4[2-8]|6[1689]|[78]\d|9[15689])|7(?:[0-69][1-9]|7[3-9]|8[147])|8(?:[16-9][1-9]|2[1-58])|9(?:[1-47-9][1-9]|6\d))\d{5}", r"(?:2[6-8]|37|6[78]|96)\d{6}|(?:2[0-59]|3[0-689]|[457]\d|6[0-69]|8[126-9]|9[1-47-9])[1-9]\d{5}"),
+            DM => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|767|900)\d{7}", r"767(?:2(?:55|66)|4(?:2[01]|4[0-25-9])|50[0-4])\d{4}", r"767(?:2(?:[2-4689]5|7[5-7])|31[5-7]|61[1-8]|70[1-6])\d{4}"),
+            DO => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|900)\d{7}", r"8(?:[04]9[2-9]\d\d|29(?:2(?:[0-59]\d|6[04-9]|7[0-27]|8[0237-9])|3(?:[0-35-9]\d|4[7-9])|[45]\d\d|6(?:[0-27-9]\d|[3-5][1-9]|6[0135-8])|7(?:0[013-9]|[1-37]\d|4[1-35689]|5[1-4689]|6[1-57-9]|8[1-79]|9[1-8])|8(?:0[146-9]|1[0-48]|[248]\d|3[1-79]|5[01589]|6[013-68]|7[124-8]|9[0-8])|9(?:[0-24]\d|3[02-46-9]|5[0-79]|60|7[0169]|8[57-9]|9[02-9])))\d{4}", r"8[024]9[2-9]\d{6}"),
+            DZ => p(213, "00", "00", Some("0"), &[8, 9], r"(?:[1-4]|[5-79]\d|80)\d{7}", r"9619\d{5}|(?:1\d|2[013-79]|3[0-8]|4[013-689])\d{6}", r"(?:5(?:4[0-29]|5\d|6[0-3])|6(?:[569]\d|7[0-6])|7[7-9]\d)\d{6}"),
+            EC => p(593, "00", "00", Some("0"), &[8, 9, 10, 11], r"1\d{9,10}|(?:[2-7]|9\d)\d{7}", r"[2-7][2-7]\d{6}", r"964[0-2]\d{5}|9(?:39|[57][89]|6[0-36-9]|[89]\d)\d{6}"),
+            EE => p(372, "00", "00", None, &[7, 8, 10], r"8\d{9}|[4578]\d{7}|(?:[3-8]\d|90)\d{5}", r"(?:3[23589]|4[3-8]|6\d|7[1-9]|88)\d{5}", r"(?:5\d{5}|8(?:1(?:0(?:0(?:00|[178]\d)|[3-9]\d\d)|(?:1(?:0[2-6]|1\d)|[2-79]\d\d)\d)|2(?:0(?:0(?:00|4\d)|(?:19|[2-7]\d)\d)|(?:(?:[124-69]\d|3[5-9])\d|7(?:[0-79]\d|8[013-9])|8(?:[2-6]\d|7[01]))\d)|[349]\d{4}))\d\d|5(?:(?:[02]\d|5[0-478])\d|1(?:[0-8]\d|95)|6(?:4[0-4]|5[1-589]))\d{3}"),
+            EG => p(20, "00", "00", Some("0"), &[8, 9, 10], r"[189]\d{8,9}|[24-6]\d{8}|[135]\d{7}", r"13[23]\d{6}|(?:15|57)\d{6,7}|(?:2\d|3|4[05-8]|5[05]|6[24-689]|8[2468]|9[235-7])\d{7}", r"1[0-25]\d{8}"),
+            EH => p(212, "00", "00", Some("0"), &[9], r"[5-8]\d{8}", r"528[89]\d{5}", r"(?:6(?:[0-79]\d|8[0-247-9])|7(?:[016-8]\d|2[0-8]|5[0-5]))\d{6}"),
+            ER => p(291, "00", "00", Some("0"), &[7], r"[178]\d{6}", r"(?:1(?:1[12568]|[24]0|55|6[146])|8\d\d)\d{4}", r"(?:17[1-3]|7\d\d)\d{4}"),
+            ES => p(34, "00", "00", None, &[9], r"[5-9]\d{8}", r"96906(?:0[0-8]|1[1-9]|[2-9]\d)\d\d|9(?:69(?:0[0-57-9]|[1-9]\d)|73(?:[0-8]\d|9[1-9]))\d{4}|(?:8(?:[1356]\d|[28][0-8]|[47][1-9])|9(?:[135]\d|[268][0-8]|4[1-9]|7[124-9]))\d{6}", r"96906(?:09|10)\d\d|(?:590(?:10[0-2]|600)|97390\d)\d{3}|(?:6\d|7[1-48])\d{7}"),
+            ET => p(251, "00", "00", Some("0"), &[9], r"(?:11|[2-579]\d)\d{7}", r"(?:11(?:[124]\d\d|3(?:[0-79]\d|8[0-7])|5(?:[02-9]\d|1[0-57-9])|6(?:[02-79]\d|1[0-57-9]|8[0-8]))|2(?:2(?:11[1-9]|22[0-7]|33\d|44[1467]|66[1-68])|5(?:11[124-6]|33[2-8]|44[1467]|55[14]|66[1-3679]|77[124-79]|880))|3(?:3(?:11[0-46-8]|(?:22|55)[0-6]|33[0134689]|44[04]|66[01467])|4(?:44[0-8]|55[0-69]|66[0-3]|77[1-5]))|4(?:6(?:119|22[0-24-7]|33[1-5]|44[13-69]|55[14-689]|660|88[1-4])|7(?:(?:11|22)[1-9]|33[13-7]|44[13-6]|55[1-689]))|5(?:7(?:227|55[05]|(?:66|77)[14-8])|8(?:11[149]|22[013-79]|33[0-68]|44[013-8]|550|66[1-5]|77\d)))\d{4}", r"700[1-9]\d{5}|(?:7(?:0[1-9]|1[0-8]|2[1-35-79]|3\d|77|86|99)|9\d\d)\d{6}"),
+            FI => p(358, "00|99(?:[01469]|5(?:[14]1|3[23]|5[59]|77|88|9[09]))", "00", Some("0"), &[5, 6, 7, 8, 9, 10, 11, 12], r"[1-35689]\d{4}|7\d{10,11}|(?:[124-7]\d|3[0-46-9])\d{8}|[1-9]\d{5,8}", r"1[3-7][1-8]\d{3,6}|(?:19[1-8]|[23568][1-8]\d|9(?:00|[1-8]\d))\d{2,6}", r"4946\d{2,6}|(?:4[0-8]|50)\d{4,8}"),
+            FJ => p(679, "0(?:0|52)", "00", None, &[7, 11], r"45\d{5}|(?:0800\d|[235-9])\d{6}", r"603\d{4}|(?:3[0-5]|6[25-7]|8[58])\d{5}", r"(?:[279]\d|45|5[01568]|8[034679])\d{5}"),
+            FK => p(500, "00", "00", None, &[5], r"[2-7]\d{4}", r"[2-47]\d{4}", r"[56]\d{4}"),
+            FM => p(691, "00", "00", None, &[7], r"(?:[39]\d\d|820)\d{4}", r"31(?:00[67]|208|309)\d\d|(?:3(?:[2357]0[1-9]|602|804|905)|(?:820|9[2-6]\d)\d)\d{3}", r"31(?:00[67]|208|309)\d\d|(?:3(?:[2357]0[1-9]|602|804|905)|(?:820|9[2-7]\d)\d)\d{3}"),
+            FO => p(298, "00", "00", None, &[6], r"[2-9]\d{5}", r"(?:20|[34]\d|8[19])\d{4}", r"(?:[27][1-9]|5\d|9[16])\d{4}"),
+            FR => p(33, "00", "00", Some("0"), &[9], r"[1-9]\d{8}", r"(?:26[013-9]|59[1-35-9])\d{6}|(?:[13]\d|2[0-57-9]|4[1-9]|5[0-8])\d{7}", r"(?:6(?:[0-24-8]\d|3[0-8]|9[589])|7[3-9]\d)\d{6}"),
+            GA => p(241, "00", "00", None, &[7, 8], r"(?:[067]\d|11)\d{6}|[2-7]\d{6}", r"[01]1\d{6}", r"(?:(?:0[2-7]|7[467])\d|6(?:0[0-4]|10|[256]\d))\d{5}|[2-7]\d{6}"),
+            GB => p(44, "00", "00", Some("0"), &[7, 9, 10], r"[1-357-9]\d{9}|[18]\d{8}|8\d{6}", r"(?:1(?:1(?:3(?:[0-58]\d\d|73[0-5])|4(?:(?:[0-5]\d|70)\d|69[7-9])|(?:(?:5[0-26-9]|[78][0-49])\d|6(?:[0-4]\d|5[01]))\d)|(?:2(?:(?:0[024-9]|2[3-9]|3[3-79]|4[1-689]|[58][02-9]|6[0-47-9]|7[013-9]|9\d)\d|1(?:[0-7]\d|8[0-3]))|(?:3(?:0\d|1[0-8]|[25][02-9]|3[02-579]|[468][0-46-9]|7[1-35-79]|9[2-578])|4(?:0[03-9]|[137]\d|[28][02-57-9]|4[02-69]|5[0-8]|[69][0-79])|5(?:0[1-35-9]|[16]\d|2[024-9]|3[015689]|4[02-9]|5[03-9]|7[0-35-9]|8[0-468]|9[0-57-9])|6(?:0[034689]|1\d|2[0-35689]|[38][013-9]|4[1-467]|5[0-69]|6[13-9]|7[0-8]|9[0-24578])|7(?:0[0246-9]|2\d|3[0236-8]|4[03-9]|5[0-46-9]|6[013-9]|7[0-35-9]|8[024-9]|9[02-9])|8(?:0[35-9]|2[1-57-9]|3[02-578]|4[0-578]|5[124-9]|6[2-69]|7\d|8[02-9]|9[02569])|9(?:0[02-589]|[18]\d|2[02-689]|3[1-57-9]|4[2-9]|5[0-579]|6[2-47-9]|7[0-24578]|9[2-57]))\d)\d)|2(?:0[013478]|3[0189]|4[017]|8[0-46-9]|9[0-2])\d{3})\d{4}|1(?:2(?:0(?:46[1-4]|87[2-9])|545[1-79]|76(?:2\d|3[1-8]|6[1-6])|9(?:7(?:2[0-4]|3[2-5])|8(?:2[2-8]|7[0-47-9]|8[3-5])))|3(?:6(?:38[2-5]|47[23])|8(?:47[04-9]|64[0157-9]))|4(?:044[1-7]|20(?:2[23]|8\d)|6(?:0(?:30|5[2-57]|6[1-8]|7[2-8])|140)|8(?:052|87[1-3]))|5(?:2(?:4(?:3[2-79]|6\d)|76\d)|6(?:26[06-9]|686))|6(?:06(?:4\d|7[4-79])|295[5-7]|35[34]\d|47(?:24|61)|59(?:5[08]|6[67]|74)|9(?:55[0-4]|77[23]))|7(?:26(?:6[13-9]|7[0-7])|(?:442|688)\d|50(?:2[0-3]|[3-68]2|76))|8(?:27[56]\d|37(?:5[2-5]|8[239])|843[2-58])|9(?:0(?:0(?:6[1-8]|85)|52\d)|3583|4(?:66[1-8]|9(?:2[01]|81))|63(?:23|3[1-4])|9561))\d{3}", r"7(?:457[0-57-9]|700[01]|911[028])\d{5}|7(?:[1-3]\d\d|4(?:[0-46-9]\d|5[0-689])|5(?:0[0-8]|[13-9]\d|2[0-35-9])|7(?:0[1-9]|[1-7]\d|8[02-9]|9[0-689])|8(?:[014-9]\d|[23][0-8])|9(?:[024-9]\d|1[02-9]|3[0-689]))\d{6}"),
+            GD => p(1, "011", "011", Some("1"), &[10], r"(?:473|[58]\d\d|900)\d{7}", r"473(?:2(?:3[0-2]|69)|3(?:2[89]|86)|4(?:[06]8|3[5-9]|4[0-4]|5[59]|73|90)|63[68]|7(?:58|84)|800|938)\d{4}", r"473(?:4(?:0[2-79]|1[04-9]|2[0-5]|49|5[6-8])|5(?:2[01]|3[3-8])|901)\d{4}"),
+            GE => p(995, "00", "00", Some("0"), &[9], r"(?:[3-57]\d\d|800)\d{6}", r"(?:3(?:[256]\d|4[124-9]|7[0-4])|4(?:1\d|2[2-7]|3[1-79]|4[2-8]|7[239]|9[1-7]))\d{6}", r"5(?:(?:(?:0555|1(?:[17]77|555))[5-9]|757(?:7[7-9]|8[01]))\d|22252[0-4])\d\d|5(?:0(?:0(?:1[09]|70)|505)|1(?:0[01]0|1(?:07|33|51))|2(?:0[02]0|2[25]2)|3(?:0[03]0|3[35]3)|(?:40[04]|900)0|5222)[0-4]\d{3}|(?:5(?:0(?:0(?:0\d|1[12]|22|3[0-6]|44|5[05]|77|88|9[09])|(?:[14]\d|77)\d|22[02])|1(?:1(?:[03][01]|[124]\d|5[2-6]|7[0-6])|4\d\d)|[23]555|4(?:4\d\d|555)|5(?:[0157-9]\d\d|200|333|444)|6[89]\d\d|7(?:(?:[0147-9]\d|22)\d|5(?:00|[57]5))|8(?:0(?:[018]\d|2[0-4])|5(?:55|8[89])|8(?:55|88))|9(?:090|[1-35-9]\d\d))|790\d\d)\d{4}"),
+            GF => p(594, "00", "00", Some("0"), &[9], r"(?:[56]94\d|7093)\d{5}|(?:80|9\d)\d{7}", r"594(?:[02-49]\d|1[0-5]|5[6-9]|6[0-3]|80)\d{4}", r"(?:694(?:[0-249]\d|3[0-8])|7093[0-3])\d{4}"),
+            GG => p(44, "00", "00", Some("0"), &[7, 9, 10], r"(?:1481|[357-9]\d{3})\d{6}|8\d{6}(?:\d{2})?", r"1481[25-9]\d{5}", r"7(?:(?:781|839)\d|911[17])\d{5}"),
+            GH => p(233, "00", "00", Some("0"), &[8, 9], r"(?:[235]\d{3}|800)\d{5}", r"3082[0-5]\d{4}|3(?:0(?:[237]\d|8[01])|[167](?:2[0-6]|7\d|80)|2(?:2[0-5]|7\d|80)|3(?:2[0-3]|7\d|80)|4(?:2[013-9]|3[01]|7\d|80)|5(?:2[0-7]|7\d|80)|8(?:2[0-2]|7\d|80)|9(?:[28]0|7\d))\d{5}", r"(?:2(?:[0346-9]\d|5[67])|5(?:[03-7]\d|9[1-9]))\d{6}"),
+            GI => p(350, "00", "00", None, &[8], r"(?:[25]\d|60)\d{6}", r"2190[0-2]\d{3}|2(?:0(?:[02]\d|3[01])|16[24-9]|2[2-5]\d)\d{4}", r"5251[0-4]\d{3}|(?:5(?:[146-8]\d\d|250)|60(?:1[01]|6\d))\d{4}"),
+            GL => p(299, "00", "00", None, &[6], r"(?:19|[2-689]\d|70)\d{4}", r"(?:19|3[1-7]|[68][1-9]|70|9\d)\d{4}", r"[245]\d{5}"),
+            GM => p(220, "00", "00", None, &[7], r"[2-9]\d{6}", r"(?:4(?:[23]\d\d|4(?:1[024679]|[6-9]\d))|5(?:5(?:3\d|4[0-7])|6[67]\d|7(?:1[04]|2[035]|3[58]|48))|8[0-589]\d\d)\d{3}", r"556\d{4}|(?:[23679]\d|4[015]|5[0-489]|8[67])\d{5}"),
+            GN => p(224, "00", "00", None, &[8, 9], r"722\d{6}|(?:3|6\d)\d{7}", r"3(?:0(?:24|3[12]|4[1-35-7]|5[13]|6[189]|[78]1|9[1478])|1\d\d)\d{4}", r"6[0-356]\d{7}"),
+            GP => p(590, "00", "00", Some("0"), &[9], r"(?:590\d|7090)\d{5}|(?:69|80|9\d)\d{7}", r"590(?:0[1-68]|[14][0-24-9]|2[0-68]|3[1-9]|5[3-579]|[68][0-689]|7[08]|9\d)\d{4}", r"(?:69(?:0\d\d|1(?:2[2-9]|3[0-5])|4(?:0[89]|1[2-6]|9\d)|6(?:1[016-9]|5[0-4]|[67]\d))|7090[0-4])\d{4}"),
+            GQ => p(240, "00", "00", None, &[9], r"222\d{6}|(?:3\d|55|[89]0)\d{7}", r"33[0-24-9]\d[46]\d{4}|3(?:33|5\d)\d[7-9]\d{4}", r"(?:222|55\d)\d{6}"),
+            GR => p(30, "00", "00", None, &[10, 11, 12], r"5005000\d{3}|8\d{9,11}|(?:[269]\d|70)\d{8}", r"2(?:1\d\d|2(?:2[1-46-9]|[36][1-8]|4[1-7]|5[1-4]|7[1-5]|[89][1-9])|3(?:1\d|2[1-57]|[35][1-3]|4[13]|7[1-7]|8[124-6]|9[1-79])|4(?:1\d|2[1-8]|3[1-4]|4[13-5]|6[1-578]|9[1-5])|5(?:1\d|[29][1-4]|3[1-5]|4[124]|5[1-6])|6(?:1\d|[269][1-6]|3[1245]|4[1-7]|5[13-9]|7[14]|8[1-5])|7(?:1\d|2[1-5]|3[1-6]|4[1-7]|5[1-57]|6[135]|9[125-7])|8(?:1\d|2[1-5]|[34][1-4]|9[1-57]))\d{6}", r"68[57-9]\d{7}|(?:69|94)\d{8}"),
+            GS => return None,
+            GT => p(502, "00", "00", None, &[8, 11], r"80\d{6}|(?:1\d{3}|[2-7])\d{7}", r"[267][2-9]\d{6}", r"(?:[3-5]\d\d|80[0-4])\d{5}"),
+            GU => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|671|900)\d{7}", r"671(?:2\d\d|3(?:00|3[39]|4[349]|55|6[26])|4(?:00|56|7[1-9]|8[02-9])|5(?:55|6[2-5]|88)|6(?:3[2-578]|4[24-9]|5[34]|78|8[235-9])|7(?:[0479]7|2[0167]|3[45]|8[7-9])|8(?:[2-57-9]8|6[478])|9(?:2[29]|6[79]|7[1279]|8[7-9]|9[78]))\d{4}", r"671(?:2\d\d|3(?:00|3[39]|4[349]|55|6[26])|4(?:00|56|7[1-9]|8[02-9])|5(?:55|6[2-5]|88)|6(?:3[2-578]|4[24-9]|5[34]|78|8[235-9])|7(?:[0479]7|2[0167]|3[45]|8[7-9])|8(?:[2-57-9]8|6[478])|9(?:2[29]|6[79]|7[1279]|8[7-9]|9[78]))\d{4}"),
+            GW => p(245, "00", "00", None, &[7, 9], r"[49]\d{8}|4\d{6}", r"443\d{6}", r"9(?:5\d|6[569]|77)\d{6}"),
+            GY => p(592, "001", "001", None, &[7], r"(?:[2-8]\d{3}|9008)\d{3}", r"(?:2(?:1[6-9]|2[0-35-9]|3[1-4]|5[3-9]|6\d|7[0-79])|3(?:2[25-9]|3\d)|4(?:4[0-24]|5[56])|50[0-6]|77[1-57])\d{4}", r"(?:51[01]|6\d\d|7(?:[0-5]\d|6[0-59]|70))\d{4}"),
+            HK => p(852, "00(?:30|5[09]|[126-9]?)", "00", None, &[5, 6, 7, 8, 9, 11], r"8[0-46-9]\d{6,7}|9\d{4,7}|(?:[2-7]|9\d{3})\d{7}", r"(?:2(?:[13-9]\d|2[013-9])\d|3(?:(?:[1569][0-24-9]|4[0-246-9]|7[0-24-69])\d|8(?:4[0-8]|[579]\d|6[0-5]))|58(?:0[1-9]|1[2-9]))\d{4}", r"(?:4(?:44[0-35-9]|6(?:4[0-57-9]|6[0-6])|7(?:4[0-48]|6[0-5]))|5(?:25[3-7]|35[4-8]|73[0-6]|95[0-8])|6(?:26[013-8]|(?:66|78)[0-5])|70(?:7[1-8]|8[0-8])|84(?:4[0-2]|8[0-35-9])|9(?:29[013-9]|39[014-9]|59[0-467]|899))\d{4}|(?:4(?:4[0-35-9]|6[0-357-9]|7[0-35])|5(?:[1-59][0-46-9]|6[0-4689]|7[0-246-9])|6(?:0[1-9]|[13-59]\d|[268][0-57-9]|7[0-79])|70[1-59]|84[0-39]|9(?:0[1-9]|1[02-9]|[2358][0-8]|[467]\d))\d{5}"),
+            HM => return None,
+            HN => p(504, "00", "00", None, &[8, 11], r"8\d{10}|[237-9]\d{7}", r"2(?:2(?:0[0-59]|1[1-9]|[23]\d|4[02-7]|5[57]|6[245]|7[0135689]|8[01346-9]|9[0-2])|4(?:0[578]|2[3-59]|3[13-9]|4[0-68]|5[1-3589])|5(?:0[2357-9]|1[1-356]|4[03-5]|5\d|6[014-69]|7[04]|80)|6(?:[056]\d|17|2[067]|3[047]|4[0-378]|[78][0-8]|9[01])|7(?:0[5-79]|6[46-9]|7[02-9]|8[034]|91)|8(?:79|8[0-357-9]|9[1-57-9]))\d{4}", r"[37-9]\d{7}"),
+            HR => p(385, "00", "00", Some("0"), &[7, 8, 9], r"[2-69]\d{8}|80\d{5,7}|[1-79]\d{7}|6\d{6}", r"1\d{7}|(?:2[0-3]|3[1-5]|4[02-47-9]|5[1-3])\d{6,7}", r"9(?:(?:0[1-9]|[12589]\d)\d\d|7(?:[0679]\d\d|5(?:[01]\d|44|55|77|9[5-79])))\d{4}|98\d{6}"),
+            HT => p(509, "00", "00", None, &[8], r"[2-589]\d{7}", r"2(?:2\d|5[1-5]|81|9[149])\d{5}", r"(?:[34]\d|5[56])\d{6}"),
+            HU => p(36, "00", "00", Some("06"), &[8, 9], r"[235-7]\d{8}|[1-9]\d{7}", r"(?:1\d|[27][2-9]|3[2-7]|4[24-9]|5[2-79]|6[23689]|8[2-57-9]|9[2-69])\d{6}", r"(?:[257]0|3[01])\d{7}"),
+            ID => p(62, "00[89]", "008", Some("0"), &[7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17], r"00[1-9]\d{9,14}|(?:[1-36]|8\d{5})\d{6}|00\d{9}|[1-9]\d{8,10}|[2-9]\d{7}", r"2[124]\d{7,8}|619\d{8}|2(?:1(?:14|500)|2\d{3})\d{3}|61\d{5,8}|(?:2(?:[35][1-4]|6[0-8]|7[1-6]|8\d|9[1-8])|3(?:1|[25][1-8]|3[1-68]|4[1-3]|6[1-3568]|7[0-469]|8\d)|4(?:0[1-589]|1[01347-9]|2[0-36-8]|3[0-24-68]|43|5[1-378]|6[1-5]|7[134]|8[1245])|5(?:1[1-35-9]|2[25-8]|3[124-9]|4[1-3589]|5[1-46]|6[1-8])|6(?:[25]\d|3[1-69]|4[1-6])|7(?:02|[125][1-9]|[36]\d|4[1-8]|7[0-36-9])|9(?:0[12]|1[013-8]|2[0-479]|5[125-8]|6[23679]|7[159]|8[01346]))\d{5,8}", r"8[1-35-9]\d{7,10}"),
+            IE => p(353, "00", "00", Some("0"), &[7, 8, 9, 10], r"(?:1\d|[2569])\d{6,8}|4\d{6,9}|7\d{8}|8\d{8,9}", r"(?:1\d|21)\d{6,7}|(?:2[24-9]|4(?:0[24]|5\d|7)|5(?:0[45]|1\d|8)|6(?:1\d|[237-9])|9(?:1\d|[35-9]))\d{5}|(?:23|4(?:[1-469]|8\d)|5[23679]|6[4-6]|7[14]|9[04])\d{7}", r"8(?:22|[35-9]\d)\d{6}"),
+            IL => p(972, "0(?:0|1[2-9])", "00", Some("0"), &[7, 8, 9, 10, 11, 12], r"1\d{6}(?:\d{3,5})?|[57]\d{8}|[1-489]\d{7}", r"153\d{8,9}|29[1-9]\d{5}|(?:2[0-8]|[3489]\d)\d{6}", r"55(?:4(?:0[0-2]|[16]0)|57[0-289])\d{4}|5(?:(?:[0-2][02-9]|[36]\d|[49][2-9]|8[3-7])\d|5(?:01|2\d|3[0-3]|4[3-5]|5[0-25689]|6[6-8]|7[0-267]|8[7-9]|9[1-9]))\d{5}"),
+            IM => p(44, "00", "00", Some("0"), &[10], r"1624\d{6}|(?:[3578]\d|90)\d{8}", r"1624(?:230|[5-8]\d\d)\d{3}", r"76245[06]\d{4}|7(?:4576|[59]24\d|624[0-4689])\d{5}"),
+            IN => p(91, "00", "00", Some("0"), &[8, 9, 10, 11, 12, 13], r"(?:000800|[2-9]\d\d)\d{7}|1\d{7,12}", r"(?:2717(?:[2-7]\d|95)|6828[235-7]\d)\d{4}|(?:170[24]|280[13468]|4(?:20[24]|72[2-8])|552[1-7])\d{6}|(?:271[0-689]|682[0-79]|782[0-6])[2-7]\d{5}|(?:2(?:[02][2-79]|90)|3(?:23|80)|683|79[1-7])\d{7}|(?:11|33|4[04]|80)[2-7]\d{7}|(?:342|674|788)(?:[0189][2-7]|[2-7]\d)\d{5}|(?:1(?:2[0-249]|3[0-25]|4[145]|[59][14]|6[014]|7[1257]|8[01346])|2(?:1[257]|3[013]|4[01]|5[0137]|6[0158]|78|8[1568]|9[14])|3(?:26|4[13]|5[34]|6[01489]|7[02-46]|8[159])|4(?:1[36]|2[1-47]|3[15]|5[12]|6[0-26-9]|7[014-9]|8[013-57]|9[014-7])|5(?:1[025]|22|[36][25]|4[28]|[578]1|9[15])|6(?:12|[2-47]1|5[17]|6[13]|80)|7(?:12|2[14]|3[134]|4[47]|5[15]|[67]1)|8(?:16|2[014]|3[126]|6[136]|7[078]|8[34]|91))[2-7]\d{6}|(?:1(?:2[35-8]|3[346-9]|4[236-9]|[59][0235-9]|6[235-9]|7[34689]|8[257-9])|2(?:1[134689]|3[24-8]|4[2-8]|5[25689]|6[2-4679]|7[3-79]|8[2-479]|9[235-9])|3(?:01|1[79]|2[1245]|4[5-8]|5[125689]|6[235-7]|7[157-9]|8[2-46-8])|4(?:1[14578]|2[5689]|3[2-467]|5[4-7]|6[35]|73|8[2689]|9[2389])|5(?:[16][146-9]|2[14-8]|3[1346]|4[14-69]|5[46]|7[2-4]|8[2-8]|9[246])|6(?:1[1358]|2[2457]|3[2-4]|4[235-7]|5[2-689]|6[24578]|7[235689]|8[14-6])|7(?:1[013-9]|2[0235-9]|3[2679]|4[1-35689]|5[2-46-9]|[67][02-9]|8[013-7]|9[089])|8(?:1[1357-9]|2[235-8]|3[03-57-9]|4[0-24-9]|5\d|6[2457-9]|7[1-6]|8[1256]|9[2-4]))\d[2-7]\d{5}", r"(?:6(?:1279|828[01489])|7(?:887[02-9]|9(?:313|79[07-9]))|8(?:079[04-9]|(?:84|91)7[02-8]))\d{5}|(?:160[01]|6(?:12|[2-47]1|5[17]|6[13]|80)[0189]|7(?:1(?:2[0189]|9[0-5])|2(?:[14][017-9]|8[0-59])|3(?:2[5-8]|[34][017-9]|9[016-9])|4(?:1[015-9]|[29][89]|39|8[389])|5(?:[15][017-9]|2[04-9]|9[7-9])|6(?:0[0-47]|1[0-257-9]|2[0-4]|3[19]|5[4589])|70[0289]|88[089]|97[02-8])|8(?:0(?:6[67]|7[02-8])|70[017-9]|84[01489]|91[0-289]))\d{6}|(?:7(?:31|4[47])|8(?:16|2[014]|3[126]|6[136]|7[78]|83))(?:[0189]\d|7[02-8])\d{5}|(?:6(?:[09]\d|1[04679]|2[03689]|3[05-9]|4[0489]|50|6[069]|7[07]|8[7-9])|7(?:0\d|2[0235-79]|3[05-8]|40|5[0346-8]|6[6-9]|7[1-9]|8[0-79]|9[089])|8(?:0[01589]|1[0-57-9]|2[235-9]|3[03-57-9]|[45]\d|6[02457-9]|7[1-69]|8[0-25-9]|9[02-9])|9\d\d)\d{7}|(?:6(?:(?:1[1358]|2[2457]|3[2-4]|4[235-7]|5[2-689]|6[24578])\d|7(?:[235689]\d|4[0189])|8(?:[14-6]\d|2[0-79]))|7(?:1(?:[013-8]\d|9[6-9])|28[6-8]|3(?:2[0-49]|9[2-5])|4(?:1[2-4]|[29][0-7]|3[0-8]|[56]\d|8[0-24-7])|5(?:2[1-3]|9[0-6])|6(?:0[5689]|2[5-9]|3[02-8]|4\d|5[0-367])|70[13-7]|881))[0189]\d{5}"),
+            IO => p(246, "00", "00", None, &[7], r"3\d{6}", r"37\d{5}", r"38\d{5}"),
+            IQ => p(964, "00", "00", Some("0"), &[8, 9, 10], r"(?:1|7\d\d)\d{7}|[2-6]\d{7,8}", r"1\d{7}|(?:2[13-5]|3[02367]|4[023]|5[03]|6[026])\d{6,7}", r"7[3-9]\d{8}"),
+            IR => p(98, "00", "00", Some("0"), &[4, 5, 6, 7, 10], r"[1-9]\d{9}|(?:[1-8]\d\d|9)\d{3,4}", r"(?:1[137]|2[13-68]|3[1458]|4[145]|5[1468]|6[16]|7[1467]|8[13467])(?:[03-57]\d{7}|[16]\d{3}(?:\d{4})?|[289]\d{3}(?:\d(?:\d{3})?)?)|94(?:000[09]|(?:12\d|30[0-2])\d|2(?:121|[2689]0\d)|4(?:111|40\d))\d{4}", r"9(?:(?:0[0-5]|[13]\d|2[0-3])\d\d|9(?:[0-46]\d\d|5(?:10|5\d)|8(?:[12]\d|88)|9(?:[0159]\d|21|69|77|8[7-9])))\d{5}"),
+            IS => p(354, "00|1(?:0(?:01|[12]0)|100)", "00", None, &[7, 9], r"(?:38\d|[4-9])\d{6}", r"(?:4(?:1[0-24-69]|2[0-7]|[37][0-8]|4[0-24589]|5[0-68]|6\d|8[0-36-8])|5(?:05|[156]\d|2[02578]|3[0-579]|4[03-7]|7[0-2578]|8[0-35-9]|9[013-689])|872)\d{4}", r"(?:38[589]\d\d|6(?:1[1-8]|2[0-6]|3[026-9]|4[014679]|5[0159]|6[0-69]|70|8[06-8]|9\d)|7(?:5[057]|[6-9]\d)|8(?:2[0-59]|[3-69]\d|8[238]))\d{4}"),
+            IT => p(39, "00", "00", None, &[6, 7, 8, 9, 10, 11, 12], r"0\d{5,11}|1\d{8,10}|3(?:[0-8]\d{7,10}|9\d{7,8})|(?:43|55|70)\d{8}|8\d{5}(?:\d{2,4})?", r"0(?:669[0-79]\d{1,6}|831\d{2,8})|0(?:1(?:[0159]\d|[27][1-5]|31|4[1-4]|6[1356]|8[2-57])|2\d\d|3(?:[0159]\d|2[1-4]|3[12]|[48][1-6]|6[2-59]|7[1-7])|4(?:[0159]\d|[23][1-9]|4[245]|6[1-5]|7[1-4]|81)|5(?:[0159]\d|2[1-5]|3[2-6]|4[1-79]|6[4-6]|7[1-578]|8[3-8])|6(?:[0-57-9]\d|6[0-8])|7(?:[0159]\d|2[12]|3[1-7]|4[2-46]|6[13569]|7[13-6]|8[1-59])|8(?:[0159]\d|2[3-578]|3[2356]|[6-8][1-5])|9(?:[0159]\d|[238][1-5]|4[12]|6[1-8]|7[1-6]))\d{2,7}", r"3[2-9]\d{7,8}|(?:31|43)\d{8}"),
+            JE => p(44, "00", "00", Some("0"), &[10], r"1534\d{6}|(?:[3578]\d|90)\d{8}", r"1534[0-24-8]\d{5}", r"7(?:(?:(?:50|82)9|937)\d|7(?:00[378]|97\d))\d{5}"),
+            JM => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|658|900)\d{7}", r"8766060\d{3}|(?:658(?:2(?:[0-8]\d|9[0-46-9])|[3-9]\d\d)|876(?:52[35]|6(?:0[1-3579]|1[0235-9]|[23]\d|40|5[06]|6[2-589]|7[0-25-9]|8[04]|9[4-9])|7(?:0[2-689]|[1-6]\d|8[056]|9[45])|9(?:0[1-8]|1[02378]|[2-8]\d|9[2-468])))\d{4}", r"(?:658295|876(?:2(?:0[1-9]|[13-9]\d|2[013-9])|[348]\d\d|5(?:0[1-9]|[1-9]\d)|6(?:4[89]|6[67])|7(?:0[07]|7\d|8[1-47-9]|9[0-36-9])|9(?:[01]9|9[0579])))\d{4}"),
+            JO => p(962, "00", "00", Some("0"), &[8, 9], r"(?:(?:[2689]|7\d)\d|32|427|53)\d{6}", r"87(?:000|90[01])\d{3}|(?:2(?:6(?:2[0-35-9]|3[0-578]|4[24-7]|5[0-24-8]|[6-8][023]|9[0-3])|7(?:0[1-79]|10|2[014-7]|3[0-689]|4[019]|5[0-3578]))|32(?:0[1-69]|1[1-35-7]|2[024-7]|3\d|4[0-3]|[5-7][023])|53(?:0[0-3]|[13][023]|2[0-59]|49|5[0-35-9]|6[15]|7[45]|8[1-6]|9[0-36-9])|6(?:2(?:[05]0|22)|3(?:00|33)|4(?:0[0-25]|1[2-7]|2[0569]|[38][07-9]|4[025689]|6[0-589]|7\d|9[0-2])|5(?:[01][056]|2[034]|3[0-57-9]|4[178]|5[0-69]|6[0-35-9]|7[1-379]|8[0-68]|9[0239]))|87(?:20|7[078]|99))\d{4}", r"(?:427|7(?:[78][0-25-9]|9\d))\d{6}"),
+            JP => p(81, "010", "010", Some("0"), &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17], r"00[1-9]\d{6,14}|[25-9]\d{9}|(?:00|[1-9]\d\d)\d{6}", r"(?:1(?:1[235-8]|2[3-6]|3[3-9]|4[2-6]|[58][2-8]|6[2-7]|7[2-9]|9[1-9])|(?:2[2-9]|[36][1-9])\d|4(?:[2-578]\d|6[02-8]|9[2-59])|5(?:[2-589]\d|6[1-9]|7[2-8])|7(?:[25-9]\d|3[4-9]|4[02-9])|8(?:[2679]\d|3[2-9]|4[5-9]|5[1-9]|8[03-9])|9(?:[2-58]\d|[679][1-9]))\d{6}", r"(?:601[0-4]0|[7-9]0[1-9]\d\d)\d{5}"),
+            KE => p(254, "000", "000", Some("0"), &[7, 8, 9, 10], r"(?:[17]\d\d|900)\d{6}|(?:2|80)0\d{6,7}|[4-6]\d{6,8}", r"(?:4[245]|5[1-79]|6[01457-9])\d{5,7}|(?:4[136]|5[08]|62)\d{7}|(?:[24]0|66)\d{6,7}", r"(?:1(?:0[0-8]|1\d|2[014]|[34]0)|7\d\d)\d{6}"),
+            KG => p(996, "00", "00", Some("0"), &[9, 10], r"8\d{9}|[235-9]\d{8}", r"312(?:5[0-79]\d|9(?:[0-689]\d|7[0-24-9]))\d{3}|(?:3(?:1(?:2[0-46-8]|3[1-9]|47|[56]\d)|2(?:22|3[0-479]|6[0-7])|4(?:22|5[6-9]|6\d)|5(?:22|3[4-7]|59|6\d)|6(?:22|5[35-7]|6\d)|7(?:22|3[468]|4[1-9]|59|[67]\d)|9(?:22|4[1-8]|6\d))|6(?:09|12|2[2-4])\d)\d{5}", r"312(?:58\d|973)\d{3}|(?:2(?:0[0-35]|2\d)|5[0-24-7]\d|600|7(?:[07]\d|55)|88[08]|9(?:12|9[05-9]))\d{6}"),
+            KH => p(855, "00[14-9]", "001", Some("0"), &[8, 9, 10], r"1\d{9}|[1-9]\d{7,8}", r"23(?:4(?:[2-4]|[56]\d)|[568]\d\d)\d{4}|23[236-9]\d{5}|(?:2[4-6]|3[2-6]|4[2-4]|[5-7][2-5])(?:(?:[237-9]|4[56]|5\d)\d{5}|6\d{5,6})", r"(?:(?:1[28]|3[18]|9[67])\d|6[016-9]|7(?:[07-9]|[16]\d)|8(?:[013-79]|8\d))\d{6}|(?:1\d|9[0-57-9])\d{6}|(?:2[3-6]|3[2-6]|4[2-4]|[5-7][2-5])48\d{5}"),
+            KI => p(686, "00", "00", Some("0"), &[5, 8], r"(?:[37]\d|6[0-79])\d{6}|(?:[2-48]\d|50)\d{3}", r"(?:[24]\d|3[1-9]|50|65(?:02[12]|12[56]|22[89]|[3-5]00)|7(?:27\d\d|3100|5(?:02[12]|12[56]|22[89]|[34](?:00|81)|500))|8[0-5])\d{3}", r"(?:6200[01]|7(?:310[1-9]|5(?:02[03-9]|12[0-47-9]|22[0-7]|[34](?:0[1-9]|8[02-9])|50[1-9])))\d{3}|(?:63\d\d|7(?:(?:[0146-9]\d|2[0-689])\d|3(?:[02-9]\d|1[1-9])|5(?:[0-2][013-9]|[34][1-79]|5[1-9]|[6-9]\d)))\d{4}"),
+            KM => p(269, "00", "00", None, &[7], r"[3478]\d{6}", r"7[4-7]\d{5}", r"[34]\d{6}"),
+            KN => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|900)\d{7}", r"869(?:2(?:29|36)|302|4(?:6[015-9]|70)|56[5-7])\d{4}", r"869(?:48[89]|55[6-8]|66\d|76[02-7])\d{4}"),
+            KP => p(850, "00|99", "00", Some("0"), &[8, 10], r"85\d{6}|(?:19\d|[2-7])\d{7}", r"(?:(?:195|2)\d|3[19]|4[159]|5[37]|6[17]|7[39]|85)\d{6}", r"19[1-3]\d{7}"),
+            KR => p(82, "00(?:[125689]|3(?:[46]5|91)|7(?:00|27|3|55|6[126]))", "001", Some("0"), &[5, 6, 8, 9, 10, 11, 12, 13, 14], r"00[1-9]\d{8,11}|(?:[12]|5\d{3})\d{7}|[13-6]\d{9}|(?:[1-6]\d|80)\d{7}|[3-6]\d{4,5}|(?:00|7)0\d{8}", r"(?:2|3[1-3]|[46][1-4]|5[1-5])[1-9]\d{6,7}|(?:3[1-3]|[46][1-4]|5[1-5])1\d{2,3}", r"1(?:05(?:[0-8]\d|9[0-6])|22[13]\d)\d{4,5}|1(?:0[0-46-9]|[16-9]\d|2[013-9])\d{6,7}"),
+            KW => p(965, "00", "00", None, &[7, 8], r"18\d{5}|(?:[2569]\d|41)\d{6}", r"2(?:[23]\d\d|4(?:[1-35-9]\d|44)|5(?:0[034]|[2-46]\d|5[1-3]|7[1-7]))\d{4}", r"(?:41\d\d|5(?:(?:[05]\d|1[0-7]|6[56])\d|2(?:22|5[25])|7(?:55|77)|88[58])|6(?:(?:0[034679]|5[015-9]|6\d)\d|1(?:00|11|6[16])|2[26]2|3[36]3|4[46]4|7(?:0[013-9]|[67]\d)|8[68]8|9(?:[069]\d|3[039]))|9(?:(?:[04679]\d|8[057-9])\d|1(?:00|1[01]|99)|2(?:00|2\d)|3(?:00|3[03])|5(?:00|5\d)))\d{4}"),
+            KY => p(1, "011", "011", Some("1"), &[10], r"(?:345|[58]\d\d|900)\d{7}", r"345(?:2(?:22|3[23]|44|66)|333|444|6(?:23|38|40)|7(?:30|4[35-79]|6[6-9]|77)|8(?:00|1[45]|4[89]|88)|9(?:14|4[035-9]))\d{4}", r"345(?:32[1-9]|42[0-4]|5(?:1[67]|2[5-79]|4[6-9]|50|76)|649|82[56]|9(?:1[679]|2[2-9]|3[06-9]|90))\d{4}"),
+            KZ => p(7, "810", "810", Some("8"), &[10, 14], r"(?:33622|8\d{8})\d{5}|[78]\d{9}", r"(?:33622|7(?:1(?:0(?:[23]\d|4[0-3]|59|63)|1(?:[23]\d|4[0-79]|59)|2(?:[23]\d|59)|3(?:2\d|3[0-79]|4[0-35-9]|59)|4(?:[24]\d|3[013-9]|5[1-9]|97)|5(?:2\d|3[1-9]|4[0-7]|59)|6(?:[2-4]\d|5[19]|61)|72\d|8(?:[27]\d|3[1-46-9]|4[0-5]|59))|2(?:1(?:[23]\d|4[46-9]|5[3469])|2(?:2\d|3[0679]|46|5[12679])|3(?:[2-4]\d|5[139])|4(?:2\d|3[1-35-9]|59)|5(?:[23]\d|4[0-8]|59|61)|6(?:2\d|3[1-9]|4[0-4]|59)|7(?:[2379]\d|40|5[279])|8(?:[23]\d|4[0-3]|59)|9(?:2\d|3[124578]|59))))\d{5}", r"7(?:0[0-25-8]|47|6[0-4]|7[15-8]|85)\d{7}"),
+            LA => p(856, "00", "00", Some("0"), &[8, 9, 10], r"[23]\d{9}|3\d{8}|(?:[235-8]\d|41)\d{6}", r"(?:2[13]|[35-7][14]|41|8[1468])\d{6}", r"(?:20(?:[23579]\d|8[78])|30[24]\d)\d{6}|30\d{7}"),
+            LB => p(961, "00", "00", Some("0"), &[7, 8], r"[27-9]\d{7}|[13-9]\d{6}", r"7(?:62|8[0-6]|9[04-9])\d{4}|(?:[14-69]\d|2(?:[14-69]\d|[78][1-9])|7[2-57]|8[02-9])\d{5}", r"(?:(?:3|81)\d|7(?:[01]\d|6[013-9]|8[7-9]|9[0-4]))\d{5}"),
+            LC => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|758|900)\d{7}", r"758(?:234|4(?:30|5\d|6[2-9]|8[0-2])|57[0-2]|(?:63|75)8)\d{4}", r"758(?:28[4-7]|384|4(?:6[01]|8[4-9])|5(?:1[89]|20|84)|7(?:1[2-9]|2\d|3[0-3])|812)\d{4}"),
+            LI => p(423, "00", "00", Some("0"), &[7, 9], r"[68]\d{8}|(?:[2378]\d|90)\d{5}", r"(?:2(?:01|1[27]|2[024]|3\d|6[02-578]|96)|3(?:[24]0|33|7[0135-7]|8[048]|9[0269]))\d{4}", r"(?:6(?:(?:4[5-9]|5\d)\d|6(?:[024-68]\d|1[01]|3[7-9]|70))\d|7(?:[37-9]\d|42|56))\d{4}"),
+            LK => p(94, "00", "00", Some("0"), &[9], r"[1-9]\d{8}", r"(?:12[2-9]|602|8[12]\d|9(?:1\d|22|9[245]))\d{6}|(?:11|2[13-7]|3[1-8]|4[157]|5[12457]|6[35-7])[2-57]\d{6}", r"7(?:[0-25-8]\d|4[0-4])\d{6}"),
+            LR => p(231, "00", "00", Some("0"), &[7, 8, 9], r"(?:[2457]\d|33|88)\d{7}|(?:2\d|[4-6])\d{6}", r"2\d{7}", r"(?:(?:(?:22|33)0|555|7(?:6[01]|7\d)|88\d)\d|4(?:240|[67]))\d{5}|[56]\d{6}"),
+            LS => p(266, "00", "00", None, &[8], r"(?:[256]\d\d|800)\d{5}", r"2\d{7}", r"[56]\d{7}"),
+            LT => p(370, "00", "00", Some("0"), &[8], r"(?:[3469]\d|52|[78]0)\d{6}", r"(?:3[1478]|4[124-6]|52)\d{6}", r"6\d{7}"),
+            LU => p(352, "00", "00", None, &[4, 5, 6, 7, 8, 9, 10, 11], r"35[013-9]\d{4,8}|6\d{8}|35\d{2,4}|(?:[2457-9]\d|3[0-46-9])\d{2,9}", r"(?:35[013-9]|80[2-9]|90[89])\d{1,8}|(?:2[2-9]|3[0-46-9]|[457]\d|8[13-9]|9[2-579])\d{2,9}", r"6(?:[269][18]|5[1568]|7[189]|81)\d{6}"),
+            LV => p(371, "00", "00", None, &[8], r"(?:[268]\d|78|90)\d{6}", r"6\d{7}", r"2333[0-8]\d{3}|2(?:[0-24-9]\d\d|3(?:0[07]|[14-9]\d|2[02-9]|3[0-24-9]))\d{4}"),
+            LY => p(218, "00", "00", Some("0"), &[9], r"[2-9]\d{8}", r"(?:2(?:0[56]|[1-6]\d|7[124579]|8[124])|3(?:1\d|2[2356])|4(?:[17]\d|2[1-357]|5[2-4]|8[124])|5(?:[1347]\d|2[1-469]|5[13-5]|8[1-4])|6(?:[1-479]\d|5[2-57]|8[1-5])|7(?:[13]\d|2[13-79])|8(?:[124]\d|5[124]|84))\d{6}", r"9[1-6]\d{7}"),
+            MA => p(212, "00", "00", Some("0"), &[9], r"[5-8]\d{8}", r"5(?:(?:18|4[0679]|5[03])\d|2(?:[0-25-79]\d|3[1-578]|4[02-46-8]|8[0235-7])|3(?:[0-47]\d|5[02-9]|6[02-8]|8[014-9]|9[3-9]))\d{5}", r"(?:6(?:[0-79]\d|8[0-247-9])|7(?:[016-8]\d|2[0-8]|5[0-5]))\d{6}"),
+            MC => p(377, "00", "00", Some("0"), &[8, 9], r"(?:[3489]|[67]\d)\d{7}", r"(?:870|9[2-47-9]\d)\d{5}", r"4(?:[469]\d|5[1-9])\d{5}|(?:3|[67]\d)\d{7}"),
+            MD => p(373, "00", "00", Some("0"), &[8], r"(?:[235-7]\d|[89]0)\d{6}", r"(?:(?:2[1-9]|3[1-79])\d|5(?:33|5[257]))\d{5}", r"562\d{5}|(?:6\d|7[16-9])\d{6}"),
+            ME => p(382, "00", "00", Some("0"), &[8, 9], r"(?:20|[3-79]\d)\d{6}|80\d{6,7}", r"(?:20[2-8]|3(?:[0-2][2-7]|3[24-7])|4(?:0[2-467]|1[2467])|5(?:0[2467]|1[24-7]|2[2-467]))\d{5}", r"6(?:[07-9]\d|3[024]|6[0-25])\d{5}"),
+            MF => p(590, "00", "00", Some("0"), &[9], r"(?:590\d|7090)\d{5}|(?:69|80|9\d)\d{7}", r"590(?:0[079]|[14]3|[27][79]|3[03-7]|5[0-268]|87)\d{4}", r"(?:69(?:0\d\d|1(?:2[2-9]|3[0-5])|4(?:0[89]|1[2-6]|9\d)|6(?:1[016-9]|5[0-4]|[67]\d))|7090[0-4])\d{4}"),
+            MG => p(261, "00", "00", Some("0"), &[9], r"[23]\d{8}", r"2072[29]\d{4}|20(?:2\d|4[47]|5[3467]|6[279]|7[356]|8[268]|9[2457])\d{5}", r"3[2-9]\d{7}"),
+            MH => p(692, "011", "011", Some("1"), &[7], r"329\d{4}|(?:[256]\d|45)\d{5}", r"(?:247|528|625)\d{4}", r"(?:(?:23|54)5|329|45[35-8])\d{4}"),
+            MK => p(389, "00", "00", Some("0"), &[8], r"[2-578]\d{7}", r"(?:(?:2(?:62|77)0|3444)\d|4[56]440)\d{3}|(?:34|4[357])700\d{3}|(?:2(?:[0-3]\d|5[0-578]|6[01]|82)|3(?:1[3-68]|[23][2-68]|4[23568])|4(?:[23][2-68]|4[3-68]|5[2568]|6[25-8]|7[24-68]|8[4-68]))\d{5}", r"7(?:3555|(?:474|9[019]7)7)\d{3}|7(?:[0-25-8]\d\d|3(?:[1-478]\d|6[01])|4(?:2\d|60|7[01578])|9(?:[2-4]\d|5[01]|7[015]))\d{4}"),
+            ML => p(223, "00", "00", None, &[8], r"[24-9]\d{7}", r"2(?:07[0-8]|12[67])\d{4}|(?:2(?:02|1[4-689])|4(?:0[0-4]|4[1-59]))\d{5}", r"2(?:0(?:01|79)|17\d)\d{4}|(?:5[0-3]|[679]\d|8[2-59])\d{6}"),
+            MM => p(95, "00", "00", Some("0"), &[6, 7, 8, 9, 10], r"1\d{5,7}|95\d{6}|(?:[4-7]|9[0-46-9])\d{6,8}|(?:2|8\d)\d{5,8}", r"(?:1(?:(?:12|[28]\d|3[56]|7[3-6]|9[0-6])\d|4(?:2[29]|7[0-2]|83)|6)|2(?:2(?:00|8[34])|4(?:0\d|22|7[0-2]|83)|51\d\d)|4(?:2(?:2\d\d|48[013])|3(?:20\d|4(?:70|83)|56)|420\d|5(?:2\d|470))|6(?:0(?:[23]|88\d)|(?:124|[56]2\d)\d|2472|3(?:20\d|470)|4(?:2[04]\d|472)|7(?:3\d\d|4[67]0|8(?:[01459]\d|8))))\d{4}|5(?:2(?:2\d{5,6}|47[02]\d{4})|(?:3472|4(?:2(?:1|86)|470)|522\d|6(?:20\d|483)|7(?:20\d|48[01])|8(?:20\d|47[02])|9(?:20\d|470))\d{4})|7(?:(?:0470|4(?:25\d|470)|5(?:202|470|96\d))\d{4}|1(?:20\d{4,5}|4(?:70|83)\d{4}))|8(?:1(?:2\d{5,6}|4(?:10|7[01]\d)\d{3})|2(?:2\d{5,6}|(?:320|490\d)\d{3})|(?:3(?:2\d\d|470)|4[24-7]|5(?:(?:2\d|51)\d|4(?:[1-35-9]\d|4[0-57-9]))|6[23])\d{4})|(?:1[2-6]\d|4(?:2[24-8]|3[2-7]|[46][2-6]|5[3-5])|5(?:[27][2-8]|3[2-68]|4[24-8]|5[23]|6[2-4]|8[24-7]|9[2-7])|6(?:[19]20|42[03-6]|(?:52|7[45])\d)|7(?:[04][24-8]|[15][2-7]|22|3[2-4])|8(?:1[2-689]|2[2-8]|(?:[35]2|64)\d))\d{4}|25\d{5,6}|(?:2[2-9]|6(?:1[2356]|[24][2-6]|3[24-6]|5[2-4]|6[2-8]|7[235-7]|8[245]|9[24])|8(?:3[24]|5[245]))\d{4}", r"(?:17[01]|9(?:2(?:[0-4]|[56]\d\d)|(?:3(?:[0-36]|4\d)|(?:6\d|8[89]|9[4-8])\d|7(?:3|40|[5-9]\d))\d|4(?:(?:[0245]\d|[1379])\d|88)|5[0-6])\d)\d{4}|9[69]1\d{6}|9(?:[68]\d|9[089])\d{5}"),
+            MN => p(976, "001", "001", Some("0"), &[8, 9, 10], r"[12]\d{7,9}|[5-9]\d{7}", r"[12]2[1-3]\d{5,6}|(?:(?:[12](?:1|27)|5[368])\d\d|7(?:0(?:[0-5]\d|7[078]|80)|128))\d{4}|[12](?:3[2-8]|4[2-68]|5[1-4689])\d{6,7}", r"92[0139]\d{5}|(?:5[05]|6[069]|7[28]|8[0135689]|9[013-9])\d{6}"),
+            MO => p(853, "00", "00", None, &[7, 8], r"0800\d{3}|(?:28|[68]\d)\d{6}", r"(?:28[2-9]|8(?:11|[2-57-9]\d))\d{5}", r"6800[0-79]\d{3}|6(?:[235]\d\d|6(?:0[0-5]|[1-9]\d)|8(?:0[1-9]|[14-8]\d|2[5-9]|[39][0-4]))\d{4}"),
+            MP => p(1, "011", "011", Some("1"), &[10], r"[58]\d{9}|(?:67|90)0\d{7}", r"670(?:2(?:3[3-7]|56|8[4-8])|32[1-38]|4(?:33|8[348])|5(?:32|55|88)|6(?:64|70|82)|78[3589]|8[3-9]8|989)\d{4}", r"670(?:2(?:3[3-7]|56|8[4-8])|32[1-38]|4(?:33|8[348])|5(?:32|55|88)|6(?:64|70|82)|78[3589]|8[3-9]8|989)\d{4}"),
+            MQ => p(596, "00", "00", Some("0"), &[9], r"(?:596\d|7091)\d{5}|(?:69|[89]\d)\d{7}", r"(?:596(?:[03-7]\d|1[05]|2[7-9]|8[0-39]|9[04-9])|80[6-9]\d\d|9(?:477[6-9]|767[4589]))\d{4}", r"(?:69[67]\d\d|7091[0-3])\d{4}"),
+            MR => p(222, "00", "00", None, &[8], r"(?:[2-4]\d\d|800)\d{5}", r"(?:25[08]|35\d|45[1-7])\d{5}", r"[2-4][0-46-9]\d{6}"),
+            MS => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|664|900)\d{7}", r"6644(?:1[0-3]|91)\d{4}", r"664(?:3(?:49|9[1-6])|49[2-6])\d{4}"),
+            MT => p(356, "00", "00", None, &[8], r"3550\d{4}|(?:[2579]\d\d|800)\d{5}", r"20(?:3[1-4]|6[059])\d{4}|2(?:0[19]|[1-357]\d|60)\d{5}", r"(?:7(?:210|[79]\d\d)|9(?:[29]\d\d|69[67]|8(?:1[1-3]|89|97)))\d{4}"),
+            MU => p(230, "0(?:0|[24-7]0|3[03])", "020", None, &[7, 8, 10], r"(?:[57]|8\d\d)\d{7}|[2-468]\d{6}", r"(?:2(?:[0346-8]\d|1[0-8])|4(?:[013568]\d|2[4-8]|71|90)|54(?:[3-5]\d|71)|6\d\d|8(?:14|3[129]))\d{4}", r"5(?:4(?:2[1-389]|7[1-9])|87[15-8])\d{4}|(?:5(?:2[5-9]|4[3-689]|[57]\d|8[0-689]|9[0-8])|7(?:0[0-7]|3[013]))\d{5}"),
+            MV => p(960, "0(?:0|19)", "00", None, &[7, 10], r"(?:800|9[0-57-9]\d)\d{7}|[34679]\d{6}", r"(?:3(?:0[0-4]|3[0-59])|6(?:[58][024689]|6[024-68]|7[02468]))\d{4}", r"(?:46[46]|[79]\d\d)\d{4}"),
+            MW => p(265, "00", "00", Some("0"), &[7, 9], r"(?:[1289]\d|31|77)\d{7}|1\d{6}", r"(?:1[2-9]|2[12]\d\d)\d{5}", r"111\d{6}|(?:31|77|[89][89])\d{7}"),
+            MX => p(52, "0[09]", "00", None, &[10], r"[2-9]\d{9}", r"(?:2(?:0[01]|2\d|3[1-35-8]|4[13-9]|7[1-689]|8[1-578]|9[467])|3(?:1[1-79]|[2458][1-9]|3\d|7[1-8]|9[1-5])|4(?:1[1-57-9]|[267][1-9]|3[1-8]|[45]\d|8[1-35-9]|9[2-689])|5(?:[56]\d|88|9[1-79])|6(?:1[2-68]|[2-4][1-9]|5[1-36-9]|6[0-57-9]|7[1-7]|8[67]|9[4-8])|7(?:[1346][1-9]|[27]\d|5[13-9]|8[1-69]|9[17])|8(?:1\d|2[13-689]|3[1-6]|4[124-6]|6[1246-9]|7[0-378]|9[12479])|9(?:1[346-9]|2[1-4]|3[2-46-8]|5[1348]|[69]\d|7[12]|8[1-8]))\d{7}", r"(?:2(?:2\d|3[1-35-8]|4[13-9]|7[1-689]|8[1-578]|9[467])|3(?:1[1-79]|[2458][1-9]|3\d|7[1-8]|9[1-5])|4(?:1[1-57-9]|[267][1-9]|3[1-8]|[45]\d|8[1-35-9]|9[2-689])|5(?:[56]\d|88|9[1-79])|6(?:1[2-68]|[2-4][1-9]|5[1-36-9]|6[0-57-9]|7[1-7]|8[67]|9[4-8])|7(?:[1346][1-9]|[27]\d|5[13-9]|8[1-69]|9[17])|8(?:1\d|2[13-689]|3[1-6]|4[124-6]|6[1246-9]|7[0-378]|9[12479])|9(?:1[346-9]|2[1-4]|3[2-46-8]|5[1348]|[69]\d|7[12]|8[1-8]))\d{7}"),
+            MY => p(60, "00", "00", Some("0"), &[8, 9, 10], r"1\d{8,9}|(?:3\d|[4-9])\d{7}", r"427[01]\d{4}|(?:3(?:2[0-36-9]|3[0-368]|4[0-278]|5[0-24-8]|6[0-467]|7[1246-9]|8\d|9[0-57])\d|4(?:2[0-689]|[3-79]\d|8[1-35689])|5(?:2[0-589]|[3468]\d|5[0-489]|7[1-9]|9[23])|6(?:2[2-9]|3[1357-9]|[46]\d|5[0-6]|7[0-35-9]|85|9[015-8])|7(?:[2579]\d|3[03-68]|4[0-8]|6[5-9]|8[0-35-9])|8(?:[24][2-8]|3[2-5]|5[2-7]|6[2-589]|7[2-578]|[89][2-9])|9(?:0[57]|13|[25-7]\d|[3489][0-8]))\d{5}", r"1(?:1888[689]|4400|8(?:47|8[27])[0-4])\d{4}|1(?:0(?:[23568]\d|4[0-6]|7[016-9]|9[0-8])|1(?:[1-5]\d\d|6(?:0[5-9]|[1-9]\d)|7(?:[0-4]\d|5[0-7]))|(?:[269]\d|[37][1-9]|4[235-9])\d|5(?:31|9\d\d)|8(?:1[23]|[236]\d|4[06]|5(?:46|[7-9])|7[016-9]|8[01]|9[0-8]))\d{5}"),
+            MZ => p(258, "00", "00", None, &[8, 9], r"(?:2|8\d)\d{7}", r"2(?:[1346]\d|5[0-2]|[78][12]|93)\d{5}", r"8[2-79]\d{7}"),
+            NA => p(264, "00", "00", Some("0"), &[8, 9], r"[68]\d{7,8}", r"64426\d{3}|6(?:1(?:2[2-7]|3[01378]|4[0-4])|254|32[0237]|4(?:27|41|5[25])|52[236-8]|626|7(?:2[2-4]|30))\d{4,5}|6(?:1(?:(?:0\d|2[0189]|3[24-69]|4[5-9])\d|17|69|7[014])|2(?:17|5[0-36-8]|69|70)|3(?:17|2[14-689]|34|6[289]|7[01]|81)|4(?:17|2[0-2]|4[06]|5[0137]|69|7[01])|5(?:17|2[0459]|69|7[01])|6(?:17|25|38|42|69|7[01])|7(?:17|2[569]|3[13]|6[89]|7[01]))\d{4}", r"(?:60|8[1245])\d{7}"),
+            NC => p(687, "00", "00", None, &[6], r"(?:050|[2-57-9]\d\d)\d{3}", r"(?:2[03-9]|3[0-5]|4[1-7]|88)\d{4}", r"(?:[579]\d|8[0-79])\d{4}"),
+            NE => p(227, "00", "00", None, &[8], r"[027-9]\d{7}", r"2(?:0(?:20|3[1-8]|4[13-5]|5[14]|6[14578]|7[1-578])|1(?:4[145]|5[14]|6[14-68]|7[169]|88))\d{4}", r"(?:23|7[0467]|[89]\d)\d{6}"),
+            NF => p(672, "00", "00", None, &[6], r"[13]\d{5}", r"(?:1(?:06|17|28|39)|3[0-2]\d)\d{3}", r"(?:14|3[58])\d{4}"),
+            NG => p(234, "009", "009", Some("0"), &[10, 11, 12, 13, 14], r"(?:20|9\d)\d{8}|[78]\d{9,13}", r"20(?:[1259]\d|3[013-9]|4[1-8]|6[024-689]|7[1-79]|8[2-9])\d{6}", r"(?:702[0-24-9]|819[01])\d{6}|(?:7(?:0[13-9]|[12]\d)|8(?:0[1-9]|1[0-8])|9(?:0[1-9]|1[1-6]))\d{7}"),
+            NI => p(505, "00", "00", None, &[8], r"(?:1800|[25-8]\d{3})\d{4}", r"2\d{7}", r"(?:5(?:5[0-7]|[78]\d)|6(?:20|3[035]|4[045]|5[05]|77|8[1-9]|9[059])|(?:7[5-8]|8\d)\d)\d{5}"),
+            NL => p(31, "00", "00", Some("0"), &[5, 6, 7, 8, 9, 10, 11], r"(?:[124-7]\d\d|3(?:[02-9]\d|1[0-8]))\d{6}|8\d{6,9}|9\d{6,10}|1\d{4,5}", r"(?:1(?:[035]\d|1[13-578]|6[124-8]|7[24]|8[0-467])|2(?:[0346]\d|2[2-46-9]|5[125]|9[479])|3(?:[03568]\d|1[3-8]|2[01]|4[1-8])|4(?:[0356]\d|1[1-368]|7[58]|8[15-8]|9[23579])|5(?:[0358]\d|[19][1-9]|2[1-57-9]|4[13-8]|6[126]|7[0-3578])|7\d\d)\d{6}", r"(?:6[1-58]|970\d)\d{7}"),
+            NO => p(47, "00", "00", None, &[5, 8], r"(?:0|[2-9]\d{3})\d{4}", r"(?:2[1-4]|3[1-3578]|5[1-35-7]|6[1-4679]|7[0-8])\d{6}", r"(?:4[015-8]|9\d)\d{6}"),
+            NP => p(977, "00", "00", Some("0"), &[8, 10, 11], r"(?:1\d|9)\d{9}|[1-9]\d{7}", r"(?:1[0-6]\d|99[02-6])\d{5}|(?:2[13-79]|3[135-8]|4[146-9]|5[135-7]|6[13-9]|7[15-9]|8[1-46-9]|9[1-7])[2-6]\d{5}", r"9(?:00|6[0-3]|7[0-24-6]|8[0-24-68])\d{7}"),
+            NR => p(674, "00", "00", None, &[7], r"(?:222|444|(?:55|8\d)\d|666|777|999)\d{4}", r"444\d{4}", r"(?:222|55[3-9]|666|777|8\d\d|999)\d{4}"),
+            NU => p(683, "00", "00", None, &[4, 7], r"(?:[4-7]|888\d)\d{3}", r"[47]\d{3}", r"(?:[56]|888[1-9])\d{3}"),
+            NZ => p(64, "0(?:0|161)", "00", Some("0"), &[5, 6, 7, 8, 9, 10], r"[1289]\d{9}|50\d{5}(?:\d{2,3})?|[27-9]\d{7,8}|(?:[34]\d|6[0-35-9])\d{6}|8\d{4,6}", r"240\d{5}|(?:3[2-79]|[49][2-9]|6[235-9]|7[2-57-9])\d{6}", r"2(?:[0-27-9]\d|6)\d{6,7}|2(?:1\d|75)\d{5}"),
+            OM => p(968, "00", "00", None, &[7, 8, 9], r"(?:1505|[279]\d{3}|500)\d{4}|800\d{5,6}", r"2[1-6]\d{6}", r"(?:1505|90[1-9]\d)\d{4}|(?:7[124-9]|9[1-9])\d{6}"),
+            PA => p(507, "00", "00", None, &[7, 8, 10, 11], r"(?:00800|8\d{3})\d{6}|[68]\d{7}|[1-57-9]\d{6}", r"(?:1(?:0\d|1[0479]|2[37]|3[0137]|4[17]|5[05]|6[058]|7[0167]|8[2358]|9[1389])|2(?:[0235-79]\d|1[0-7]|4[013-9]|8[02-9])|3(?:[047-9]\d|1[0-8]|2[0-5]|33|5[0-35]|6[068])|4(?:00|3[0-579]|4\d|7[0-57-9])|5(?:[01]\d|2[0-7]|[56]0|79)|7(?:0[09]|2[0-26-8]|3[03]|4[04]|5[05-9]|6[0156]|7[0-24-9]|8[4-9]|90)|8(?:09|2[89]|3\d|4[0-24-689]|5[014]|8[02])|9(?:0[5-9]|1[0135-8]|2[036-9]|3[35-79]|40|5[0457-9]|6[05-9]|7[04-9]|8[35-8]|9\d))\d{4}", r"(?:1[16]1|21[89]|6\d{3}|8(?:1[01]|7[23]))\d{4}"),
+            PE => p(51, "00|19(?:1[124]|77|90)00", "00", Some("0"), &[8, 9], r"(?:[14-8]|9\d)\d{7}", r"(?:(?:(?:4[34]|5[14])[0-8]|687)\d|7(?:173|(?:3[0-8]|55)\d)|8(?:10[05689]|6(?:0[06-9]|1[6-9]|29)|7(?:0[0569]|[56]0)))\d{4}|(?:1[0-8]|4[12]|5[236]|6[1-7]|7[246]|8[2-4])\d{6}", r"9\d{8}"),
+            PF => p(689, "00", "00", None, &[6, 8, 9], r"4\d{5}(?:\d{2})?|8\d{7,8}", r"4(?:0[4-689]|9[4-68])\d{5}", r"8[7-9]\d{6}"),
+            PG => p(675, "00|140[1-3]", "00", None, &[7, 8], r"(?:180|[78]\d{3})\d{4}|(?:[2-589]\d|64)\d{5}", r"(?:(?:3[0-2]|4[257]|5[34]|9[78])\d|64[1-9]|85[02-46-9])\d{4}", r"(?:7\d|8[1-48])\d{6}"),
+            PH => p(63, "00", "00", Some("0"), &[6, 8, 9, 10, 11, 12, 13], r"(?:[2-7]|9\d)\d{8}|2\d{5}|(?:1800|8)\d{7,9}", r"(?:(?:2[3-8]|3[2-68]|4[2-9]|5[2-6]|6[2-58]|7[24578])\d{3}|88(?:22\d\d|42))\d{4}|(?:2|8[2-8]\d\d)\d{5}", r"(?:8(?:1[37]|9[5-8])|9(?:0[5-9]|1[0-24-9]|[235-7]\d|4[2-9]|8[135-9]|9[1-9]))\d{7}"),
+            PK => p(92, "00", "00", Some("0"), &[8, 9, 10, 11, 12], r"122\d{6}|[24-8]\d{10,11}|9(?:[013-9]\d{8,10}|2(?:[01]\d\d|2(?:[06-8]\d|1[01]))\d{7})|(?:[2-8]\d{3}|92(?:[0-7]\d|8[1-9]))\d{6}|[24-9]\d{8}|[89]\d{7}", r"(?:(?:21|42)[2-9]|58[126])\d{7}|(?:2[25]|4[0146-9]|5[1-35-7]|6[1-8]|7[14]|8[16]|91)[2-9]\d{6,7}|(?:2(?:3[2358]|4[2-4]|9[2-8])|45[3479]|54[2-467]|60[468]|72[236]|8(?:2[2-689]|3[23578]|4[3478]|5[2356])|9(?:2[2-8]|3[27-9]|4[2-6]|6[3569]|9[25-8]))[2-9]\d{5,6}", r"3(?:[0-247]\d|3[0-79]|55|64)\d{7}"),
+            PL => p(48, "00", "00", None, &[6, 7, 8, 9, 10], r"(?:6|8\d\d)\d{7}|[1-9]\d{6}(?:\d{2})?|[26]\d{5}", r"47\d{7}|(?:1[2-8]|2[2-69]|3[2-4]|4[1-468]|5[24-689]|6[1-3578]|7[14-7]|8[1-79]|9[145])(?:[02-9]\d{6}|1(?:[0-8]\d{5}|9\d{3}(?:\d{2})?))", r"2131[89]\d{4}|21(?:1[013-5]|2\d|3[2-9])\d{5}|(?:45|5[0137]|6[069]|7[2389]|88)\d{7}"),
+            PM => p(508, "00", "00", Some("0"), &[6, 9], r"[45]\d{5}|(?:708|8\d\d)\d{6}", r"(?:4[1-35-9]|5[0-47-9]|80[6-9]\d\d)\d{4}", r"(?:4[02-489]|5[02-9]|708(?:4[0-5]|5[0-6]))\d{4}"),
+            PN => return None,
+            PR => p(1, "011", "011", Some("1"), &[10], r"(?:[589]\d\d|787)\d{7}", r"(?:787|939)[2-9]\d{6}", r"(?:787|939)[2-9]\d{6}"),
+            PS => p(970, "00", "00", Some("0"), &[8, 9, 10], r"[2489]2\d{6}|(?:1\d|5)\d{8}", r"(?:22[2-47-9]|42[45]|82[014-68]|92[3569])\d{5}", r"5[69]\d{7}"),
+            PT => p(351, "00", "00", None, &[9], r"1693\d{5}|(?:[26-9]\d|30)\d{7}", r"2(?:[12]\d|3[1-689]|4[1-59]|[57][1-9]|6[1-35689]|8[1-69]|9[1256])\d{6}", r"6(?:[06]92(?:30|9\d)|[35]92(?:[049]\d|3[034]))\d{3}|(?:(?:16|6[0356])93|9(?:[1-36]\d\d|480))\d{5}"),
+            PW => p(680, "01[12]", "011", None, &[7], r"(?:[24-8]\d\d|345|900)\d{4}", r"(?:2(?:55|77)|345|488|5(?:35|44|87)|6(?:22|54|79)|7(?:33|47)|8(?:24|55|76)|900)\d{4}", r"(?:(?:46|83)[0-5]|(?:6[2-4689]|78)0)\d{4}|(?:45|77|88)\d{5}"),
+            PY => p(595, "00", "00", Some("0"), &[6, 7, 8, 9, 10, 11], r"59\d{4,6}|9\d{5,10}|(?:[2-46-8]\d|5[0-8])\d{4,7}", r"(?:[26]1|3[289]|4[1246-8]|7[1-3]|8[1-36])\d{5,7}|(?:2(?:2[4-68]|[4-68]\d|7[15]|9[1-5])|3(?:18|3[167]|4[2357]|51|[67]\d)|4(?:3[12]|5[13]|9[1-47])|5(?:[1-4]\d|5[02-4])|6(?:3[1-3]|44|7[1-8])|7(?:4[0-4]|5\d|6[1-578]|75|8[0-8])|858)\d{5,6}", r"9(?:51|6[129]|7[1-6]|8[1-7]|9[1-5])\d{6}"),
+            QA => p(974, "00", "00", None, &[7, 8, 9, 11], r"800\d{4}|(?:2|800)\d{6}|(?:0080|[3-7])\d{7}", r"4(?:(?:[014]\d\d|999)\d|2022)\d{3}", r"[35-7]\d{7}"),
+            RE => p(262, "00", "00", Some("0"), &[9], r"709\d{6}|(?:26|[689]\d)\d{7}", r"26(?:2\d\d|3(?:0\d|1[0-6]))\d{4}", r"(?:69(?:2\d\d|3(?:[06][0-6]|1[0-3]|2[0-2]|3[0-39]|4\d|5[0-5]|7[0-37]|8[0-8]|9[0-479]))|7092[0-3])\d{4}"),
+            RO => p(40, "00", "00", Some("0"), &[6, 9], r"(?:[236-8]\d|90)\d{7}|[23]\d{5}", r"[23][13-6]\d{7}|(?:2(?:19\d|[3-6]\d9)|31\d\d)\d\d", r"(?:630|702)0\d{5}|(?:6(?:00|2\d)|7(?:0[013-9]|1[0-3]|[2-7]\d|8[03-8]|9[0-39]))\d{6}"),
+            RS => p(381, "00", "00", Some("0"), &[6, 7, 8, 9, 10, 11, 12], r"38[02-9]\d{6,9}|6\d{7,9}|90\d{4,8}|38\d{5,6}|(?:7\d\d|800)\d{3,9}|(?:[12]\d|3[0-79])\d{5,10}", r"(?:11[1-9]\d|(?:2[389]|39)(?:0[2-9]|[2-9]\d))\d{3,8}|(?:1[02-9]|2[0-24-7]|3[0-8])[2-9]\d{4,9}", r"6(?:[0-689]|7\d)\d{6,7}"),
+            RU => p(7, "810", "810", Some("8"), &[10, 14], r"8\d{13}|[347-9]\d{9}", r"336(?:[013-9]\d|2[013-9])\d{5}|(?:3(?:0[12]|4[1-35-79]|5[1-3]|65|8[1-58]|9[0145])|4(?:01|1[1356]|2[13467]|7[1-5]|8[1-7]|9[1-689])|8(?:1[1-8]|2[01]|3[13-6]|4[0-8]|5[15-7]|6[0-35-79]|7[1-37-9]))\d{7}", r"9\d{9}"),
+            RW => p(250, "00", "00", Some("0"), &[8, 9], r"(?:06|[27]\d\d|[89]00)\d{6}", r"(?:06|2[23568]\d)\d{6}", r"7[237-9]\d{7}"),
+            SA => p(966, "00", "00", Some("0"), &[9, 10], r"(?:[15]\d|800|92)\d{7}", r"1(?:1\d|2[24-8]|3[35-8]|4[3-68]|6[2-5]|7[235-7])\d{6}", r"579[01]\d{5}|5(?:[013-689]\d|7[0-8])\d{6}"),
+            SB => p(677, "0[01]", "00", None, &[5, 7], r"[6-9]\d{6}|[1-6]\d{4}", r"(?:1[4-79]|[23]\d|4[0-2]|5[03]|6[0-37])\d{3}", r"48\d{3}|(?:(?:6[89]|7[1-9]|8[4-9])\d|9(?:1[2-9]|2[013-9]|3[0-2]|[46]\d|5[0-46-9]|7[0-689]|8[0-79]|9[0-8]))\d{4}"),
+            SC => p(248, "010|0[0-2]", "00", None, &[7], r"(?:[2489]\d|64)\d{5}", r"4[2-46]\d{5}", r"2[125-8]\d{5}"),
+            SD => p(249, "00", "00", Some("0"), &[9], r"[19]\d{8}", r"1(?:5\d|8[35-7])\d{6}", r"(?:1[0-2]|9[0-3569])\d{7}"),
+            SE => p(46, "00", "00", Some("0"), &[6, 7, 8, 9, 10, 12], r"(?:[26]\d\d|9)\d{9}|[1-9]\d{8}|[1-689]\d{7}|[1-4689]\d{6}|2\d{5}", r"(?:(?:[12][136]|3[356]|4[0246]|6[03]|8\d)\d|90[1-9])\d{4,6}|(?:1(?:2[0-35]|4[0-4]|5[0-25-9]|7[13-6]|[89]\d)|2(?:2[0-7]|4[0136-8]|5[0138]|7[018]|8[01]|9[0-57])|3(?:0[0-4]|1\d|2[0-25]|4[056]|7[0-2]|8[0-3]|9[023])|4(?:1[013-8]|3[0135]|5[14-79]|7[0-246-9]|8[0156]|9[0-689])|5(?:0[0-6]|[15][0-5]|2[0-68]|3[0-4]|4\d|6[03-5]|7[013]|8[0-79]|9[01])|6(?:1[1-3]|2[0-4]|4[02-57]|5[0-37]|6[0-3]|7[0-2]|8[0247]|9[0-356])|9(?:1[0-68]|2\d|3[02-5]|4[0-3]|5[0-4]|[68][01]|7[0135-8]))\d{5,6}", r"7[02369]\d{7}"),
+            SG => p(65, r"0[0-3]\d", "001", None, &[8, 10, 11], r"(?:(?:1\d|8)\d\d|7000)\d{7}|[3689]\d{7}", r"662[0-24-9]\d{4}|6(?:[0-578]\d|6[013-57-9]|9[0-35-9])\d{5}", r"89(?:8[02-9]|9[0-4])\d{4}|(?:8(?:0[1-9]|[1-8]\d|9[0-7])|9[0-8]\d)\d{5}"),
+            SH => p(290, "00", "00", None, &[4, 5], r"(?:[256]\d|8)\d{3}", r"2(?:[0-57-9]\d|6[4-9])\d\d", r"[56]\d{4}"),
+            SI => p(386, "00|10(?:22|66|88|99)", "00", Some("0"), &[5, 6, 7, 8], r"[1-7]\d{7}|8\d{4,7}|90\d{4,6}", r"(?:[1-357][2-8]|4[24-8])\d{6}", r"65(?:[178]\d|5[56]|6[01])\d{4}|(?:[37][01]|4[0139]|51|6[489])\d{6}"),
+            SJ => p(47, "00", "00", None, &[5, 8], r"0\d{4}|(?:[489]\d|79)\d{6}", r"79\d{6}", r"(?:4[015-8]|9\d)\d{6}"),
+            SK => p(421, "00", "00", Some("0"), &[6, 7, 9], r"[2-689]\d{8}|[2-59]\d{6}|[2-5]\d{5}", r"(?:2(?:16|[2-9]\d{3})|(?:(?:[3-5][1-8]\d|819)\d|601[1-5])\d)\d{4}|(?:2|[3-5][1-8])1[67]\d{3}|[3-5][1-8]16\d\d", r"909[1-9]\d{5}|9(?:0[1-8]|1[0-24-9]|4[03-57-9]|5\d)\d{6}"),
+            SL => p(232, "00", "00", Some("0"), &[8], r"(?:[237-9]\d|66)\d{6}", r"22[2-4][2-9]\d{4}", r"(?:25|3[0-5]|66|7[1-9]|8[08]|9[09])\d{6}"),
+            SM => p(378, "00", "00", None, &[8, 10], r"(?:0549|[5-7]\d)\d{6}", r"0549(?:8[0157-9]|9\d)\d{4}", r"6[16]\d{6}"),
+            SN => p(221, "00", "00", None, &[9], r"(?:[378]\d|93)\d{7}", r"3(?:0(?:1[0-2]|80)|282|3(?:8[1-9]|9[3-9])|611)\d{5}", r"7(?:[015-8]\d|21|90)\d{6}"),
+            SO => p(252, "00", "00", Some("0"), &[6, 7, 8, 9], r"[346-9]\d{8}|[12679]\d{7}|[1-5]\d{6}|[1348]\d{5}", r"(?:1\d|2[0-79]|3[0-46-8]|4[0-7]|5[57-9])\d{5}|(?:[134]\d|8[125])\d{4}", r"(?:(?:15|(?:3[59]|4[89]|6\d|7[679]|8[08])\d|9(?:0\d|[2-9]))\d|2(?:4\d|8))\d{5}|(?:[67]\d\d|904)\d{5}"),
+            SR => p(597, "00", "00", None, &[6, 7], r"(?:[2-5]|[6-8]\d|90)\d{5}", r"(?:2[1-3]|3[0-7]|4\d|5[2-58])\d{4}", r"(?:6[08]|7[124-7]|8[1-9])\d{5}"),
+            SS => p(211, "00", "00", Some("0"), &[9], r"[19]\d{8}", r"1[89]\d{7}", r"(?:12|9[1257-9])\d{7}"),
+            ST => p(239, "00", "00", None, &[7], r"(?:22|9\d)\d{5}", r"22\d{5}", r"900[5-9]\d{3}|9(?:0[1-9]|[89]\d)\d{4}"),
+            SV => p(503, "00", "00", None, &[7, 8, 11], r"[25-7]\d{7}|(?:80\d|900)\d{4}(?:\d{4})?", r"2(?:79(?:0[0347-9]|[1-9]\d)|89(?:0[024589]|[1-9]\d))\d{3}|2(?:[1-69]\d|[78][0-8])\d{5}", r"[5-7]\d{7}"),
+            SX => p(1, "011", "011", Some("1"), &[10], r"7215\d{6}|(?:[58]\d\d|900)\d{7}", r"7215(?:4[2-8]|8[239]|9[056])\d{4}", r"7215(?:1[02]|2\d|5[034679]|8[014-8])\d{4}"),
+            SY => p(963, "00", "00", Some("0"), &[8, 9], r"[1-359]\d{8}|[1-5]\d{7}", r"21\d{6,7}|(?:1(?:[14]\d|[2356])|2[235]|3(?:[13]\d|4)|4[134]|5[1-3])\d{6}", r"(?:50|9[1-9])\d{7}"),
+            SZ => p(268, "00", "00", None, &[8, 9], r"0800\d{4}|(?:[237]\d|900)\d{6}", r"[23][2-5]\d{6}", r"7[5-9]\d{6}"),
+            TC => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|649|900)\d{7}", r"649(?:266|712|9(?:4\d|50))\d{4}", r"649(?:2(?:3[129]|4[1-79])|3\d\d|4[34][1-3])\d{4}"),
+            TD => p(235, "00|16", "00", None, &[8], r"(?:22|30|[689]\d|77)\d{6}", r"22(?:[37-9]0|5[0-5]|6[89])\d{4}", r"(?:30|[69]\d|77|8[5-7])\d{6}"),
+            TF => return None,
+            TG => p(228, "00", "00", None, &[8], r"[279]\d{7}", r"2(?:2[2-7]|3[23]|4[45]|55|6[67]|77)\d{5}", r"(?:7[0-29]|9[0-36-9])\d{6}"),
+            TH => p(66, "00[1-9]", "001", Some("0"), &[8, 9, 10, 13], r"(?:001800|[2-57]|[689]\d)\d{7}|1\d{7,9}", r"(?:1[0689]|2\d|3[2-9]|4[2-5]|5[2-6]|7[3-7])\d{6}", r"67(?:1[0-8]|2[4-7])\d{5}|(?:14|6[1-6]|[89]\d)\d{7}"),
+            TJ => p(992, "810", "810", None, &[9], r"(?:[0-57-9]\d|66)\d{7}", r"(?:3(?:1[3-5]|2[245]|3[12]|4[24-7]|5[25]|72)|4(?:46|74|87))\d{6}", r"(?:33[03-9]|4(?:1[18]|4[02-479])|81[1-9])\d{6}|(?:[09]\d|1[0-27-9]|2[0-27]|3[08]|40|5[05]|66|7[01578]|8[078])\d{7}"),
+            TK => p(690, "00", "00", None, &[4, 5, 6, 7], r"[2-47]\d{3,6}", r"(?:2[2-4]|[34]\d)\d{2,5}", r"7[2-4]\d{2,5}"),
+            TL => p(670, "00", "00", None, &[7, 8], r"7\d{7}|(?:[2-47]\d|[89]0)\d{5}", r"(?:2[1-5]|3[1-9]|4[1-4])\d{5}", r"7[2-8]\d{6}"),
+            TM => p(993, "810", "810", Some("8"), &[8], r"(?:[1-6]\d|71)\d{6}", r"(?:1(?:2\d|3[1-9])|2(?:22|4[0-35-8])|3(?:22|4[03-9])|4(?:22|3[128]|4\d|6[15])|5(?:22|5[7-9]|6[014-689]))\d{5}", r"(?:6\d|71)\d{6}"),
+            TN => p(216, "00", "00", None, &[8], r"[2-57-9]\d{7}", r"81200\d{3}|(?:3[0-2]|7\d)\d{6}", r"3(?:001|[12]40)\d{4}|(?:(?:[259]\d|4[0-8])\d|3(?:1[1-35]|6[0-4]|91))\d{5}"),
+            TO => p(676, "00", "00", None, &[5, 7], r"(?:0800|(?:[5-8]\d\d|999)\d)\d{3}|[2-8]\d{4}", r"(?:2\d|3[0-8]|4[0-4]|50|6[09]|7[0-24-69]|8[05])\d{3}", r"(?:5(?:4[0-5]|5[4-6])|6(?:[09]\d|3[02]|8[15-9])|(?:7\d|8[46-9])\d|999)\d{4}"),
+            TR => p(90, "00", "00", Some("0"), &[7, 10, 12, 13], r"4\d{6}|8\d{11,12}|(?:[2-58]\d\d|900)\d{7}", r"(?:2(?:[13][26]|[28][2468]|[45][268]|[67][246])|3(?:[13][28]|[24-6][2468]|[78][02468]|92)|4(?:[16][246]|[23578][2468]|4[26]))\d{7}", r"561(?:011|61\d)\d{4}|5(?:0[15-7]|1[06]|24|[34]\d|5[1-59]|9[46])\d{7}"),
+            TT => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|900)\d{7}", r"868(?:2(?:01|1[5-9]|[23]\d|4[0-2])|6(?:0[7-9]|1[02-8]|2[1-9]|[3-69]\d|7[0-79])|82[124])\d{4}", r"868(?:(?:2[5-9]|3\d)\d|4(?:3[0-6]|[6-9]\d)|6(?:20|78|8\d)|7(?:0[1-9]|1[02-9]|[2-9]\d))\d{4}"),
+            TV => p(688, "00", "00", None, &[5, 6, 7], r"(?:2|7\d\d|90)\d{4}", r"2[02-9]\d{3}", r"(?:7[01]\d|90)\d{4}"),
+            TW => p(886, "0(?:0[25-79]|19)", "002", Some("0"), &[7, 8, 9, 10, 11], r"[2-689]\d{8}|7\d{9,10}|[2-8]\d{7}|2\d{6}", r"(?:2[2-8]\d|370|55[01]|7[1-9])\d{6}|4(?:(?:0(?:0[1-9]|[2-48]\d)|1[023]\d)\d{4,5}|(?:[239]\d\d|4(?:0[56]|12|49))\d{5})|6(?:[01]\d{7}|4(?:0[56]|12|24|4[09])\d{4,5})|8(?:(?:2(?:3\d|4[0-269]|[578]0|66)|36[24-9]|90\d\d)\d{4}|4(?:0[56]|12|24|4[09])\d{4,5})|(?:2(?:2(?:0\d\d|4(?:0[68]|[249]0|3[0-467]|5[0-25-9]|6[0235689]))|(?:3(?:[09]\d|1[0-4])|(?:4\d|5[0-49]|6[0-29]|7[0-5])\d)\d)|(?:(?:3[2-9]|5[2-8]|6[0-35-79]|8[7-9])\d\d|4(?:2(?:[089]\d|7[1-9])|(?:3[0-4]|[78]\d|9[01])\d))\d)\d{3}", r"(?:40001[0-2]|9[0-8]\d{4})\d{3}"),
+            TZ => p(255, "00[056]", "000", Some("0"), &[9], r"(?:[25-8]\d|41|90)\d{7}", r"2[2-8]\d{7}", r"(?:6[1-35-9]|7[13-9])\d{7}"),
+            UA => p(380, "00", "00", Some("0"), &[9, 10], r"[89]\d{9}|[3-9]\d{8}", r"(?:3[1-8]|4[13-8]|5[1-7]|6[12459])\d{7}", r"790\d{6}|(?:39|50|6[36-8]|7[1-357]|9[1-9])\d{7}"),
+            UG => p(256, "00[057]", "000", Some("0"), &[9], r"800\d{6}|(?:[29]0|[347]\d)\d{7}", r"20(?:(?:240|30[67])\d|6(?:00[0-2]|30[0-4]))\d{3}|(?:20(?:[017]\d|2[5-9]|3[1-4]|5[0-4]|6[15-9])|[34]\d{3})\d{5}", r"72[48]0\d{5}|7(?:[014-8]\d|2[0167]|3[06]|9[0-2589])\d{6}"),
+            UM => return None,
+            US => p(1, "011", "011", Some("1"), &[10], r"[2-9]\d{9}|3\d{6}", r"3052(?:0[0-8]|[1-9]\d)\d{4}|(?:2742|305[3-9])\d{6}|(?:472|983)[2-47-9]\d{6}|(?:2(?:0[1-35-9]|1[02-9]|2[03-57-9]|3[1459]|4[08]|5[1-46]|6[0279]|7[0269]|8[13])|3(?:0[1-47-9]|1[02-9]|2[013-79]|3[0-24679]|4[167]|5[0-3]|6[01349]|8[056])|4(?:0[124-9]|1[02-579]|2[3-5]|3[0245]|4[023578]|58|6[349]|7[0589]|8[04])|5(?:0[1-57-9]|1[0235-8]|20|3[0149]|4[01]|5[179]|6[1-47]|7[0-5]|8[0256])|6(?:0[1-35-9]|1[024-9]|2[03689]|3[016]|4[0156]|5[01679]|6[0-279]|78|8[0-269])|7(?:0[1-46-8]|1[2-9]|2[04-8]|3[0-247]|4[0378]|5[47]|6[02359]|7[0-59]|8[156])|8(?:0[1-68]|1[02-8]|2[0168]|3[0-2589]|4[03578]|5[046-9]|6[02-5]|7[028])|9(?:0[1346-9]|1[02-9]|2[0589]|3[0146-8]|4[01357-9]|5[12469]|7[0-3589]|8[04-69]))[2-9]\d{6}", r"3052(?:0[0-8]|[1-9]\d)\d{4}|(?:2742|305[3-9])\d{6}|(?:472|983)[2-47-9]\d{6}|(?:2(?:0[1-35-9]|1[02-9]|2[03-57-9]|3[1459]|4[08]|5[1-46]|6[0279]|7[0269]|8[13])|3(?:0[1-47-9]|1[02-9]|2[013-79]|3[0-24679]|4[167]|5[0-3]|6[01349]|8[056])|4(?:0[124-9]|1[02-579]|2[3-5]|3[0245]|4[023578]|58|6[349]|7[0589]|8[04])|5(?:0[1-57-9]|1[0235-8]|20|3[0149]|4[01]|5[179]|6[1-47]|7[0-5]|8[0256])|6(?:0[1-35-9]|1[024-9]|2[03689]|3[016]|4[0156]|5[01679]|6[0-279]|78|8[0-269])|7(?:0[1-46-8]|1[2-9]|2[04-8]|3[0-247]|4[0378]|5[47]|6[02359]|7[0-59]|8[156])|8(?:0[1-68]|1[02-8]|2[0168]|3[0-2589]|4[03578]|5[046-9]|6[02-5]|7[028])|9(?:0[1346-9]|1[02-9]|2[0589]|3[0146-8]|4[01357-9]|5[12469]|7[0-3589]|8[04-69]))[2-9]\d{6}"),
+            UY => p(598, r"0(?:0|1[3-9]\d)", "00", Some("0"), &[4, 5, 6, 7, 8, 9, 10, 11, 12, 13], r"0004\d{2,9}|[1249]\d{7}|2\d{3,4}|(?:[49]\d|80)\d{5}", r"(?:1(?:770|9(?:20|[89]7))|(?:2\d|4[2-7])\d\d)\d{4}", r"9[1-9]\d{6}"),
+            UZ => p(998, "00", "00", None, &[9], r"(?:20|33|[5-9]\d)\d{7}", r"(?:55\d\d|6(?:1(?:22|3[124]|4[1-4]|5[1-3578]|64)|2(?:22|3[0-57-9]|41)|5(?:22|3[3-7]|5[024-8])|[69]\d\d|7(?:[23]\d|7[69]))|7(?:0(?:5[4-9]|6[0146]|7[124-6]|9[135-8])|[168]\d\d|2(?:22|3[13-57-9]|4[1-3579]|5[14])|3(?:2\d|3[1578]|4[1-35-7]|5[1-57]|61)|4(?:2\d|3[1-579]|7[1-79])|5(?:22|5[1-9]|6[1457])|9(?:22|5[1-9])))\d{5}", r"(?:(?:[25]0|33|8[078]|9[0-57-9])\d{3}|6(?:1(?:2(?:2[01]|98)|35[0-4]|50\d|61[23]|7(?:[01][017]|4\d|55|9[5-9]))|2(?:(?:11|7\d)\d|2(?:[12]1|9[01379])|5(?:[126]\d|3[0-4]))|5(?:19[01]|2(?:27|9[26])|(?:30|59|7\d)\d)|6(?:2(?:1[5-9]|2[0367]|38|41|52|60)|(?:3[79]|9[0-3])\d|4(?:56|83)|7(?:[07]\d|1[017]|3[07]|4[047]|5[057]|67|8[0178]|9[79]))|7(?:2(?:24|3[237]|4[5-9]|7[15-8])|5(?:7[12]|8[0589])|7(?:0\d|[39][07])|9(?:0\d|7[079])))|7(?:[07]\d{3}|2(?:2(?:2[79]|95)|3(?:2[5-9]|6[0-6])|57\d|7(?:0\d|1[17]|2[27]|3[37]|44|5[057]|66|88))|3(?:2(?:1[0-6]|21|3[469]|7[159])|(?:33|9[4-6])\d|5(?:0[0-4]|5[579]|9\d)|7(?:[0-3579]\d|4[0467]|6[67]|8[078]))|4(?:2(?:29|5[0257]|6[0-7]|7[1-57])|5(?:1[0-4]|8\d|9[5-9])|7(?:0\d|1[024589]|2[0-27]|3[0137]|[46][07]|5[01]|7[5-9]|9[079])|9(?:7[015-9]|[89]\d))|5(?:112|2(?:0\d|2[29]|[49]4)|3[1568]\d|52[6-9]|7(?:0[01578]|1[017]|[23]7|4[047]|[5-7]\d|8[78]|9[079]))|9(?:22[128]|3(?:2[0-4]|7\d)|57[02569]|7(?:2[05-9]|3[37]|4\d|60|7[2579]|87|9[07]))))\d{4}"),
+            VA => p(39, "00", "00", None, &[6, 7, 8, 9, 10, 11, 12], r"0\d{5,10}|3[0-8]\d{7,10}|55\d{8}|8\d{5}(?:\d{2,4})?|(?:1\d|39)\d{7,8}", r"06698\d{1,6}", r"3[1-9]\d{8}|3[2-9]\d{7}"),
+            VC => p(1, "011", "011", Some("1"), &[10], r"(?:[58]\d\d|784|900)\d{7}", r"784(?:266|3(?:6[6-9]|7\d|8[0-6])|4(?:38|5[0-36-8]|8[0-8])|5(?:55|7[0-2]|93)|638|784)\d{4}", r"784(?:4(?:3[0-5]|5[45]|89|9[0-8])|5(?:2[6-9]|3[0-4])|720)\d{4}"),
+            VE => p(58, "00", "00", Some("0"), &[10], r"[68]00\d{7}|(?:[24]\d|[59]0)\d{8}", r"(?:2(?:12|3[457-9]|[467]\d|[58][1-9]|9[1-6])|[4-6]00)\d{7}", r"4(?:1[24-8]|2[246])\d{7}"),
+            VG => p(1, "011", "011", Some("1"), &[10], r"(?:284|[58]\d\d|900)\d{7}", r"284(?:229|4(?:22|9[45])|774|8(?:52|6[459]))\d{4}", r"284(?:245|3(?:0[0-3]|4[0-7]|68|9[34])|4(?:4[0-6]|68|9[69])|5(?:4[0-7]|68|9[69]))\d{4}"),
+            VI => p(1, "011", "011", Some("1"), &[10], r"[58]\d{9}|(?:34|90)0\d{7}", r"340(?:2(?:0\d|10|2[06-8]|4[49]|77)|3(?:32|44)|4(?:2[23]|44|7[34]|89)|5(?:1[34]|55)|6(?:2[56]|4[23]|77|9[023])|7(?:1[2-57-9]|2[57]|7\d)|884|998)\d{4}", r"340(?:2(?:0\d|10|2[06-8]|4[49]|77)|3(?:32|44)|4(?:2[23]|44|7[34]|89)|5(?:1[34]|55)|6(?:2[56]|4[23]|77|9[023])|7(?:1[2-57-9]|2[57]|7\d)|884|998)\d{4}"),
+            VN => p(84, "00", "00", Some("0"), &[7, 8, 9, 10], r"[12]\d{9}|[135-9]\d{8}|[16]\d{7}|[16-8]\d{6}", r"2(?:0[3-9]|1[0-689]|2[0-25-9]|[38][2-9]|4[2-8]|5[124-9]|6[0-39]|7[0-7]|9[0-4679])\d{7}", r"(?:5(?:2[238]|59)|89[6-9]|99[013-9])\d{6}|(?:3\d|5[1689]|7[06-9]|8[1-8]|9[0-8])\d{7}"),
+            VU => p(678, "00", "00", None, &[5, 7], r"[57-9]\d{6}|(?:[238]\d|48)\d{3}", r"(?:38[0-8]|48[4-9])\d\d|(?:2[02-9]|3[4-7]|88)\d{3}", r"(?:[58]\d|7[013-7])\d{5}"),
+            WF => p(681, "00", "00", None, &[6, 9], r"(?:40|72|8\d{4})\d{4}|[89]\d{5}", r"72\d{4}", r"(?:72|8[23])\d{4}"),
+            WS => p(685, "0", "0", None, &[5, 6, 7, 10], r"(?:[2-6]|8\d{5})\d{4}|[78]\d{6}|[68]\d{5}", r"6[1-9]\d{3}|(?:[2-5]|60)\d{4}", r"(?:7[1-35-8]|8(?:[3-7]|9\d{3}))\d{5}"),
+            YE => p(967, "00", "00", Some("0"), &[7, 8, 9], r"(?:1|7\d)\d{7}|[1-7]\d{6}", r"78[0-7]\d{4}|17\d{6}|(?:[12][2-68]|3[2358]|4[2-58]|5[2-6]|6[3-58]|7[24-6])\d{5}", r"7[01378]\d{7}"),
+            YT => p(262, "00", "00", Some("0"), &[9], r"7093\d{5}|(?:80|9\d)\d{7}|(?:26|63)9\d{6}", r"269(?:0[0-467]|15|5[0-4]|6\d|[78]0)\d{4}", r"(?:639(?:0[0-79]|1[019]|[267]\d|3[09]|40|5[05-9]|9[04-79])|7093[5-7])\d{4}"),
+            ZA => p(27, "00", "00", Some("0"), &[5, 6, 7, 8, 9, 10], r"[1-79]\d{8}|8\d{4,9}", r"(?:2(?:0330|4302)|52087)0\d{3}|(?:1[0-8]|2[1-378]|3[1-69]|4\d|5[1346-8])\d{7}", r"(?:1(?:3492[0-25]|4495[0235]|549(?:20|5[01]))|4[34]492[01])\d{3}|8[1-4]\d{3,7}|(?:2[27]|47|54)4950\d{3}|(?:1(?:049[2-4]|9[12]\d\d)|(?:50[0-2]|[67]\d\d)\d\d|8(?:5\d{3}|7(?:08[67]|158|28[5-9]|310)))\d{4}|(?:1[6-8]|28|3[2-69]|4[025689]|5[36-8])4920\d{3}|(?:12|[2-5]1)492\d{4}"),
+            ZM => p(260, "00", "00", Some("0"), &[9], r"800\d{6}|(?:21|[579]\d|63)\d{7}", r"21[1-8]\d{6}", r"(?:[59][5-8]|7[5-9])\d{7}"),
+            ZW => p(263, "00", "00", Some("0"), &[5, 6, 7, 8, 9, 10], r"2(?:[0-57-9]\d{6,8}|6[0-24-9]\d{6,7})|[38]\d{9}|[35-8]\d{8}|[3-6]\d{7}|[1-689]\d{6}|[1-3569]\d{5}|[1356]\d{4}", r"(?:1(?:(?:3\d|9)\d|[4-8])|2(?:(?:(?:0(?:2[014]|5)|(?:2[0157]|31|84|9)\d\d|[56](?:[14]\d\d|20)|7(?:[089]|2[03]|[35]\d\d))\d|4(?:2\d\d|8))\d|1(?:2|[39]\d{4}))|3(?:(?:123|(?:29\d|92)\d)\d\d|7(?:[19]|[56]\d))|5(?:0|1[2-478]|26|[37]2|4(?:2\d{3}|83)|5(?:25\d\d|[78])|[689]\d)|6(?:(?:[16-8]21|28|52[013])\d\d|[39])|8(?:[1349]28|523)\d\d)\d{3}|(?:4\d\d|9[2-9])\d{4,5}|(?:(?:2(?:(?:(?:0|8[146])\d|7[1-7])\d|2(?:[278]\d|92)|58(?:2\d|3))|3(?:[26]|9\d{3})|5(?:4\d|5)\d\d)\d|6(?:(?:(?:[0-246]|[78]\d)\d|37)\d|5[2-8]))\d\d|(?:2(?:[569]\d|8[2-57-9])|3(?:[013-59]\d|8[37])|6[89]8)\d{3}", r"7(?:[1278]\d|3[1-9])\d{6}"),
+        })
+    }
+}
+
+// Countries sharing a calling code without formats of their own use the
+// formats of the main country for the code.
+fn number_formats(country: Country) -> &'static [NumberFormat] {
+    use Country::*;
+    match country {
+        AD => &[
+            NumberFormat(r"(\d{3})(\d{3})", "[135-9]", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "1", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "6", "$1 $2 $3", None, None),
+        ],
+        AE => &[
+            NumberFormat(r"(\d{3})(\d{2,9})", "60|8", "$1 $2", None, None),
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "[236]|[479][2-8]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d)(\d{5})", "[479]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "5", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        AF => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[1-9]", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[2-7]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        AL => &[
+            NumberFormat(r"(\d{3})(\d{3,4})", "80|9", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "4[2-6]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[2358][2-5]|4", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{5})", "[23578]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "6", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        AM => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})", "[89]0", "$1 $2 $3", None, Some("0 $FG")),
+            NumberFormat(r"(\d{3})(\d{5})", "2|3[12]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{6})", "1|47", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{6})", "[3-9]", "$1 $2", None, Some("0$FG")),
+        ],
+        AO => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[29]", "$1 $2 $3", None, None),
+        ],
+        AR => &[
+            NumberFormat(r"(\d{3})", "0|1(?:0[0-35-7]|1[02-5]|2[015]|3[47]|4[478])|911", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{4})", "[1-9]", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{4})", "[2-9]", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{4})", "[1-8]", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{2})(\d{4})", "2(?:[23]02|6(?:[25]|4(?:64|[78]))|9(?:[02356]|4(?:[0268]|5[2-6])|72|8[23]))|3(?:3[28]|4(?:[04679]|3(?:5(?:4[0-25689]|[56])|[78])|58|8[2379])|5(?:[2467]|3[237]|8(?:[23]|4(?:[45]|60)|5(?:4[0-39]|5|64)))|7[1-578]|8(?:[2469]|3[278]|54(?:4|5[13-7]|6[89])|86[3-6]))|2(?:2[24-9]|3[1-59]|47)|38(?:[58][78]|7[378])|3(?:454|85[56])[46]|3(?:4(?:36|5[56])|8(?:[38]5|76))[4-6]", "$1 $2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "1", "$1 $2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[68]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[23]", "$1 $2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{4})(\d{2})(\d{4})", "9(?:2(?:[23]02|6(?:[25]|4(?:64|[78]))|9(?:[02356]|4(?:[0268]|5[2-6])|72|8[23]))|3(?:3[28]|4(?:[04679]|3(?:5(?:4[0-25689]|[56])|[78])|5(?:4[46]|8)|8[2379])|5(?:[2467]|3[237]|8(?:[23]|4(?:[45]|60)|5(?:4[0-39]|5|64)))|7[1-578]|8(?:[2469]|3[278]|5(?:4(?:4|5[13-7]|6[89])|[56][46]|[78])|7[378]|8(?:6[3-6]|[78]))))|92(?:2[24-9]|3[1-59]|47)|93(?:4(?:36|5[56])|8(?:[38]5|76))[4-6]", "$2 15-$3-$4", Some("$1 $2 $3-$4"), Some("0$FG")),
+            NumberFormat(r"(\d)(\d{2})(\d{4})(\d{4})", "91", "$2 15-$3-$4", Some("$1 $2 $3-$4"), Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{5})", "8", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{3})(\d{4})", "9", "$2 15-$3-$4", Some("$1 $2 $3-$4"), Some("0$FG")),
+        ],
+        AT => &[
+            NumberFormat(r"(\d{4})", "14", "$1", Some("NA"), None),
+            NumberFormat(r"(\d)(\d{3,12})", "1(?:11|[2-9])", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})", "517", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3,5})", "5[079]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{6})", "[18]", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3,10})", "(?:31|4)6|51|6(?:485|5[0-3579]|[6-9])|7(?:20|32|8)|[89]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3,9})", "[2-467]|5[2-6]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "5", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4,7})", "5", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        AU | CC | CX => &[
+            NumberFormat(r"(\d{2})(\d{3,4})", "16", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})", "13", "$1 $2 $3", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})", "19", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{4})", "1802", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{3,4})", "19", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2,4})", "16", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "14|4", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "[2378]", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", "1(?:30|[89])", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{4})(\d{4})", "130", "$1 $2 $3", Some("NA"), None),
+        ],
+        AW => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[25-9]", "$1 $2", None, None),
+        ],
+        AZ => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})", "[1-9]", "$1 $2 $3", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "90", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "1[28]|2|365(?:4|5[02])|46", "$1 $2 $3 $4", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "[13-9]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        BA => &[
+            NumberFormat(r"(\d{3})(\d{3})", "[2-9]", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "6[1-3]|[7-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[3-5]|6[56]", "$1 $2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{3})", "6", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        BD => &[
+            NumberFormat(r"(\d{2})(\d{4,6})", "31[5-8]|[459]1", "$1-$2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3,7})", "3(?:[67]|8[013-9])|4(?:6[168]|7|[89][18])|5(?:6[128]|9)|6(?:[15]|28|4[14])|7[2-589]|8(?:0[014-9]|[12])|9[358]|(?:3[2-5]|4[235]|5[2-578]|6[0389]|76|8[3-7]|9[24])1|(?:44|66)[01346-9]", "$1-$2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3,6})", "[13-9]|2[23]", "$1-$2", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{7,8})", "2", "$1-$2", None, Some("0$FG")),
+        ],
+        BE => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})", "(?:80|9)0", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{2})(\d{2})", "[239]|4[23]", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[15-8]", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "4", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        BF => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[024-7]", "$1 $2 $3 $4", None, None),
+        ],
+        BG => &[
+            NumberFormat(r"(\d{6})", "1", "$1", Some("NA"), None),
+            NumberFormat(r"(\d)(\d)(\d{2})(\d{2})", "2", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})", "43[1-6]|70[1-9]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{3,4})", "2", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2,3})", "[356]|4[124-7]|7[1-9]|8[1-6]|9[1-7]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})", "(?:70|8)0", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{2})", "43[1-7]|7", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[48]|9[08]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "9", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        BH => &[
+            NumberFormat(r"(\d{4})(\d{4})", "[13679]|8[02-4679]", "$1 $2", None, None),
+        ],
+        BI => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[2367]", "$1 $2 $3 $4", None, None),
+        ],
+        BJ => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "8", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})(\d{2})", "0", "$1 $2 $3 $4 $5", None, None),
+        ],
+        BN => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-578]", "$1 $2", None, None),
+        ],
+        BO => &[
+            NumberFormat(r"(\d)(\d{7})", "[235]|4[46]", "$1 $2", None, None),
+            NumberFormat(r"(\d{8})", "[67]", "$1", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{4})", "8", "$1 $2 $3", None, None),
+        ],
+        BR => &[
+            NumberFormat(r"(\d{3,6})", "1(?:1[25-8]|2[357-9]|3[02-68]|4[12568]|5|6[0-8]|8[015]|9[0-47-9])|321|610", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{4})", "300|4(?:0(?:0|20)|370|864)", "$1-$2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[2357]|4(?:[0-24-9]|3(?:[0-689]|7[1-9]))", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{2,3})(\d{4})", "(?:[358]|90)0", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{4})", "9", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "(?:[14689][1-9]|2[12478]|3[1-578]|5[13-5]|7[13-579])[2-57]", "$1 $2-$3", None, Some("($FG)")),
+            NumberFormat(r"(\d{2})(\d{5})(\d{4})", "[16][1-9]|[2-57-9]", "$1 $2-$3", None, Some("($FG)")),
+        ],
+        BT => &[
+            NumberFormat(r"(\d{3})(\d{3})", "[2-7]", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d)(\d{3})(\d{3})", "[2-6]|7[246]|8[2-4]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "1[67]|[78]", "$1 $2 $3 $4", None, None),
+        ],
+        BW => &[
+            NumberFormat(r"(\d{2})(\d{5})", "90", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{4})", "[24-6]|3[15-9]", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[37]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", "0", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{4})(\d{3})", "8", "$1 $2 $3", None, None),
+        ],
+        BY => &[
+            NumberFormat(r"(\d{3})(\d{3})", "800", "$1 $2", None, Some("8 $FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2,4})", "800", "$1 $2 $3", None, Some("8 $FG")),
+            NumberFormat(r"(\d{4})(\d{2})(\d{3})", "1(?:5[169]|6(?:3[1-3]|4|5[125])|7(?:1[3-9]|7[0-24-6]|9[2-7]))|2(?:1[35]|2[34]|3[3-5])", "$1 $2-$3", None, Some("8 0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "1(?:[56]|7[467])|2[1-3]", "$1 $2-$3-$4", None, Some("8 0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "[1-4]", "$1 $2-$3-$4", None, Some("8 0$FG")),
+            NumberFormat(r"(\d{3})(\d{3,4})(\d{4})", "[89]", "$1 $2 $3", None, Some("8 $FG")),
+        ],
+        BZ => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-8]", "$1-$2", None, None),
+            NumberFormat(r"(\d)(\d{3})(\d{4})(\d{3})", "0", "$1-$2-$3-$4", None, None),
+        ],
+        CD => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{3})", "88", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{5})", "[1-6]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{4})", "2", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "1", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[89]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{3})(\d{3})", "5", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        CF => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[278]", "$1 $2 $3 $4", None, None),
+        ],
+        CG => &[
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "8", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[02]", "$1 $2 $3", None, None),
+        ],
+        CH => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "8[047]|90", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "[2-79]|81", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})(\d{2})(\d{2})", "8", "$1 $2 $3 $4 $5", None, Some("0$FG")),
+        ],
+        CI => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d)(\d{5})", "2", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{4})", "0", "$1 $2 $3 $4", None, None),
+        ],
+        CK => &[
+            NumberFormat(r"(\d{2})(\d{3})", "[2-578]", "$1 $2", None, None),
+        ],
+        CL => &[
+            NumberFormat(r"(\d{4})", "1(?:[03-589]|21)|[29]0|78", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{5})(\d{4})", "2196", "$1 $2", None, Some("($FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "60|809", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "44", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "2[1-36]", "$1 $2 $3", None, Some("($FG)")),
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "9(?:10|[2-9])", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "3[2-5]|[47]|5[1-3578]|6[13-57]|8(?:0[1-8]|[1-9])", "$1 $2 $3", None, Some("($FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "60|8", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{4})", "1", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{2})(\d{3})", "60", "$1 $2 $3 $4", None, None),
+        ],
+        CM => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "88", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d)(\d{2})(\d{2})(\d{2})(\d{2})", "[26]|88", "$1 $2 $3 $4 $5", None, None),
+        ],
+        CN => &[
+            NumberFormat(r"(\d{5,6})", "1(?:00|2(?:1|395))|9[56]", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{5,6})", "1(?:0|23(?:[0-8]|9[0-46-9]))|78123|[1-9]123", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{5,6})", r"10(?:1(?:0|23)|9[56])|2[0-57-9](?:1(?:00|23)|9[56])|(?:3(?:[157]\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\d|8[23])|5(?:[1357]\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:078|1[236-8]|2[5-7]|[37]\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))123", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})", r"1(?:0(?:[02-8]|1(?:[013-9]|2[0-24-9])|9[0-47-9])|[1-9])|2(?:[0-57-9](?:[02-8]|1(?:0[1-9]|[13-9]|2[0-24-9])|9[0-47-9])|6)|3(?:[0268]|3[0-46-9]|4[0-8]|9[079])|4(?:[049]|1[03]|2[02-68]|[35]0|6[0-356]|8[014-9])|5(?:0|2[0-24-689]|4[0-2457-9]|6[057-9]|8[1-9]|90)|6(?:[0-24578]|3[06-9]|6[14-79]|9[03-9])|7(?:0[02-9]|2[0135-79]|3[23]|4[0-27-9]|6[1457]|8)|8(?:0(?:[0-689]|7[0-79])|1[01459]|2[0-489]|[46]|50|8[0-2459]|9[09])|9(?:0[0457]|1[08]|[268]|4[024-9]|5[06-9]|78|94)|(?:3(?:[157]\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\d|8[23])|5(?:[1357]\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:078|1[236-8]|2[5-7]|[37]\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))(?:[02-9]|1(?:[013-9]|2[0-24-9]))", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{4})", "16[08]", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{5,6})", r"85[23](?:100|95)|(?:3(?:[157]\d|35|49|9[1-68])|4(?:[17]\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\d|4[13]|5[1-5]))(?:100|9[56])", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{4})", r"1(?:0[02-8]|[1-9])|2(?:[0-57-9][0-8]|6)|3(?:[0268]|3[0-46-9]|4[0-8]|9[079])|4(?:[049]|2[02-68]|[35]0|6[0-356]|8[014-9])|5(?:0|2[0-24-689]|4[0-2457-9]|6[057-9]|90)|6(?:[0-24578]|3[06-9]|6[14-79]|9[03-9])|7(?:0[02-9]|2[0135-79]|3[23]|4[0-27-9]|6[1457]|8)|8(?:[046]|1[01459]|2[0-489]|5(?:0|[23](?:[02-8]|1[1-9]|9[0-46-9]))|8[0-2459]|9[09])|9(?:0[0457]|1[08]|[268]|4[024-9]|5[06-9])|(?:10|2[0-57-9])9[0-47-9]|(?:101|58|85[23]10)[1-9]|(?:3(?:[157]\d|35|49|9[1-68])|4(?:[17]\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\d|4[13]|5[1-5]))(?:[02-8]|1(?:0[1-9]|[1-9])|9[0-47-9])", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "(?:4|80)0", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "10[0-79]|2(?:[02-57-9]|1[1-79])|(?:10|21)8(?:0[1-9]|[1-9])", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "3(?:[3-59]|7[02-68])|4(?:[26-8]|3[3-9]|5[2-9])|5(?:3[03-9]|[468]|7[028]|9[2-46-9])|6|7(?:[0-247]|3[04-9]|5[0-4689]|6[2368])|8(?:[1-358]|9[1-7])|9(?:[013479]|5[1-5])|(?:[34]1|55|79|87)[02-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{7,8})", "9", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{4})", "80", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})(\d{4})", "[3-578]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})(\d{4})", "1[3-9]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})(\d{4})", "[12]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        CO => &[
+            NumberFormat(r"(\d{4})(\d{4})", "46", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{7})", "6|90", "$1 $2", None, Some("($FG)")),
+            NumberFormat(r"(\d{3})(\d{7})", "3[0-357]|9[14]", "$1 $2", None, None),
+            NumberFormat(r"(\d)(\d{3})(\d{7})", "1", "$1-$2-$3", Some("$1 $2 $3"), Some("0$FG")),
+        ],
+        CR => &[
+            NumberFormat(r"(\d{4})(\d{4})", "[2-7]|8[3-9]", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[89]", "$1-$2-$3", None, None),
+        ],
+        CU => &[
+            NumberFormat(r"(\d{2})(\d{4,6})", "2[1-4]|[34]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d)(\d{6,7})", "7", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d)(\d{7})", "[56]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{7})", "8", "$1 $2", None, Some("0$FG")),
+        ],
+        CV => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})", "[2-589]", "$1 $2 $3", None, None),
+        ],
+        BQ | CW => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[3467]", "$1 $2", None, None),
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "9[4-8]", "$1 $2 $3", None, None),
+        ],
+        CY => &[
+            NumberFormat(r"(\d{2})(\d{6})", "[257-9]", "$1 $2", None, None),
+        ],
+        CZ => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[2-8]|9[015-7]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})(\d{2})", "96", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})(\d{3})", "9", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})(\d{3})", "9", "$1 $2 $3 $4", None, None),
+        ],
+        DE => &[
+            NumberFormat(r"(\d{2})(\d{3,13})", "3[02]|40|[68]9", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{6})", "2277", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3,12})", "2(?:0[1-389]|12[0-8])|3(?:[35-9][15]|4[015])|906|2(?:[13][14]|2[18])|(?:2[4-9]|4[2-9]|[579][1-9]|[68][1-8])1", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{2,11})", "[24-6]|3(?:3(?:0[1-467]|2[127-9]|3[124578]|7[1257-9]|8[1256]|9[145])|4(?:2[135]|4[13578]|9[1346])|5(?:0[14]|2[1-3589]|6[1-4]|7[13468]|8[13568])|6(?:2[1-489]|3[124-6]|6[13]|7[12579]|8[1-356]|9[135])|7(?:2[1-7]|4[145]|6[1-5]|7[1-4])|8(?:21|3[1468]|6|7[1467]|8[136])|9(?:0[12479]|2[1358]|4[134679]|6[1-9]|7[136]|8[147]|9[1468]))|70[2-8]|8(?:0[2-9]|[1-8])|90[7-9]|[79][1-9]|3[68]4[1347]|3(?:47|60)[1356]|3(?:3[46]|46|5[49])[1246]|3[4579]3[1357]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})", "138", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{2,10})", "3", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{5,11})", "181", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d)(\d{4,10})", "1(?:3|80)|9", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{7,8})", "1[67]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{7,12})", "8", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{6})", "18500", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})(\d{4})", "7", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{7})", "18[68]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{7})", "15[1279]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{6})", "15(?:[0568]|3[13])", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{8})", "18", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{7,8})", "1(?:6[023]|7)", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{2})(\d{7})", "15[279]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{8})", "15", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        DJ => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[27]", "$1 $2 $3 $4", None, None),
+        ],
+        DK => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[2-9]", "$1 $2 $3 $4", None, None),
+        ],
+        DZ => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[1-4]", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "9", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[5-8]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        EC => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-7]", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "[2-7]", "$1 $2-$3", Some("$1-$2-$3"), Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "9", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3,4})", "1", "$1 $2 $3", None, None),
+        ],
+        EE => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[369]|4[3-8]|5(?:[02]|1(?:[0-8]|95)|5[0-478]|6(?:4[0-4]|5[1-589]))|7[1-9]|88", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{3,4})", "[45]|8(?:00[1-9]|[1-49])", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{4})", "7", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", "8", "$1 $2 $3", None, None),
+        ],
+        EG => &[
+            NumberFormat(r"(\d)(\d{7,8})", "[23]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{6,7})", "1[35]|[4-6]|8[2468]|9[235-7]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[89]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{8})", "1", "$1 $2", None, Some("0$FG")),
+        ],
+        ER => &[
+            NumberFormat(r"(\d)(\d{3})(\d{3})", "[178]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        ES => &[
+            NumberFormat(r"(\d{4})", "905", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{6})", "[79]9", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[89]00", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[5-9]", "$1 $2 $3 $4", None, None),
+        ],
+        ET => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[1-579]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        AX | FI => &[
+            NumberFormat(r"(\d{5})", "75[12]", "$1", Some("NA"), Some("0$FG")),
+            NumberFormat(r"(\d{5})", "20[2-59]", "$1", None, Some("0$FG")),
+            NumberFormat(r"(\d{6})", "11", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3,7})", "(?:[1-3]0|[68])0|70[07-9]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4,8})", "[14]|2[09]|50|7[135]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{6,10})", "7", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{4,9})", "(?:19|[2568])[1-8]|3(?:0[1-9]|[1-9])|9", "$1 $2", None, Some("0$FG")),
+        ],
+        FJ => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[235-9]|45", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{4})", "0", "$1 $2 $3", None, None),
+        ],
+        FM => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[389]", "$1 $2", None, None),
+        ],
+        FO => &[
+            NumberFormat(r"(\d{6})", "[2-9]", "$1", None, None),
+        ],
+        FR => &[
+            NumberFormat(r"(\d{4})", "10", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})", "1", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "8", "$1 $2 $3 $4", None, Some("0 $FG")),
+            NumberFormat(r"(\d)(\d{2})(\d{2})(\d{2})(\d{2})", "[1-79]", "$1 $2 $3 $4 $5", None, Some("0$FG")),
+        ],
+        GA => &[
+            NumberFormat(r"(\d)(\d{2})(\d{2})(\d{2})", "[2-7]", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "0", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "11|[67]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        GB | GG | IM | JE => &[
+            NumberFormat(r"(\d{3})(\d{4})", "8001111", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})", "845464", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{6})", "800", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{4,5})", "1(?:3873|5(?:242|39[4-6])|(?:697|768)[347]|9467)", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{5,6})", "1(?:[2-69][02-9]|[78])", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "[25]|7(?:0|6(?:[03-9]|2[356]))", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{6})", "7", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[1389]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        GE => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "70", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "32", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[57]", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[348]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        GF => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[5-7]|9[47]", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[89]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        GH => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[237]|8[0-2]", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{5})", "8", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[235]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        GI => &[
+            NumberFormat(r"(\d{3})(\d{5})", "2", "$1 $2", None, None),
+        ],
+        GL => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})", "19|[2-9]", "$1 $2 $3", None, None),
+        ],
+        GM => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-9]", "$1 $2", None, None),
+        ],
+        GN => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "3", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[67]", "$1 $2 $3 $4", None, None),
+        ],
+        BL | GP | MF => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[5-79]", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "8", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        GQ => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[235]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{6})", "[89]", "$1 $2", None, None),
+        ],
+        GR => &[
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "21|7", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{6})", "2(?:2|3[2-57-9]|4[2-469]|5[2-59]|6[2-9]|7[2-69]|8[2-49])|5", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[2689]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3,4})(\d{5})", "8", "$1 $2 $3", None, None),
+        ],
+        GT => &[
+            NumberFormat(r"(\d{4})(\d{4})", "[2-8]", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{4})", "1", "$1 $2 $3", None, None),
+        ],
+        GW => &[
+            NumberFormat(r"(\d{3})(\d{4})", "40", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[49]", "$1 $2 $3", None, None),
+        ],
+        GY => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-9]", "$1 $2", None, None),
+        ],
+        HK => &[
+            NumberFormat(r"(\d{3})(\d{2,5})", "9003", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[2-7]|8[1-4]|9(?:0[1-9]|[1-8])", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "8", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})(\d{3})", "9", "$1 $2 $3 $4", None, None),
+        ],
+        HN => &[
+            NumberFormat(r"(\d{4})(\d{4})", "[237-9]", "$1-$2", None, None),
+            NumberFormat(r"(\d{3})(\d{4})(\d{4})", "8", "$1 $2 $3", Some("NA"), None),
+        ],
+        HR => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{3})", "6[01]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2,3})", "8", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{4})(\d{3})", "1", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "6|7[245]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "9", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[2-57]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "8", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        HT => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{4})", "[2-589]", "$1 $2 $3", None, None),
+        ],
+        HU => &[
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "1", "$1 $2 $3", None, Some("(06 $FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[27][2-9]|3[2-7]|4[24-9]|5[2-79]|6|8[2-57-9]|9[2-69]", "$1 $2 $3", None, Some("(06 $FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[2-9]", "$1 $2 $3", None, Some("06 $FG")),
+        ],
+        ID => &[
+            NumberFormat(r"(\d)(\d{3})(\d{3})", "15", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{5,9})", "2[124]|[36]1", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{5,7})", "800", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{5,8})", "[2-79]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{3,4})(\d{3})", "8[1-35-9]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{6,8})", "1", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "804", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d)(\d{3})(\d{3})", "80", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})(\d{4,5})", "8", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})(\d{2,8})", "001", "$1 $2 $3 $4", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{4})(\d{3})(\d{4})", "0", "$1 $2 $3 $4", Some("NA"), None),
+        ],
+        IE => &[
+            NumberFormat(r"(\d{2})(\d{5})", "2[24-9]|47|58|6[237-9]|9[35-9]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{5})", "[45]0", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d)(\d{3,4})(\d{4})", "1", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[2569]|4[1-69]|7[14]", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "70", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "81", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[78]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", "1", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "4", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d)(\d{3})(\d{4})", "8", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        IL => &[
+            NumberFormat(r"(\d{4})(\d{3})", "125", "$1-$2", None, None),
+            NumberFormat(r"(\d{4})(\d{2})(\d{2})", "121", "$1-$2-$3", None, None),
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "[2-489]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[57]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", "12", "$1-$2-$3", None, None),
+            NumberFormat(r"(\d{4})(\d{6})", "159", "$1-$2", None, None),
+            NumberFormat(r"(\d)(\d{3})(\d{3})(\d{3})", "1[7-9]", "$1-$2-$3-$4", None, None),
+            NumberFormat(r"(\d{3})(\d{1,2})(\d{3})(\d{4})", "15", "$1-$2 $3-$4", None, None),
+        ],
+        IN => &[
+            NumberFormat(r"(\d{7})", "575", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{8})", "5(?:0|2(?:21|3)|3(?:0|3[23])|616|717|8888)", "$1", None, None),
+            NumberFormat(r"(\d{4})(\d{4,5})", "1800", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "140", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "11|2[02]|33|4[04]|79(?:[124-6]|3(?:[02-9]|1[0-24-9])|7(?:1|9[1-6]))|80(?:[2-4]|6[0-589])", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "1(?:2[0-24]|3[0-25]|4[145]|[59][14]|6[1-9]|7[1257]|8[1-57-9])|2(?:1[257]|3[013]|4[01]|5[0137]|6[058]|78|8[1568]|9[14])|3(?:26|4[1-3]|5[34]|6[01489]|7[02-46]|8[159])|4(?:1[36]|2[1-47]|3[15]|5[12]|6[0-26-9]|7[0-24-9]|8[013-57]|9[014-7])|5(?:1[025]|22|[36][25]|4[28]|[578]1|9[15])|6(?:12(?:[2-6]|7[0-8])|74[2-7])|7(?:(?:2[14]|5[15])[2-6]|3171|61[346]|88(?:[2-7]|82))|8(?:70[2-6]|84(?:[2356]|7[19])|91(?:[3-6]|7[19]))|73[134][2-6]|(?:74[47]|8(?:16|2[014]|3[126]|6[136]|7[78]|83))(?:[2-6]|7[19])|(?:1(?:29|60|8[06])|261|552|6(?:[2-4]1|5[17]|6[13]|7(?:1|4[0189])|80)|7(?:12|88[01]))[2-7]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", r"1(?:[2-479]|5(?:[0236-9]|5[013-9]))|[2-5]|6(?:2(?:84|95)|355|8(?:28[235-7]|3))|73179|807(?:1|9[1-3])|(?:1552|6(?:(?:1[1358]|2[2457]|3[2-4]|4[235-7]|5[2-689]|6[24578]|7[235689])\d|8(?:[14-6]\d|2[0-79]))|7(?:1(?:[013-8]\d|9[6-9])|28[6-8]|3(?:2[0-49]|9[2-57])|4(?:1[2-4]|[29][0-7]|3[0-8]|[56]\d|8[0-24-7])|5(?:2[1-3]|9[0-6])|6(?:0[5689]|2[5-9]|3[02-8]|4\d|5[0-367])|70[13-7]))[2-7]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{5})", "16|[6-9]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{2,4})(\d{4})", "18[06]0", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})(\d{4})", "0", "$1 $2 $3 $4", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})(\d{3})", "18", "$1 $2 $3 $4", None, None),
+        ],
+        IO => &[
+            NumberFormat(r"(\d{3})(\d{4})", "3", "$1 $2", None, None),
+        ],
+        IQ => &[
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "1", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[2-6]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "7", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        IR => &[
+            NumberFormat(r"(\d{4,5})", "96", "$1", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4,5})", "(?:1[137]|2[13-68]|3[1458]|4[145]|5[1468]|6[16]|7[1467]|8[13467])[12689]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "9", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "[1-8]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        IS => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[4-9]", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "3", "$1 $2 $3", None, None),
+        ],
+        IT | VA => &[
+            NumberFormat(r"(\d{4,5})", "1(?:0|9(?:2[2-9]|[46]))", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{6})", "1(?:1|92)", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{4,6})", "0[26]", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3,6})", "0[13-57-9][0159]|8(?:03|4[17]|9(?:2|3[04]|[45][0-4]))", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{2,6})", "0(?:[13-579][2-46-8]|8[236-8])", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "894", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{3,4})(\d{4})", "0[26]|5", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "1(?:44|[679])|[378]|43", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3,4})(\d{4})", "0[13-57-9][0159]|14", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{4})(\d{5})", "0[26]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{4})", "0", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{4})(\d{4,5})", "[03]", "$1 $2 $3", None, None),
+        ],
+        JO => &[
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "[2356]|87", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{5,6})", "[89]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{7})", "70", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "[47]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        JP => &[
+            NumberFormat(r"(\d{4})(\d{4})", "00777[01]", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d{8,10})", "000", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "(?:12|57|99)0", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d)(\d{4})", "1(?:267|3(?:7[247]|9[278])|466|5(?:47|58|64)|6(?:3[245]|48|5[4-68]))|499[2468]|5(?:769|979[2-69])|7468|8(?:3(?:8[7-9]|96[2457-9])|477|51[2-9])|9(?:802|9(?:1[23]|69))|1(?:45|58)[67]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "60", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "3|4(?:2(?:0|9[02-69])|7(?:0[019]|1))|6[1-9]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "1(?:1|5(?:4[018]|5[017])|77|88|9[69])|2(?:2[127]|3[0-269]|4[59]|5(?:[1-3]|5[0-69]|9(?:17|99))|6(?:2|4[016-9])|7(?:[1-35]|8[0189])|8(?:[16]|3[0134]|9[0-5])|9(?:[028]|17))|4(?:2(?:[13-79]|8[014-6])|3[0-57]|[45]|6[248]|7[2-47]|9[29])|5(?:2|3(?:[045]|9(?:[0-58]|6[4-9]|7[0-35689]))|4[0-369]|5[29]|8[02389]|9[0-3])|7(?:2[02-46-9]|34|[58]|6[0249]|7[57]|9(?:[23]|4[0-59]|5[01569]|6[0167]))|8(?:2(?:[1258]|4[0-39]|9[0169])|3(?:[29]|60|7(?:[017-9]|6[6-8]))|49|51|6(?:[0-24]|36[2-57-9]|5(?:[0-389]|5[23])|6(?:[01]|9[178])|7(?:2[2-468]|3[78])|9[0145])|7[0-468]|8[68])|9(?:4[15]|5[138]|7[156]|8[189]|9(?:[1289]|3(?:31|4[357])|4[0178]))|(?:8294|96)[1-3]|2(?:57|93)[015-9]|(?:223|8699)[014-9]|(?:25[0468]|422|838)[01]|(?:48|8292|9[23])[1-9]|(?:47[59]|59[89]|8(?:68|9))[019]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{4})", "[14]|[289][2-9]|5[3-9]|7[2-4679]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{2})(\d{3,4})", "0077", "$1-$2-$3", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{2})(\d{4})", "008", "$1-$2-$3", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "800", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "[25-9]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3,4})", "0", "$1-$2-$3", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{4})(\d{4,5})", "0", "$1-$2-$3", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{5})(\d{5,6})", "0", "$1-$2-$3", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{6})(\d{6,7})", "0", "$1-$2-$3", Some("NA"), None),
+        ],
+        KE => &[
+            NumberFormat(r"(\d{2})(\d{5,7})", "[24-6]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{6})", "[17]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "[89]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        KG => &[
+            NumberFormat(r"(\d{4})(\d{5})", "3(?:1[346]|[24-79])", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[235-79]|88", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d)(\d{2,3})", "8", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        KH => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[1-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", "1", "$1 $2 $3", None, None),
+        ],
+        KM => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})", "[3478]", "$1 $2 $3", None, None),
+        ],
+        KP => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "8", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "[2-7]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "1", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        KR => &[
+            NumberFormat(r"(\d{5})", "1[016-9]114", "$1", Some("NA"), Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3,4})", "(?:3[1-3]|[46][1-4]|5[1-5])1", "$1-$2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{4})", "1", "$1-$2", None, None),
+            NumberFormat(r"(\d)(\d{3,4})(\d{4})", "2", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[36]0|8", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3,4})(\d{4})", "[1346]|5[1-5]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "[57]", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{3})(\d{3})", "0030", "$1 $2 $3", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{5})(\d{4})", "5", "$1-$2-$3", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{3,4})(\d{4})", "0", "$1 $2 $3", Some("NA"), None),
+            NumberFormat(r"(\d{5})(\d{2})(\d{3})(\d{4})", "0", "$1 $2 $3 $4", Some("NA"), None),
+        ],
+        KW => &[
+            NumberFormat(r"(\d{4})(\d{3,4})", "[169]|2(?:[235]|4[1-35-9])|52", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{5})", "[245]", "$1 $2", None, None),
+        ],
+        LA => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "2[13]|3[14]|[4-8]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{3})", "3", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{3})(\d{3})", "[23]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        LB => &[
+            NumberFormat(r"(\d)(\d{3})(\d{3})", "[13-69]|7(?:[2-57]|62|8[0-6]|9[04-9])|8[02-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[27-9]", "$1 $2 $3", None, None),
+        ],
+        LI => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})", "[2379]|8(?:0(?:02|9)|7)", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "8", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "69", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "6", "$1 $2 $3", None, None),
+        ],
+        LK => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "7", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[1-689]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        LR => &[
+            NumberFormat(r"(\d)(\d{3})(\d{3})", "4[67]|[56]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "2", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[2-578]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        LS => &[
+            NumberFormat(r"(\d{4})(\d{4})", "[2568]", "$1 $2", None, None),
+        ],
+        LT => &[
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "52[0-7]", "$1 $2 $3", None, Some("(0-$FG)")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})", "[7-9]", "$1 $2 $3", None, Some("0 $FG")),
+            NumberFormat(r"(\d{2})(\d{6})", "37|4(?:[15]|6[1-8])", "$1 $2", None, Some("(0-$FG)")),
+            NumberFormat(r"(\d{3})(\d{5})", "[3-6]", "$1 $2", None, Some("(0-$FG)")),
+        ],
+        LU => &[
+            NumberFormat(r"(\d{2})(\d{3})", "2(?:0[2-689]|[2-9])|[3-57]|8(?:0[2-9]|[13-9])|9(?:0[89]|[2-579])", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})", "2(?:0[2-689]|[2-9])|[3-57]|8(?:0[2-9]|[13-9])|9(?:0[89]|[2-579])", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{3})", "20[2-689]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{1,2})", "2(?:[0367]|4[3-8])", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})", "80[01]|90[015]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{3})", "20", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "6", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})(\d{1,2})", "2(?:[0367]|4[3-8])", "$1 $2 $3 $4 $5", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{1,5})", "[3-57]|8[13-9]|9(?:0[89]|[2-579])|(?:2|80)[2-9]", "$1 $2 $3 $4", None, None),
+        ],
+        LV => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[2679]|8[01]", "$1 $2 $3", None, None),
+        ],
+        LY => &[
+            NumberFormat(r"(\d{2})(\d{7})", "[2-9]", "$1-$2", None, Some("0$FG")),
+        ],
+        EH | MA => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "5[45]", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{5})", "5(?:[19]|2[2-46-9]|3[3-9])|8(?:0[89]|92)", "$1-$2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{7})", "8", "$1-$2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{6})", "[5-7]", "$1-$2", None, Some("0$FG")),
+        ],
+        MC => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{2})", "87", "$1 $2 $3", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "4", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[389]", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d)(\d{2})(\d{2})(\d{2})(\d{2})", "[67]", "$1 $2 $3 $4 $5", None, Some("0$FG")),
+        ],
+        MD => &[
+            NumberFormat(r"(\d{3})(\d{5})", "[89]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "22|3", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})", "[25-7]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        ME => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[2-9]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        MG => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{3})(\d{2})", "[23]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        MH => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-6]", "$1-$2", None, None),
+        ],
+        MK => &[
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "2|34[47]|4(?:[37]7|5[47]|64)", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[347]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d)(\d{2})(\d{2})", "[58]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        ML => &[
+            NumberFormat(r"(\d{4})", "67(?:0[09]|[59]9|77|8[89])|74(?:0[02]|44|55)", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[24-9]", "$1 $2 $3 $4", None, None),
+        ],
+        MM => &[
+            NumberFormat(r"(\d)(\d{2})(\d{3})", "16|2", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{3})", "4(?:[2-46]|5[3-5])|5|6(?:[1-689]|7[235-7])|7(?:[0-4]|5[2-7])|8[1-5]|(?:60|86)[23]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{3,4})", "[12]|452|6788|86", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[4-7]|8[1-35]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{4,6})", "9(?:2[0-4]|[35-9]|4[137-9])", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "2", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "8", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{3})(\d{3})", "92", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{5})(\d{4})", "9", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        MN => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{4})", "[12]1", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{4})", "[5-9]", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{5,6})", "[12]2[1-3]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{5,6})", "[12](?:27|3[2-8]|4[2-68]|5[1-4689])[0-3]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{5})(\d{4,5})", "[12]", "$1 $2", None, Some("0$FG")),
+        ],
+        MO => &[
+            NumberFormat(r"(\d{4})(\d{3})", "0", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[268]", "$1 $2", None, None),
+        ],
+        MQ => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[5-79]|8(?:0[6-9]|[36])", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "8", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        MR => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[2-48]", "$1 $2 $3 $4", None, None),
+        ],
+        MT => &[
+            NumberFormat(r"(\d{4})(\d{4})", "[2357-9]", "$1 $2", None, None),
+        ],
+        MU => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-46]|8[013]", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[57]", "$1 $2", None, None),
+            NumberFormat(r"(\d{5})(\d{5})", "8", "$1 $2", None, None),
+        ],
+        MV => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[34679]", "$1-$2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[89]", "$1 $2 $3", None, None),
+        ],
+        MW => &[
+            NumberFormat(r"(\d)(\d{3})(\d{3})", "1[2-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "2", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[137-9]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        MX => &[
+            NumberFormat(r"(\d{5})", "53", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "33|5[56]|81", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[2-9]", "$1 $2 $3", None, None),
+        ],
+        MY => &[
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "[4-79]", "$1-$2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "1(?:[02469]|[37][1-9]|53|8(?:[1-46-9]|5[7-9]))|8", "$1-$2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "3", "$1-$2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{2})(\d{4})", "1(?:[367]|80)", "$1-$2-$3-$4", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "15", "$1-$2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "1", "$1-$2 $3", None, Some("0$FG")),
+        ],
+        MZ => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "2|8[2-79]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "8", "$1 $2 $3", None, None),
+        ],
+        NA => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "88", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "6", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "87", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "8", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        NC => &[
+            NumberFormat(r"(\d{3})", "5[6-8]", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})", "[02-57-9]", "$1.$2.$3", None, None),
+        ],
+        NE => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "08", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[089]|2[013]|7[0467]", "$1 $2 $3 $4", None, None),
+        ],
+        NF => &[
+            NumberFormat(r"(\d{2})(\d{4})", "1[0-3]", "$1 $2", None, None),
+            NumberFormat(r"(\d)(\d{5})", "[13]", "$1 $2", None, None),
+        ],
+        NG => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "[7-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "20[129]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{2})(\d{4})", "2", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})(\d{4,5})", "[78]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{5})(\d{5,6})", "[78]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        NI => &[
+            NumberFormat(r"(\d{4})(\d{4})", "[125-8]", "$1 $2", None, None),
+        ],
+        NL => &[
+            NumberFormat(r"(\d{4})", "1[238]|[34]", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{3,4})", "14", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{6})", "1", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{4,7})", "[89]0", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{7})", "66", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{8})", "6", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "1[16-8]|2[259]|3[124]|4[17-9]|5[124679]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[1-578]|91", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{5})", "9", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        NO | SJ => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})", "8", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[2-79]", "$1 $2 $3 $4", None, None),
+        ],
+        NP => &[
+            NumberFormat(r"(\d)(\d{7})", "1[2-6]", "$1-$2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{6})", "1[01]|[2-8]|9(?:[1-59]|[67][2-6])", "$1-$2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{7})", "9", "$1-$2", None, None),
+            NumberFormat(r"(\d{4})(\d{2})(\d{5})", "1", "$1-$2-$3", Some("NA"), None),
+        ],
+        NR => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[24-9]", "$1 $2", None, None),
+        ],
+        NU => &[
+            NumberFormat(r"(\d{3})(\d{4})", "8", "$1 $2", None, None),
+        ],
+        NZ => &[
+            NumberFormat(r"(\d{2})(\d{3,8})", "8[1-79]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2,3})", "50(?:[0367]|88)|8|90", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "24|[346]|7[2-57-9]|9[2-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "2(?:10|74)|[589]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3,4})(\d{4})", "1|2[028]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,5})", "2(?:[169]|7[0-35-9])|7", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        OM => &[
+            NumberFormat(r"(\d{3})(\d{4,6})", "[58]", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{6})", "2", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[179]", "$1 $2", None, None),
+        ],
+        PA => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[1-57-9]", "$1-$2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[68]", "$1-$2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "8", "$1 $2 $3", None, None),
+        ],
+        PE => &[
+            NumberFormat(r"(\d{3})(\d{5})", "80", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d)(\d{7})", "1", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{6})", "[4-8]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "9", "$1 $2 $3", None, None),
+        ],
+        PF => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})", "44", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "4|8[7-9]", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "8", "$1 $2 $3 $4", None, None),
+        ],
+        PG => &[
+            NumberFormat(r"(\d{3})(\d{4})", "18|[2-69]|85", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[78]", "$1 $2", None, None),
+        ],
+        PH => &[
+            NumberFormat(r"(\d)(\d{5})", "2", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{4})(\d{4,6})", "3(?:230|397|461)|4(?:2(?:35|[46]4|51)|396|4(?:22|63)|59[347]|76[15])|5(?:221|446)|642[23]|8(?:622|8(?:[24]2|5[13]))", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{5})(\d{4})", "3469|4(?:279|9(?:30|56))|8834", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d)(\d{4})(\d{4})", "2", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[3-7]|8[2-8]", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[89]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{4})", "1", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{1,2})(\d{3})(\d{4})", "1", "$1 $2 $3 $4", None, None),
+        ],
+        PK => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{2,7})", "[89]0", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{5})", "1", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{6,7})", "9(?:2[3-8]|98)|(?:2(?:3[2358]|4[2-4]|9[2-8])|45[3479]|54[2-467]|60[468]|72[236]|8(?:2[2-689]|3[23578]|4[3478]|5[2356])|9(?:22|3[27-9]|4[2-6]|6[3569]|9[25-7]))[2-9]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{7,8})", "(?:2[125]|4[0-246-9]|5[1-35-7]|6[1-8]|7[14]|8[16]|91)[2-9]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{5})(\d{5})", "58", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{7})", "3", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})(\d{3})", "2[125]|4[0-246-9]|5[1-35-7]|6[1-8]|7[14]|8[16]|91", "$1 $2 $3 $4", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})(\d{3})", "[24-9]", "$1 $2 $3 $4", None, Some("(0$FG)")),
+        ],
+        PL => &[
+            NumberFormat(r"(\d{5})", "19", "$1", None, None),
+            NumberFormat(r"(\d{3})(\d{3})", "11|20|64", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{3})", "(?:1[2-8]|2[2-69]|3[2-4]|4[1-468]|5[24-689]|6[1-3578]|7[14-7]|8[1-79]|9[145])19", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2,3})", "64", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "21|39|45|5[0137]|6[0469]|7[02389]|8(?:0[14]|8)", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "1[2-8]|[2-7]|8[1-79]|9[145]", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "8", "$1 $2 $3", None, None),
+        ],
+        PM => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})", "[45]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "7", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "8", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        PS => &[
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "[2489]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "5", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", "1", "$1 $2 $3", None, None),
+        ],
+        PT => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "2[12]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "16|[236-9]", "$1 $2 $3", None, None),
+        ],
+        PW => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-9]", "$1 $2", None, None),
+        ],
+        PY => &[
+            NumberFormat(r"(\d{3})(\d{3,6})", "[2-9]0", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{7})", "[15]", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{5})", "[26]1|3[289]|4[1246-8]|7[1-3]|8[1-36]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{4,5})", "2[279]|3[13-5]|4[359]|5|6(?:[34]|7[1-46-8])|7[46-8]|85", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "2[14-68]|3[26-9]|4[1246-8]|6(?:1|75)|7[1-35]|8[1-36]", "$1 $2 $3", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "87", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{6})", "9(?:[5-79]|8[1-7])", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[2-8]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{4})", "9", "$1 $2 $3", None, None),
+        ],
+        QA => &[
+            NumberFormat(r"(\d{3})(\d{4})", "2[136]|8", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[3-7]", "$1 $2", None, None),
+        ],
+        RE | YT => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[26-9]", "$1 $2 $3 $4", None, Some("0$FG")),
+        ],
+        RO => &[
+            NumberFormat(r"(\d{3})(\d{3})", r"2[3-6]\d9", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})", "219|31", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[23]1", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[236-9]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        RS => &[
+            NumberFormat(r"(\d{3})(\d{3,9})", "(?:2[389]|39)0|[7-9]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{5,10})", "[1-36]", "$1 $2", None, Some("0$FG")),
+        ],
+        KZ | RU => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})", "[0-79]", "$1-$2-$3", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{2})(\d{2})(\d{2})", "7(?:1(?:[0-356]2|4[29]|7|8[27])|2(?:13[03-69]|62[013-9]))|72[1-57-9]2", "$1 $2 $3 $4", None, Some("8 ($FG)")),
+            NumberFormat(r"(\d{5})(\d)(\d{2})(\d{2})", "7(?:1(?:0(?:[356]|4[023])|[18]|2(?:3[013-9]|5)|3[45]|43[013-79]|5(?:3[1-8]|4[1-7]|5)|6(?:3[0-35-9]|[4-6]))|2(?:1(?:3[178]|[45])|[24-689]|3[35]|7[457]))|7(?:14|23)4[0-8]|71(?:33|45)[1-79]", "$1 $2 $3 $4", None, Some("8 ($FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "7", "$1 $2 $3", None, Some("8 ($FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{2})(\d{2})", "[349]|8(?:[02-7]|1[1-8])", "$1 $2-$3-$4", None, Some("8 ($FG)")),
+            NumberFormat(r"(\d{4})(\d{4})(\d{3})(\d{3})", "8", "$1 $2 $3 $4", None, Some("8 ($FG)")),
+        ],
+        RW => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "0", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "2", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[7-9]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        SA => &[
+            NumberFormat(r"(\d{4})(\d{5})", "9", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "1", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "5", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "8", "$1 $2 $3", None, None),
+        ],
+        SB => &[
+            NumberFormat(r"(\d{2})(\d{5})", "6[89]|7|8[4-9]|9(?:[1-8]|9[0-8])", "$1 $2", None, None),
+        ],
+        SC => &[
+            NumberFormat(r"(\d)(\d{3})(\d{3})", "[246]|9[57]", "$1 $2 $3", None, None),
+        ],
+        SD => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[19]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        SE => &[
+            NumberFormat(r"(\d{2})(\d{2,3})(\d{2})", "20", "$1-$2 $3", Some("$1 $2 $3"), Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})", "9(?:00|39|44|9)", "$1-$2", Some("$1 $2"), Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})", "[12][136]|3[356]|4[0246]|6[03]|90[1-9]", "$1-$2 $3", Some("$1 $2 $3"), Some("0$FG")),
+            NumberFormat(r"(\d)(\d{2,3})(\d{2})(\d{2})", "8", "$1-$2 $3 $4", Some("$1 $2 $3 $4"), Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2,3})(\d{2})", "1[2457]|2(?:[247-9]|5[0138])|3[0247-9]|4[1357-9]|5[0-35-9]|6(?:[125689]|4[02-57]|7[0-2])|9(?:[125-8]|3[02-5]|4[0-3])", "$1-$2 $3", Some("$1 $2 $3"), Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2,3})(\d{3})", "9(?:00|39|44)", "$1-$2 $3", Some("$1 $2 $3"), Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2,3})(\d{2})(\d{2})", "1[13689]|2[0136]|3[1356]|4[0246]|54|6[03]|90[1-9]", "$1-$2 $3 $4", Some("$1 $2 $3 $4"), Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "10|7", "$1-$2 $3 $4", Some("$1 $2 $3 $4"), Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{3})(\d{2})", "8", "$1-$2 $3 $4", Some("$1 $2 $3 $4"), Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "[13-5]|2(?:[247-9]|5[0138])|6(?:[124-689]|7[0-2])|9(?:[125-8]|3[02-5]|4[0-3])", "$1-$2 $3 $4", Some("$1 $2 $3 $4"), Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{3})", "9", "$1-$2 $3 $4", Some("$1 $2 $3 $4"), Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{2})(\d{3})(\d{2})(\d{2})", "[26]", "$1-$2 $3 $4 $5", Some("$1 $2 $3 $4 $5"), Some("0$FG")),
+        ],
+        SG => &[
+            NumberFormat(r"(\d{4,5})", "1(?:[013-8]|9(?:0[1-9]|[1-9]))|77", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{4})(\d{4})", "[369]|8(?:0[1-9]|[1-9])", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "8", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{4})(\d{3})", "7", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d{3})(\d{4})", "1", "$1 $2 $3", None, None),
+        ],
+        SI => &[
+            NumberFormat(r"(\d{2})(\d{3,6})", "8[09]|9", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{5})", "59|8", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[37][01]|4[0139]|51|6", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{2})(\d{2})", "[1-57]", "$1 $2 $3 $4", None, Some("(0$FG)")),
+        ],
+        SK => &[
+            NumberFormat(r"(\d)(\d{2})(\d{3,4})", "21", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2,3})", "[3-5][1-8]1[67]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})", "9090", "$1 $2", Some("NA"), Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{3})(\d{2})", "2", "$1/$2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[689]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "[3-5]", "$1/$2 $3 $4", None, Some("0$FG")),
+        ],
+        SL => &[
+            NumberFormat(r"(\d{2})(\d{6})", "[236-9]", "$1 $2", None, Some("(0$FG)")),
+        ],
+        SM => &[
+            NumberFormat(r"(\d{6})", "[89]", "$1", Some("NA"), None),
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[5-7]", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{4})(\d{6})", "0", "$1 $2", None, None),
+        ],
+        SN => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "8", "$1 $2 $3 $4", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "[379]", "$1 $2 $3 $4", None, None),
+        ],
+        SO => &[
+            NumberFormat(r"(\d{2})(\d{4})", "8[125]", "$1 $2", None, None),
+            NumberFormat(r"(\d{6})", "[134]", "$1", None, None),
+            NumberFormat(r"(\d)(\d{6})", "[15]|2[0-79]|3[0-46-8]|4[0-7]", "$1 $2", None, None),
+            NumberFormat(r"(\d)(\d{7})", "(?:2|90)4|[67]", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[348]|64|79|90", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{5,7})", "1|28|6[0-35-9]|7[67]|9[2-9]", "$1 $2", None, None),
+        ],
+        SR => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})", "56", "$1-$2-$3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})", "[2-5]", "$1-$2", None, None),
+            NumberFormat(r"(\d{3})(\d{4})", "[6-9]", "$1-$2", None, None),
+        ],
+        SS => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[19]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        ST => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[29]", "$1 $2", None, None),
+        ],
+        SV => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[89]", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "[25-7]", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{4})(\d{4})", "[89]", "$1 $2 $3", None, None),
+        ],
+        SY => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[1-4]|5[1-3]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[59]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        SZ => &[
+            NumberFormat(r"(\d{4})(\d{4})", "[0237]", "$1 $2", None, None),
+            NumberFormat(r"(\d{5})(\d{4})", "9", "$1 $2", None, None),
+        ],
+        TD => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[236-9]", "$1 $2 $3 $4", None, None),
+        ],
+        TG => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "[279]", "$1 $2 $3 $4", None, None),
+        ],
+        TH => &[
+            NumberFormat(r"(\d)(\d{3})(\d{4})", "2", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[13-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3})(\d{3})", "1", "$1 $2 $3", None, None),
+        ],
+        TJ => &[
+            NumberFormat(r"(\d{6})(\d)(\d{2})", "3317", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{4})", "44[02-479]|[34]7", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{4})(\d)(\d{4})", "3(?:[1245]|3[12])", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", r"\d", "$1 $2 $3", None, None),
+        ],
+        TL => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[2-489]|70", "$1 $2", None, None),
+            NumberFormat(r"(\d{4})(\d{4})", "7", "$1 $2", None, None),
+        ],
+        TM => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})(\d{2})", "12", "$1 $2-$3-$4", None, Some("(8 $FG)")),
+            NumberFormat(r"(\d{3})(\d)(\d{2})(\d{2})", "[1-5]", "$1 $2-$3-$4", None, Some("(8 $FG)")),
+            NumberFormat(r"(\d{2})(\d{6})", "[67]", "$1 $2", None, Some("8 $FG")),
+        ],
+        TN => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "[2-57-9]", "$1 $2 $3", None, None),
+        ],
+        TO => &[
+            NumberFormat(r"(\d{2})(\d{3})", "[2-4]|50|6[09]|7[0-24-69]|8[05]", "$1-$2", None, None),
+            NumberFormat(r"(\d{4})(\d{3})", "0", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{4})", "[5-9]", "$1 $2", None, None),
+        ],
+        TR => &[
+            NumberFormat(r"(\d{3})(\d)(\d{3})", "444", "$1 $2 $3", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "512|8[01589]|90", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{2})(\d{2})", "5(?:[0-59]|61[06]1)", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{2})(\d{2})", "[24][1-8]|3[1-9]", "$1 $2 $3 $4", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{6,7})", "80", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        TV => &[
+            NumberFormat(r"(\d{2})(\d{3})", "2", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{4})", "90", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{5})", "7", "$1 $2", None, None),
+        ],
+        TW => &[
+            NumberFormat(r"(\d{2})(\d)(\d{4})", "202", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "[258]0", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3,4})(\d{4})", "[23568]|4(?:0[2-48]|[1-47-9])|(?:400|7)[1-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[49]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4,5})", "7", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        TZ => &[
+            NumberFormat(r"(\d{3})(\d{2})(\d{4})", "[89]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[24]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{7})", "5", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[67]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        UA => &[
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "6[12][29]|(?:35|4[1378]|5[12457]|6[49])2|(?:56|65)[24]|(?:3[1-46-8]|46)2[013-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{5})", "3[1-8]|4(?:[1367]|[45][6-9]|8[4-6])|5(?:[1-5]|6(?:[015689]|3[02389])|7[4-6])|6(?:[12][3-7]|[459])", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[3-7]|89|9[1-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "[89]", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        UG => &[
+            NumberFormat(r"(\d{4})(\d{5})", "2024", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{6})", "[27-9]|4(?:6[45]|[7-9])", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{7})", "[34]", "$1 $2", None, Some("0$FG")),
+        ],
+        AG | AI | AS | BB | BM | BS | CA | DM | DO | GD | GU | JM | KN | KY | LC | MP | MS | PR | SX | TC | TT | US | VC | VG | VI => &[
+            NumberFormat(r"(\d{3})(\d{4})", "310", "$1-$2", None, None),
+            NumberFormat(r"(\d{3})(\d{4})", "[24-9]|3(?:[02-9]|1[1-9])", "$1-$2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "[2-9]", "($1) $2-$3", Some("$1-$2-$3"), None),
+        ],
+        UY => &[
+            NumberFormat(r"(\d{4,5})", "21", "$1", None, None),
+            NumberFormat(r"(\d{3})(\d{3,4})", "0", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{4})", "[49]0|8", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3})", "9", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{4})", "[124]", "$1 $2", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{2,4})", "0", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})(\d{2,4})", "0", "$1 $2 $3 $4", None, None),
+        ],
+        UZ => &[
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "[235-9]", "$1 $2 $3 $4", None, None),
+        ],
+        VE => &[
+            NumberFormat(r"(\d{3})(\d{7})", "[24-689]", "$1-$2", None, Some("0$FG")),
+        ],
+        VN => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[17]99", "$1 $2", Some("NA"), Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{5})", "80", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4,5})", "69", "$1 $2", Some("NA"), Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{4,6})", "1", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2})(\d{2})", "6", "$1 $2 $3 $4", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[357-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{4})(\d{4})", "2[48]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})(\d{3})", "2", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        VU => &[
+            NumberFormat(r"(\d{3})(\d{4})", "[57-9]", "$1 $2", None, None),
+        ],
+        WF => &[
+            NumberFormat(r"(\d{2})(\d{2})(\d{2})", "[47-9]", "$1 $2 $3", None, None),
+            NumberFormat(r"(\d{3})(\d{2})(\d{2})(\d{2})", "8", "$1 $2 $3 $4", None, None),
+        ],
+        WS => &[
+            NumberFormat(r"(\d{5})", "[2-5]|6[1-9]", "$1", None, None),
+            NumberFormat(r"(\d{3})(\d{3,7})", "[68]", "$1 $2", None, None),
+            NumberFormat(r"(\d{2})(\d{5})", "7", "$1 $2", None, None),
+        ],
+        YE => &[
+            NumberFormat(r"(\d)(\d{3})(\d{3,4})", "[1-6]|7(?:[24-6]|8[0-7])", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "7", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        ZA => &[
+            NumberFormat(r"(\d{2})(\d{3,4})", "8[1-4]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{2,3})", "8[1-4]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "860", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "[1-9]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{4})", "8", "$1 $2 $3", None, Some("0$FG")),
+        ],
+        ZM => &[
+            NumberFormat(r"(\d{3})(\d{3})", "[1-9]", "$1 $2", Some("NA"), None),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3})", "[28]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{7})", "[579]", "$1 $2", None, Some("0$FG")),
+        ],
+        ZW => &[
+            NumberFormat(r"(\d{3})(\d{3,5})", "2(?:0[45]|2[278]|[49]8)|3(?:[09]8|17)|6(?:[29]8|37|75)|[23][78]|(?:33|5[15]|6[68])[78]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d)(\d{3})(\d{2,4})", "[49]", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{4})", "80", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{7})", "2(?:02[014]|4|[56]20|[79]2)|392|5(?:42|525)|6(?:[16-8]21|52[013])|8[13-59]", "$1 $2", None, Some("(0$FG)")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{4})", "7", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{3})(\d{3})(\d{3,4})", "2(?:1[39]|2[0157]|[378]|[56][14])|3(?:123|29)", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{6})", "8", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3,5})", "1|2(?:0[0-36-9]|12|29|[56])|3(?:1[0-689]|[24-6])|5(?:[0236-9]|1[2-4])|6(?:[013-59]|7[0-46-9])|(?:33|55|6[68])[0-69]|(?:29|3[09]|62)[0-79]", "$1 $2", None, Some("0$FG")),
+            NumberFormat(r"(\d{2})(\d{3})(\d{3,4})", "29[013-9]|39|54", "$1 $2 $3", None, Some("0$FG")),
+            NumberFormat(r"(\d{4})(\d{3,5})", "258|5483", "$1 $2", None, Some("0$FG")),
+        ],
+        _ => &[]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ format_phone, parse_phone, Format, NumberType, PhoneError };
+
+    #[test]
+    fn plan() {
+        let plan = Country::PL.phone_plan().unwrap();
+        assert_eq!(48, plan.calling_code);
+        assert_eq!("00", plan.international_prefix);
+        assert_eq!(None, plan.trunk_prefix);
+        assert_eq!(Some("0"), Country::GB.phone_plan().unwrap().trunk_prefix);
+        assert_eq!("011", Country::US.phone_plan().unwrap().international_prefix);
+        assert_eq!(None, Country::AQ.phone_plan());
+
+        assert_eq!(Some(NumberType::Mobile), plan.number_type("512345678"));
+        assert_eq!(Some(NumberType::FixedLine), plan.number_type("123456789"));
+        assert_eq!(Some(NumberType::FixedLineOrMobile), Country::US.phone_plan().unwrap().number_type("2015550123"));
+        assert_eq!(None, plan.number_type("12345"));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok("123456789".to_string()), parse_phone(Country::PL, "+48 12 345 67 89"));
+        assert_eq!(Ok("123456789".to_string()), parse_phone(Country::PL, "0048 12 345 67 89"));
+        assert_eq!(Ok("123456789".to_string()), parse_phone(Country::PL, "12-345-67-89"));
+        assert_eq!(Ok("2079460958".to_string()), parse_phone(Country::GB, "020 7946 0958"));
+        assert_eq!(Ok("2015550123".to_string()), parse_phone(Country::US, "(201) 555-0123"));
+        assert_eq!(Ok("2015550123".to_string()), parse_phone(Country::US, "1 201 555 0123"));
+        assert_eq!(Ok("0612345678".to_string()), parse_phone(Country::IT, "06 1234 5678"));
+
+        assert_eq!(Err(PhoneError::WrongCountry), parse_phone(Country::PL, "+49 30 123456"));
+        assert_eq!(Err(PhoneError::InvalidLength), parse_phone(Country::PL, "12 345"));
+        // 265 digits, 9 when truncated to a byte.
+        assert_eq!(Err(PhoneError::InvalidLength), parse_phone(Country::PL, &"1".repeat(265)));
+        assert!(!Country::PL.phone_plan().unwrap().is_valid(&"1".repeat(265)));
+        assert_eq!(Err(PhoneError::InvalidNumber), parse_phone(Country::US, "123 555 0123"));
+        assert_eq!(Err(PhoneError::InvalidCharacter), parse_phone(Country::PL, "12 345 67 8x"));
+        assert_eq!(Err(PhoneError::NoPlan), parse_phone(Country::BV, "12345"));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(Ok("+48123456789".to_string()), format_phone(Country::PL, "12 345 67 89", Format::E164));
+        assert_eq!(Ok("12 345 67 89".to_string()), format_phone(Country::PL, "+48123456789", Format::National));
+        assert_eq!(Ok("+48 12 345 67 89".to_string()), format_phone(Country::PL, "123456789", Format::International));
+        assert_eq!(Ok("020 7946 0958".to_string()), format_phone(Country::GB, "+442079460958", Format::National));
+        assert_eq!(Ok("+44 20 7946 0958".to_string()), format_phone(Country::GB, "02079460958", Format::International));
+        assert_eq!(Ok("(201) 555-0123".to_string()), format_phone(Country::US, "2015550123", Format::National));
+        assert_eq!(Ok("+1 201-555-0123".to_string()), format_phone(Country::US, "2015550123", Format::International));
+        assert_eq!(Ok("(416) 555-0123".to_string()), format_phone(Country::CA, "4165550123", Format::National));
+    }
+
+    #[test]
+    fn patterns_parse() {
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            if let Some(plan) = country.phone_plan() {
+                for pattern in &[plan.international_prefixes, plan.pattern, plan.fixed_line, plan.mobile] {
+                    let mut rest = pattern.as_bytes();
+                    super::parse_alternatives(&mut rest);
+                    assert!(rest.is_empty(), "{} {}", country, pattern);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,633 @@
+// Postal code formats.
+//
+// Patterns follow the postal code data of Google's libaddressinput, written
+// in a simpler notation: "#" is a digit, "@" a letter, "*" a letter or a
+// digit, and any other character stands for itself.  Alternatives are
+// separated by "|" and the first one is the canonical form.
+
+use std::fmt;
+use std::error::Error;
+
+use crate::Country;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PostalCodeFormat {
+    /// Whether the country uses postal codes at all.
+    pub used: bool,
+    pub pattern: &'static str,
+    pub example: &'static str,
+}
+
+impl PostalCodeFormat {
+    /// The alternative forms of the pattern, canonical form first.
+    pub fn patterns(&self) -> impl Iterator<Item = &'static str> {
+        self.pattern.split('|').filter(|p| !p.is_empty())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PostalCodeError {
+    /// The country does not use postal codes.
+    NotUsed,
+    InvalidFormat,
+    /// The postal code belongs to another subdivision.
+    WrongSubdivision,
+}
+
+impl Error for PostalCodeError {
+    fn description(&self) -> &str { "error parsing postal code" }
+}
+
+impl fmt::Display for PostalCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Validates a postal code and returns it in its canonical form.
+///
+/// Missing or extra separators and a leading country code are accepted, so
+/// "00950" becomes "00-950" in Poland and "sw1a1aa" becomes "SW1A 1AA" in
+/// the United Kingdom.  Codes in an older form that is still accepted, such
+/// as "1425" or "C1425" for "C1425AAM" in Argentina, keep that form.
+pub fn validate_postal_code(country: Country, code: &str) -> Result<String, PostalCodeError> {
+    let format = country.postal_code_format();
+    if !format.used {
+        return Err(PostalCodeError::NotUsed);
+    }
+    let code = code.split_whitespace().collect::<Vec<_>>().join(" ").to_uppercase();
+    if let Some(canonical) = canonical(&format, &code) {
+        return Ok(canonical);
+    }
+    let prefix = country.to_string();
+    match code.strip_prefix(&prefix) {
+        Some(rest) if rest.starts_with(['-', ' ']) => canonical(&format, &rest[1..]),
+        _ => None
+    }.ok_or(PostalCodeError::InvalidFormat)
+}
+
+/// Validates a postal code and checks that it belongs to the given ISO
+/// 3166-2 subdivision, e.g. "US-CA" or "CA".
+///
+/// Subdivisions are only known for Australia, Canada, France, Spain and the
+/// United States.  For other countries only the format is checked.
+pub fn validate_postal_code_in(country: Country, subdivision: &str, code: &str) -> Result<String, PostalCodeError> {
+    let code = validate_postal_code(country, code)?;
+    let expected = subdivision.trim().to_ascii_uppercase();
+    let expected = expected.rsplit('-').next().unwrap_or("");
+    match self::subdivision(country, &code) {
+        Some(found) if found.rsplit('-').next() != Some(expected) => Err(PostalCodeError::WrongSubdivision),
+        None if has_subdivisions(country) => Err(PostalCodeError::WrongSubdivision),
+        _ => Ok(code)
+    }
+}
+
+/// The ISO 3166-2 subdivision a postal code belongs to, where known.
+pub fn subdivision(country: Country, code: &str) -> Option<&'static str> {
+    let digits = |n: usize| -> Option<u32> {
+        let prefix = code.get(..n)?;
+        if prefix.bytes().all(|b| b.is_ascii_digit()) { prefix.parse().ok() } else { None }
+    };
+    match country {
+        Country::US => {
+            let zip = digits(5)?;
+            US_ZIP_RANGES.iter().find(|&&(lo, hi, _)| lo <= zip && zip <= hi).map(|&(_, _, s)| s)
+        },
+        Country::AU => {
+            let code = digits(4)?;
+            AU_POSTCODE_RANGES.iter().find(|&&(lo, hi, _)| lo <= code && code <= hi).map(|&(_, _, s)| s)
+        },
+        Country::CA => Some(match code.as_bytes().first()? {
+            b'A' => "CA-NL",
+            b'B' => "CA-NS",
+            b'C' => "CA-PE",
+            b'E' => "CA-NB",
+            b'G' | b'H' | b'J' => "CA-QC",
+            b'K' | b'L' | b'M' | b'N' | b'P' => "CA-ON",
+            b'R' => "CA-MB",
+            b'S' => "CA-SK",
+            b'T' => "CA-AB",
+            b'V' => "CA-BC",
+            b'X' if matches!(code.get(..3), Some("X0A") | Some("X0B") | Some("X0C")) => "CA-NU",
+            b'X' => "CA-NT",
+            b'Y' => "CA-YT",
+            _ => return None
+        }),
+        Country::ES => ES_PROVINCES.get(digits(2)?.checked_sub(1)? as usize).copied(),
+        Country::FR => {
+            let department = digits(2)?;
+            Some(match department {
+                20 => if digits(3)? < 202 { "FR-2A" } else { "FR-2B" },
+                97 => match digits(3)? {
+                    971 => "FR-971",
+                    972 => "FR-972",
+                    973 => "FR-973",
+                    974 => "FR-974",
+                    976 => "FR-976",
+                    _ => return None
+                },
+                1..=95 => FR_DEPARTMENTS[department as usize - 1],
+                _ => return None
+            })
+        },
+        _ => None
+    }
+}
+
+fn has_subdivisions(country: Country) -> bool {
+    matches!(country, Country::AU | Country::CA | Country::ES | Country::FR | Country::US)
+}
+
+fn canonical(format: &PostalCodeFormat, code: &str) -> Option<String> {
+    if format.patterns().any(|p| matches(p, code)) {
+        return Some(code.to_string());
+    }
+    // Retry without separators and, for patterns starting with a fixed
+    // prefix such as "LV-####", without the prefix.
+    let compact: String = code.chars().filter(|c| !is_separator(*c)).collect();
+    for pattern in format.patterns() {
+        let literal = pattern.find(|c| is_special(c) || c.is_ascii_digit()).unwrap_or(pattern.len());
+        let candidates = [(pattern, ""), (&pattern[literal..], &pattern[..literal])];
+        for &(pattern, prefix) in &candidates {
+            if !pattern.contains(is_special) {
+                continue;
+            }
+            let bare: String = pattern.chars().filter(|c| !is_separator(*c)).collect();
+            if matches(&bare, &compact) {
+                let mut chars = compact.chars();
+                let mut result = prefix.to_string();
+                for p in pattern.chars() {
+                    if is_separator(p) { result.push(p) } else { result.extend(chars.next()) }
+                }
+                return Some(result);
+            }
+        }
+    }
+    None
+}
+
+fn is_separator(c: char) -> bool {
+    c == ' ' || c == '-'
+}
+
+fn is_special(c: char) -> bool {
+    c == '#' || c == '@' || c == '*'
+}
+
+pub(crate) fn matches(pattern: &str, code: &str) -> bool {
+    pattern.len() == code.len() && pattern.bytes().zip(code.bytes()).all(|(p, c)| match p {
+        b'#' => c.is_ascii_digit(),
+        b'@' => c.is_ascii_uppercase(),
+        b'*' => c.is_ascii_digit() || c.is_ascii_uppercase(),
+        _ => p == c,
+    })
+}
+
+const NONE : PostalCodeFormat = PostalCodeFormat { used: false, pattern: "", example: "" };
+
+const fn f(pattern: &'static str, example: &'static str) -> PostalCodeFormat {
+    PostalCodeFormat { used: true, pattern, example }
+}
+
+impl Country {
+    pub fn postal_code_format(&self) -> PostalCodeFormat {
+        use Country::*;
+        match *self {
+            Unspecified => NONE,
+            AD => f("AD###", "AD100"),
+            AE => NONE,
+            AF => f("####", "1001"),
+            AG => NONE,
+            AI => f("AI-2640", "AI-2640"),
+            AL => f("####", "1001"),
+            AM => f("####", "0010"),
+            AO => NONE,
+            AQ => NONE,
+            AR => f("@####@@@|####|@####", "C1070AAM"),
+            AS => f("#####|#####-####", "96799"),
+            AT => f("####", "1010"),
+            AU => f("####", "2060"),
+            AW => NONE,
+            AX => f("22###", "22150"),
+            AZ => f("AZ ####", "AZ 1000"),
+            BA => f("#####", "71000"),
+            BB => f("BB#####", "BB23026"),
+            BD => f("####", "1340"),
+            BE => f("####", "4000"),
+            BF => NONE,
+            BG => f("####", "1000"),
+            BH => f("###|####", "317"),
+            BI => NONE,
+            BJ => NONE,
+            BL => f("97133", "97133"),
+            BM => f("@@ ##|@@ @@", "FL 07"),
+            BN => f("@@####", "BT2328"),
+            BO => NONE,
+            BQ => NONE,
+            BR => f("#####-###", "40301-110"),
+            BS => NONE,
+            BT => f("#####", "11001"),
+            BV => NONE,
+            BW => NONE,
+            BY => f("######", "223016"),
+            BZ => NONE,
+            CA => f("@#@ #@#", "H3Z 2Y7"),
+            CC => f("6799", "6799"),
+            CD => NONE,
+            CF => NONE,
+            CG => NONE,
+            CH => f("####", "2544"),
+            CI => NONE,
+            CK => NONE,
+            CL => f("#######|###-####", "8340457"),
+            CM => NONE,
+            CN => f("######", "266033"),
+            CO => f("######", "111221"),
+            CR => f("#####", "10101"),
+            CU => f("#####", "10700"),
+            CV => f("####", "7600"),
+            CW => NONE,
+            CX => f("6798", "6798"),
+            CY => f("####", "2008"),
+            CZ => f("### ##", "100 00"),
+            DE => f("#####", "26133"),
+            DJ => NONE,
+            DK => f("####", "8660"),
+            DM => NONE,
+            DO => f("#####", "11903"),
+            DZ => f("#####", "40304"),
+            EC => f("######", "090105"),
+            EE => f("#####", "69501"),
+            EG => f("#####", "12411"),
+            EH => f("#####", "70000"),
+            ER => NONE,
+            ES => f("#####", "28039"),
+            ET => f("####", "1000"),
+            FI => f("#####", "00550"),
+            FJ => NONE,
+            FK => f("FIQQ 1ZZ", "FIQQ 1ZZ"),
+            FM => f("#####|#####-####", "96941"),
+            FO => f("###", "100"),
+            FR => f("#####", "33380"),
+            GA => NONE,
+            GB => f("@# #@@|@## #@@|@@# #@@|@@## #@@|@#@ #@@|@@#@ #@@|GIR 0AA", "EC1Y 8SY"),
+            GD => NONE,
+            GE => f("####", "0101"),
+            GF => f("973##", "97300"),
+            GG => f("GY# #@@|GY## #@@", "GY1 1AA"),
+            GH => NONE,
+            GI => f("GX11 1AA", "GX11 1AA"),
+            GL => f("39##", "3900"),
+            GM => NONE,
+            GN => f("###", "001"),
+            GP => f("971##", "97100"),
+            GQ => NONE,
+            GR => f("### ##", "151 24"),
+            GS => f("SIQQ 1ZZ", "SIQQ 1ZZ"),
+            GT => f("#####", "09001"),
+            GU => f("969##|969##-####", "96910"),
+            GW => f("####", "1000"),
+            GY => NONE,
+            HK => NONE,
+            HM => f("7151", "7151"),
+            HN => f("#####", "31301"),
+            HR => f("#####", "10000"),
+            HT => f("####", "6120"),
+            HU => f("####", "1037"),
+            ID => f("#####", "40115"),
+            IE => f("@## ****|D6W ****", "A65 F4E2"),
+            IL => f("#######", "9614303"),
+            IM => f("IM# #@@|IM## #@@", "IM2 1AA"),
+            IN => f("######", "110034"),
+            IO => f("BBND 1ZZ", "BBND 1ZZ"),
+            IQ => f("#####", "31001"),
+            IR => f("#####-#####", "11936-12345"),
+            IS => f("###", "320"),
+            IT => f("#####", "00144"),
+            JE => f("JE# #@@", "JE2 2BT"),
+            JM => NONE,
+            JO => f("#####", "11937"),
+            JP => f("###-####", "154-0023"),
+            KE => f("#####", "20100"),
+            KG => f("######", "720001"),
+            KH => f("######|#####", "120101"),
+            KI => NONE,
+            KM => NONE,
+            KN => NONE,
+            KP => NONE,
+            KR => f("#####", "03051"),
+            KW => f("#####", "54541"),
+            KY => f("KY#-####", "KY1-1100"),
+            KZ => f("######", "040900"),
+            LA => f("#####", "01160"),
+            LB => f("#### ####|####", "2038 3054"),
+            LC => f("LC## ###", "LC05 201"),
+            LI => f("94##", "9496"),
+            LK => f("#####", "20000"),
+            LR => f("####", "1000"),
+            LS => f("###", "100"),
+            LT => f("LT-#####", "LT-04340"),
+            LU => f("L-####", "L-4750"),
+            LV => f("LV-####", "LV-1073"),
+            LY => NONE,
+            MA => f("#####", "53000"),
+            MC => f("980##", "98000"),
+            MD => f("MD-####", "MD-2012"),
+            ME => f("8####", "81257"),
+            MF => f("97150", "97150"),
+            MG => f("###", "501"),
+            MH => f("969##|969##-####", "96960"),
+            MK => f("####", "1314"),
+            ML => NONE,
+            MM => f("#####", "11181"),
+            MN => f("#####", "65030"),
+            MO => NONE,
+            MP => f("9695#|9695#-####", "96950"),
+            MQ => f("972##", "97220"),
+            MR => NONE,
+            MS => f("MSR ####", "MSR 1110"),
+            MT => f("@@@ ####|@@@ ##", "NXR 01"),
+            MU => f("#####", "42602"),
+            MV => f("#####", "20026"),
+            MW => NONE,
+            MX => f("#####", "02860"),
+            MY => f("#####", "43000"),
+            MZ => f("####", "1102"),
+            NA => f("#####", "10001"),
+            NC => f("988##", "98814"),
+            NE => f("####", "8001"),
+            NF => f("2899", "2899"),
+            NG => f("######", "930283"),
+            NI => f("#####", "52000"),
+            NL => f("#### @@", "1234 AB"),
+            NO => f("####", "0025"),
+            NP => f("#####", "44601"),
+            NR => NONE,
+            NU => NONE,
+            NZ => f("####", "6001"),
+            OM => f("###", "133"),
+            PA => NONE,
+            PE => f("#####", "15001"),
+            PF => f("987##", "98709"),
+            PG => f("###", "111"),
+            PH => f("####", "1008"),
+            PK => f("#####", "44000"),
+            PL => f("##-###", "00-950"),
+            PM => f("97500", "97500"),
+            PN => f("PCRN 1ZZ", "PCRN 1ZZ"),
+            PR => f("00###|00###-####", "00930"),
+            PS => NONE,
+            PT => f("####-###", "2725-079"),
+            PW => f("969##|969##-####", "96940"),
+            PY => f("####", "1536"),
+            QA => NONE,
+            RE => f("974##", "97400"),
+            RO => f("######", "060274"),
+            RS => f("#####", "11000"),
+            RU => f("######", "247112"),
+            RW => NONE,
+            SA => f("#####|#####-####", "11564"),
+            SB => NONE,
+            SC => NONE,
+            SD => f("#####", "11042"),
+            SE => f("### ##", "114 55"),
+            SG => f("######", "546080"),
+            SH => f("STHL 1ZZ|ASCN 1ZZ|TDCU 1ZZ", "STHL 1ZZ"),
+            SI => f("####", "4000"),
+            SJ => f("####", "9170"),
+            SK => f("### ##", "010 01"),
+            SL => NONE,
+            SM => f("4789#", "47890"),
+            SN => f("#####", "12500"),
+            SO => f("@@ #####", "JH 09010"),
+            SR => NONE,
+            SS => NONE,
+            ST => NONE,
+            SV => f("CP ####", "CP 1101"),
+            SX => NONE,
+            SY => NONE,
+            SZ => f("@###", "H100"),
+            TC => f("TKCA 1ZZ", "TKCA 1ZZ"),
+            TD => NONE,
+            TF => NONE,
+            TG => NONE,
+            TH => f("#####", "10150"),
+            TJ => f("######", "735450"),
+            TK => NONE,
+            TL => NONE,
+            TM => f("######", "744000"),
+            TN => f("####", "1002"),
+            TO => NONE,
+            TR => f("#####", "01960"),
+            TT => f("######", "120110"),
+            TV => NONE,
+            TW => f("###|#####|######", "104"),
+            TZ => f("#####|####", "6090"),
+            UA => f("#####", "15432"),
+            UG => NONE,
+            UM => f("96898", "96898"),
+            US => f("#####|#####-####", "95014"),
+            UY => f("#####", "11600"),
+            UZ => f("######", "702100"),
+            VA => f("00120", "00120"),
+            VC => f("VC####", "VC0100"),
+            VE => f("####|####-@", "1010"),
+            VG => f("VG####", "VG1110"),
+            VI => f("008##|008##-####", "00802"),
+            VN => f("#####|######", "70010"),
+            VU => NONE,
+            WF => f("986##", "98600"),
+            WS => f("WS####", "WS1382"),
+            YE => NONE,
+            YT => f("976##", "97600"),
+            ZA => f("####", "0083"),
+            ZM => f("#####", "10101"),
+            ZW => NONE,
+        }
+    }
+}
+
+// Five digit ZIP code ranges of the states, the District of Columbia and the
+// territories.  Military (AA, AE, AP) codes have no ISO 3166-2 subdivision.
+const US_ZIP_RANGES : &[(u32, u32, &str)] = &[
+    (501, 544, "US-NY"),
+    (600, 799, "US-PR"),
+    (800, 899, "US-VI"),
+    (900, 999, "US-PR"),
+    (1000, 2799, "US-MA"),
+    (2800, 2999, "US-RI"),
+    (3000, 3899, "US-NH"),
+    (3900, 4999, "US-ME"),
+    (5000, 5499, "US-VT"),
+    (5500, 5599, "US-MA"),
+    (5600, 5999, "US-VT"),
+    (6000, 6999, "US-CT"),
+    (7000, 8999, "US-NJ"),
+    (10000, 14999, "US-NY"),
+    (15000, 19699, "US-PA"),
+    (19700, 19999, "US-DE"),
+    (20000, 20099, "US-DC"),
+    (20100, 20199, "US-VA"),
+    (20200, 20599, "US-DC"),
+    (20600, 21999, "US-MD"),
+    (22000, 24699, "US-VA"),
+    (24700, 26899, "US-WV"),
+    (27000, 28999, "US-NC"),
+    (29000, 29999, "US-SC"),
+    (30000, 31999, "US-GA"),
+    (32000, 33999, "US-FL"),
+    (34100, 34999, "US-FL"),
+    (35000, 36999, "US-AL"),
+    (37000, 38599, "US-TN"),
+    (38600, 39799, "US-MS"),
+    (39800, 39999, "US-GA"),
+    (40000, 42799, "US-KY"),
+    (43000, 45999, "US-OH"),
+    (46000, 47999, "US-IN"),
+    (48000, 49999, "US-MI"),
+    (50000, 52899, "US-IA"),
+    (53000, 54999, "US-WI"),
+    (55000, 56799, "US-MN"),
+    (56900, 56999, "US-DC"),
+    (57000, 57799, "US-SD"),
+    (58000, 58899, "US-ND"),
+    (59000, 59999, "US-MT"),
+    (60000, 62999, "US-IL"),
+    (63000, 65899, "US-MO"),
+    (66000, 67999, "US-KS"),
+    (68000, 69399, "US-NE"),
+    (70000, 71499, "US-LA"),
+    (71600, 72999, "US-AR"),
+    (73000, 73299, "US-OK"),
+    (73300, 73399, "US-TX"),
+    (73400, 74999, "US-OK"),
+    (75000, 79999, "US-TX"),
+    (80000, 81699, "US-CO"),
+    (82000, 83199, "US-WY"),
+    (83200, 83899, "US-ID"),
+    (84000, 84799, "US-UT"),
+    (85000, 86599, "US-AZ"),
+    (87000, 88499, "US-NM"),
+    (88500, 88599, "US-TX"),
+    (88900, 89899, "US-NV"),
+    (90000, 96199, "US-CA"),
+    (96700, 96798, "US-HI"),
+    (96799, 96799, "US-AS"),
+    (96800, 96899, "US-HI"),
+    (96910, 96932, "US-GU"),
+    (96950, 96952, "US-MP"),
+    (97000, 97999, "US-OR"),
+    (98000, 99499, "US-WA"),
+    (99500, 99999, "US-AK"),
+];
+
+const AU_POSTCODE_RANGES : &[(u32, u32, &str)] = &[
+    (200, 299, "AU-ACT"),
+    (800, 999, "AU-NT"),
+    (1000, 2599, "AU-NSW"),
+    (2600, 2618, "AU-ACT"),
+    (2619, 2899, "AU-NSW"),
+    (2900, 2920, "AU-ACT"),
+    (2921, 2999, "AU-NSW"),
+    (3000, 3999, "AU-VIC"),
+    (4000, 4999, "AU-QLD"),
+    (5000, 5999, "AU-SA"),
+    (6000, 6999, "AU-WA"),
+    (7000, 7999, "AU-TAS"),
+    (8000, 8999, "AU-VIC"),
+    (9000, 9999, "AU-QLD"),
+];
+
+// Provinces by the first two digits of the postal code, 01 to 52.
+const ES_PROVINCES : &[&str] = &[
+    "ES-VI", "ES-AB", "ES-A", "ES-AL", "ES-AV", "ES-BA", "ES-PM", "ES-B", "ES-BU", "ES-CC",
+    "ES-CA", "ES-CS", "ES-CR", "ES-CO", "ES-C", "ES-CU", "ES-GI", "ES-GR", "ES-GU", "ES-SS",
+    "ES-H", "ES-HU", "ES-J", "ES-LE", "ES-L", "ES-LO", "ES-LU", "ES-M", "ES-MA", "ES-MU",
+    "ES-NA", "ES-OR", "ES-O", "ES-P", "ES-GC", "ES-PO", "ES-SA", "ES-TF", "ES-S", "ES-SG",
+    "ES-SE", "ES-SO", "ES-T", "ES-TE", "ES-TO", "ES-V", "ES-VA", "ES-BI", "ES-ZA", "ES-Z",
+    "ES-CE", "ES-ML",
+];
+
+// Metropolitan departments by the first two digits of the postal code, 01
+// to 95.  Corsica (20) is split into 2A and 2B by the third digit.
+const FR_DEPARTMENTS : &[&str] = &[
+    "FR-01", "FR-02", "FR-03", "FR-04", "FR-05", "FR-06", "FR-07", "FR-08", "FR-09", "FR-10",
+    "FR-11", "FR-12", "FR-13", "FR-14", "FR-15", "FR-16", "FR-17", "FR-18", "FR-19", "FR-20",
+    "FR-21", "FR-22", "FR-23", "FR-24", "FR-25", "FR-26", "FR-27", "FR-28", "FR-29", "FR-30",
+    "FR-31", "FR-32", "FR-33", "FR-34", "FR-35", "FR-36", "FR-37", "FR-38", "FR-39", "FR-40",
+    "FR-41", "FR-42", "FR-43", "FR-44", "FR-45", "FR-46", "FR-47", "FR-48", "FR-49", "FR-50",
+    "FR-51", "FR-52", "FR-53", "FR-54", "FR-55", "FR-56", "FR-57", "FR-58", "FR-59", "FR-60",
+    "FR-61", "FR-62", "FR-63", "FR-64", "FR-65", "FR-66", "FR-67", "FR-68", "FR-69", "FR-70",
+    "FR-71", "FR-72", "FR-73", "FR-74", "FR-75", "FR-76", "FR-77", "FR-78", "FR-79", "FR-80",
+    "FR-81", "FR-82", "FR-83", "FR-84", "FR-85", "FR-86", "FR-87", "FR-88", "FR-89", "FR-90",
+    "FR-91", "FR-92", "FR-93", "FR-94", "FR-95",
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ subdivision, validate_postal_code, validate_postal_code_in, PostalCodeError };
+
+    #[test]
+    fn format() {
+        let format = Country::PL.postal_code_format();
+        assert!(format.used);
+        assert_eq!("##-###", format.pattern);
+        assert_eq!("00-950", format.example);
+        assert!(!Country::AE.postal_code_format().used);
+        let format = Country::AR.postal_code_format();
+        assert_eq!(Some("@####@@@"), format.patterns().next());
+        assert!(super::matches("@####@@@", format.example));
+    }
+
+    #[test]
+    fn examples_are_valid() {
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            let format = country.postal_code_format();
+            if format.used {
+                assert_eq!(Ok(format.example.to_string()), validate_postal_code(country, format.example), "{}", country);
+            }
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(Ok("00-950".to_string()), validate_postal_code(Country::PL, "00950"));
+        assert_eq!(Ok("00-950".to_string()), validate_postal_code(Country::PL, " 00-950 "));
+        assert_eq!(Ok("00-950".to_string()), validate_postal_code(Country::PL, "PL-00-950"));
+        assert_eq!(Ok("SW1A 1AA".to_string()), validate_postal_code(Country::GB, "SW1A1AA"));
+        assert_eq!(Ok("SW1A 1AA".to_string()), validate_postal_code(Country::GB, "sw1a  1aa"));
+        assert_eq!(Ok("12345-6789".to_string()), validate_postal_code(Country::US, "123456789"));
+        assert_eq!(Ok("LV-1073".to_string()), validate_postal_code(Country::LV, "1073"));
+        assert_eq!(Ok("1234 AB".to_string()), validate_postal_code(Country::NL, "1234ab"));
+        assert_eq!(Ok("C1070AAM".to_string()), validate_postal_code(Country::AR, "c1070aam"));
+        assert_eq!(Ok("C1070AAM".to_string()), validate_postal_code(Country::AR, "C 1070 AAM"));
+        assert_eq!(Ok("C1425".to_string()), validate_postal_code(Country::AR, "c1425"));
+        assert_eq!(Ok("C1425".to_string()), validate_postal_code(Country::AR, "AR-C1425"));
+        assert_eq!(Ok("1425".to_string()), validate_postal_code(Country::AR, "1425"));
+        assert_eq!(Err(PostalCodeError::InvalidFormat), validate_postal_code(Country::AR, "C1425A"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(PostalCodeError::InvalidFormat), validate_postal_code(Country::PL, "0095"));
+        assert_eq!(Err(PostalCodeError::InvalidFormat), validate_postal_code(Country::GB, "SW1A 1A"));
+        assert_eq!(Err(PostalCodeError::InvalidFormat), validate_postal_code(Country::DE, ""));
+        assert_eq!(Err(PostalCodeError::NotUsed), validate_postal_code(Country::HK, "999077"));
+    }
+
+    #[test]
+    fn subdivisions() {
+        assert_eq!(Some("US-CA"), subdivision(Country::US, "95014"));
+        assert_eq!(Some("CA-QC"), subdivision(Country::CA, "H3Z 2Y7"));
+        assert_eq!(Some("ES-M"), subdivision(Country::ES, "28039"));
+        assert_eq!(Some("FR-2A"), subdivision(Country::FR, "20000"));
+        assert_eq!(Some("AU-ACT"), subdivision(Country::AU, "2600"));
+        assert_eq!(None, subdivision(Country::PL, "00-950"));
+
+        assert_eq!(Ok("95014".to_string()), validate_postal_code_in(Country::US, "US-CA", "95014"));
+        assert_eq!(Ok("95014".to_string()), validate_postal_code_in(Country::US, "ca", "95014"));
+        assert_eq!(Err(PostalCodeError::WrongSubdivision), validate_postal_code_in(Country::US, "US-NY", "95014"));
+        assert_eq!(Ok("00-950".to_string()), validate_postal_code_in(Country::PL, "PL-14", "00950"));
+    }
+}
@@ -0,0 +1,303 @@
+// Country attribution of IP prefixes and AS numbers from the delegation
+// statistics of the Regional Internet Registries.
+//
+// The registries publish "delegated-extended" files, e.g.
+// https://ftp.ripe.net/pub/stats/ripencc/delegated-ripencc-extended-latest,
+// with one delegation per line:
+//
+//     ripencc|PL|ipv4|81.0.0.0|4096|20010321|allocated|a6c2b8a0-...
+//     ripencc|PL|ipv6|2001:1a68::|32|20021204|allocated|a6c2b8a0-...
+//     ripencc|PL|asn|5617|1|19950907|allocated|a6c2b8a0-...
+//
+// Load the files of all five registries into one `RirIndex`.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{ self, BufRead, BufReader };
+use std::net::{ IpAddr, Ipv4Addr, Ipv6Addr };
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::Country;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Registry {
+    Afrinic,
+    Apnic,
+    Arin,
+    Lacnic,
+    RipeNcc,
+}
+
+impl Registry {
+    /// The registry name as used in the delegation files.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Registry::Afrinic => "afrinic",
+            Registry::Apnic => "apnic",
+            Registry::Arin => "arin",
+            Registry::Lacnic => "lacnic",
+            Registry::RipeNcc => "ripencc",
+        }
+    }
+}
+
+impl FromStr for Registry {
+    type Err = RirError;
+
+    fn from_str(s: &str) -> Result<Registry, RirError> {
+        match s {
+            "afrinic" => Ok(Registry::Afrinic),
+            "apnic" => Ok(Registry::Apnic),
+            "arin" => Ok(Registry::Arin),
+            "lacnic" => Ok(Registry::Lacnic),
+            "ripencc" | "ripe" => Ok(Registry::RipeNcc),
+            _ => Err(RirError::UnknownRegistry(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug)]
+pub enum RirError {
+    Io(io::Error),
+    UnknownRegistry(String),
+    /// The line with the given (1-based) number cannot be read.
+    InvalidLine(usize),
+}
+
+impl Error for RirError {
+    fn description(&self) -> &str { "error reading RIR delegation file" }
+}
+
+impl fmt::Display for RirError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl From<io::Error> for RirError {
+    fn from(e: io::Error) -> RirError {
+        RirError::Io(e)
+    }
+}
+
+/// An IP network, e.g. 81.0.0.0/20.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Prefix {
+    pub address: IpAddr,
+    pub len: u8,
+}
+
+impl Prefix {
+    /// Whether the address is in the network.  A length beyond the address
+    /// size, e.g. /40 for IPv4, matches the single address.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let mask = |bits: u32| u128::MAX.checked_shl(bits.saturating_sub(self.len as u32)).unwrap_or(0);
+        match (self.address, ip) {
+            (IpAddr::V4(a), IpAddr::V4(b)) => {
+                (u32::from(a) as u128 ^ u32::from(b) as u128) & mask(32) & 0xffff_ffff == 0
+            },
+            (IpAddr::V6(a), IpAddr::V6(b)) => (u128::from(a) ^ u128::from(b)) & mask(128) == 0,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.len)
+    }
+}
+
+#[derive(Default)]
+pub struct RirIndex {
+    /// `(first, last, country)` AS number ranges sorted by first.
+    asns: Vec<(u32, u32, Country)>,
+    prefixes: HashMap<Country, Vec<Prefix>>,
+    /// The number of delegations per registry for each country.
+    registries: HashMap<Country, HashMap<Registry, usize>>,
+}
+
+impl RirIndex {
+    pub fn new() -> RirIndex {
+        RirIndex::default()
+    }
+
+    pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), RirError> {
+        self.load(BufReader::new(File::open(path)?))
+    }
+
+    /// Adds the delegations of one registry file.  Summary lines, reserved
+    /// and available space and delegations without a country are skipped.
+    pub fn load<R: BufRead>(&mut self, reader: R) -> Result<(), RirError> {
+        for (n, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue
+            }
+            let fields : Vec<&str> = line.split('|').collect();
+            // The version line has a number as its first field, summary lines
+            // have a "*" country and a "summary" last field.
+            if fields[0].starts_with(|c: char| c.is_ascii_digit()) || fields.last() == Some(&"summary") {
+                continue
+            }
+            if fields.len() < 7 {
+                return Err(RirError::InvalidLine(n + 1))
+            }
+            let registry : Registry = fields[0].parse()?;
+            let country = match fields[1].parse::<Country>() {
+                Ok(country) if matches!(fields[6], "allocated" | "assigned") => country,
+                _ => continue,
+            };
+            let invalid = || RirError::InvalidLine(n + 1);
+            match fields[2] {
+                "asn" => {
+                    let first : u32 = fields[3].parse().map_err(|_| invalid())?;
+                    let count : u32 = fields[4].parse().map_err(|_| invalid())?;
+                    let last = count.checked_sub(1).and_then(|c| first.checked_add(c)).ok_or_else(invalid)?;
+                    self.asns.push((first, last, country));
+                },
+                "ipv4" => {
+                    let start : Ipv4Addr = fields[3].parse().map_err(|_| invalid())?;
+                    let count : u64 = fields[4].parse().map_err(|_| invalid())?;
+                    let prefixes = self.prefixes.entry(country).or_default();
+                    // Delegations of a number of addresses that is not a power
+                    // of two are split into CIDR blocks.
+                    let mut start = u32::from(start) as u64;
+                    let end = start + count;
+                    if end > 1 << 32 {
+                        return Err(invalid())
+                    }
+                    while start < end {
+                        let size = (1u64 << start.trailing_zeros().min(32)).min(1 << (end - start).ilog2());
+                        let address = IpAddr::V4(Ipv4Addr::from(start as u32));
+                        prefixes.push(Prefix { address, len: 32 - size.ilog2() as u8 });
+                        start += size;
+                    }
+                },
+                "ipv6" => {
+                    let address : Ipv6Addr = fields[3].parse().map_err(|_| invalid())?;
+                    let len : u8 = fields[4].parse().map_err(|_| invalid())?;
+                    if len > 128 {
+                        return Err(invalid())
+                    }
+                    let prefixes = self.prefixes.entry(country).or_default();
+                    prefixes.push(Prefix { address: IpAddr::V6(address), len });
+                },
+                _ => return Err(invalid()),
+            }
+            *self.registries.entry(country).or_default().entry(registry).or_default() += 1;
+        }
+        self.asns.sort_unstable_by_key(|&(first, _, _)| first);
+        Ok(())
+    }
+
+    pub fn country_of_asn(&self, asn: u32) -> Option<Country> {
+        let i = self.asns.partition_point(|&(first, _, _)| first <= asn);
+        match i.checked_sub(1).map(|i| self.asns[i]) {
+            Some((_, last, country)) if asn <= last => Some(country),
+            _ => None,
+        }
+    }
+
+    /// The IPv4 and IPv6 prefixes delegated to organisations in the country.
+    pub fn prefixes_of(&self, country: Country) -> &[Prefix] {
+        self.prefixes.get(&country).map(|p| &p[..]).unwrap_or(&[])
+    }
+
+    /// The AS number ranges `(first, last)` delegated in the country.
+    pub fn asns_of(&self, country: Country) -> Vec<(u32, u32)> {
+        self.asns.iter().filter(|a| a.2 == country).map(|a| (a.0, a.1)).collect()
+    }
+
+    /// The registry serving the country, i.e. the one with the most
+    /// delegations there.  A few countries have legacy space from other
+    /// registries, e.g. early ARIN allocations in Europe.
+    pub fn registry_of(&self, country: Country) -> Option<Registry> {
+        self.registries.get(&country)?
+            .iter()
+            .max_by_key(|&(registry, count)| (count, std::cmp::Reverse(registry)))
+            .map(|(&registry, _)| registry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use crate::Country;
+    use super::{ Prefix, Registry, RirIndex };
+
+    const RIPE : &str = "\
+2.3|ripencc|1700000000|3|19830705|20240101|+0100
+ripencc|*|asn|*|2|summary
+ripencc|*|ipv4|*|2|summary
+ripencc|PL|ipv4|81.0.0.0|4096|20010321|allocated|a
+ripencc|PL|ipv4|83.0.0.0|768|20030101|allocated|a
+ripencc|PL|ipv6|2001:1a68::|32|20021204|allocated|a
+ripencc|PL|asn|5617|1|19950907|allocated|b
+ripencc|DE|asn|3320|4|19940701|allocated|c
+ripencc||ipv4|185.0.0.0|1024||available|
+ripencc|ZZ|asn|64496|16||reserved|
+";
+
+    const ARIN : &str = "\
+arin|PL|ipv4|192.0.2.0|256|19930101|assigned|d
+arin|US|asn|7018|1|19960101|assigned|e
+";
+
+    fn index() -> RirIndex {
+        let mut index = RirIndex::new();
+        index.load(RIPE.as_bytes()).unwrap();
+        index.load(ARIN.as_bytes()).unwrap();
+        index
+    }
+
+    #[test]
+    fn asns() {
+        let index = index();
+        assert_eq!(Some(Country::PL), index.country_of_asn(5617));
+        assert_eq!(Some(Country::DE), index.country_of_asn(3323));
+        assert_eq!(None, index.country_of_asn(3324));
+        assert_eq!(Some(Country::US), index.country_of_asn(7018));
+        assert_eq!(None, index.country_of_asn(64500));
+        assert_eq!(vec![(3320, 3323)], index.asns_of(Country::DE));
+    }
+
+    #[test]
+    fn prefixes() {
+        let index = index();
+        let prefixes : Vec<String> = index.prefixes_of(Country::PL).iter().map(Prefix::to_string).collect();
+        assert_eq!(vec!["81.0.0.0/20", "83.0.0.0/23", "83.0.2.0/24", "2001:1a68::/32", "192.0.2.0/24"], prefixes);
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+        assert!(index.prefixes_of(Country::PL)[0].contains(ip("81.0.15.255")));
+        assert!(!index.prefixes_of(Country::PL)[0].contains(ip("81.0.16.0")));
+        assert!(index.prefixes_of(Country::PL)[3].contains(ip("2001:1a68:1::1")));
+        assert!(index.prefixes_of(Country::FR).is_empty());
+        let prefix = |s: &str, len| Prefix { address: ip(s), len };
+        assert!(prefix("0.0.0.0", 0).contains(ip("81.0.0.1")));
+        assert!(prefix("::", 0).contains(ip("2001:db8::1")));
+        assert!(prefix("81.0.0.1", 40).contains(ip("81.0.0.1")));
+        assert!(!prefix("81.0.0.1", 40).contains(ip("81.0.0.2")));
+        assert!(!prefix("2001:db8::1", 200).contains(ip("2001:db8::2")));
+    }
+
+    #[test]
+    fn registries() {
+        let index = index();
+        assert_eq!(Some(Registry::RipeNcc), index.registry_of(Country::PL));
+        assert_eq!(Some(Registry::Arin), index.registry_of(Country::US));
+        assert_eq!(None, index.registry_of(Country::FR));
+        assert!(matches!(RirIndex::new().load("ripencc|PL|ipv4|81.0.0.0|x|2001|allocated|a".as_bytes()),
+                         Err(super::RirError::InvalidLine(1))));
+    }
+}
@@ -0,0 +1,400 @@
+// Alternative serde representations of `Country`, for use with
+// `#[serde(with = "iso_country::serde::alpha3")]` and the like.  The
+// `Serialize` and `Deserialize` impls of `Country` use the alpha-2 code.
+
+use std::fmt;
+
+use ::serde::de::{ self, MapAccess, Unexpected, Visitor };
+use ::serde::ser::{ self, SerializeStruct };
+use ::serde::{ Deserializer, Serializer };
+
+use crate::Country;
+
+fn from_numeric(n: u64) -> Option<Country> {
+    u16::try_from(n).ok().and_then(Country::from_numeric)
+}
+
+/// The three letter code, "POL".
+pub mod alpha3 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> {
+        match country.alpha3() {
+            "" => Err(ser::Error::custom(format_args!("{:?} has no alpha-3 code", country))),
+            code => serializer.serialize_str(code),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+        deserializer.deserialize_str(StrVisitor("3 letter country code", Country::from_alpha3))
+    }
+}
+
+/// The numeric code as an integer, 616.
+pub mod numeric {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(*country as u16)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+        deserializer.deserialize_u16(NumericVisitor)
+    }
+
+    struct NumericVisitor;
+
+    impl<'de> Visitor<'de> for NumericVisitor {
+        type Value = Country;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("numeric country code")
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Country, E> {
+            from_numeric(value).ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Country, E> {
+            u64::try_from(value).ok().and_then(from_numeric)
+                .ok_or_else(|| E::invalid_value(Unexpected::Signed(value), &self))
+        }
+    }
+}
+
+/// The numeric code as a zero-padded string, "004".
+pub mod numeric_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:03}", *country as u16))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+        fn parse(s: &str) -> Option<Country> {
+            if s.len() != 3 || !s.bytes().all(|b| b.is_ascii_digit()) {
+                return None
+            }
+            s.parse().ok().and_then(from_numeric)
+        }
+        deserializer.deserialize_str(StrVisitor("3 digit numeric country code", parse))
+    }
+}
+
+/// The English short name, "Poland".
+pub mod name {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(country.name())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+        deserializer.deserialize_str(StrVisitor("English country name", Country::from_name))
+    }
+}
+
+/// An object with all codes, `{"alpha2": "PL", "alpha3": "POL", "numeric": 616,
+/// "name": "Poland"}`.  When deserializing, the country is taken from the
+/// first of alpha2, alpha3 and numeric that is present, the other fields are
+/// ignored.
+pub mod object {
+    use super::*;
+
+    const FIELDS : &[&str] = &["alpha2", "alpha3", "numeric", "name"];
+
+    pub fn serialize<S: Serializer>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> {
+        if *country == Country::Unspecified {
+            return Err(ser::Error::custom(format_args!("{:?} has no country codes", country)))
+        }
+        let mut state = serializer.serialize_struct("Country", 4)?;
+        state.serialize_field("alpha2", &country.to_string())?;
+        state.serialize_field("alpha3", country.alpha3())?;
+        state.serialize_field("numeric", &(*country as u16))?;
+        state.serialize_field("name", country.name())?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+        deserializer.deserialize_struct("Country", FIELDS, ObjectVisitor)
+    }
+
+    struct ObjectVisitor;
+
+    impl<'de> Visitor<'de> for ObjectVisitor {
+        type Value = Country;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("country object with alpha2, alpha3 or numeric")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Country, A::Error> {
+            let (mut alpha2, mut alpha3, mut numeric) = (None, None, None);
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "alpha2" => alpha2 = Some(map.next_value::<String>()?),
+                    "alpha3" => alpha3 = map.next_value::<Option<String>>()?,
+                    "numeric" => numeric = Some(map.next_value::<u64>()?),
+                    _ => { map.next_value::<de::IgnoredAny>()?; },
+                }
+            }
+            if let Some(alpha2) = alpha2 {
+                alpha2.parse().map_err(|_| de::Error::invalid_value(Unexpected::Str(&alpha2), &"2 letter country code"))
+            } else if let Some(alpha3) = alpha3 {
+                Country::from_alpha3(&alpha3).ok_or_else(|| de::Error::invalid_value(Unexpected::Str(&alpha3), &"3 letter country code"))
+            } else if let Some(numeric) = numeric {
+                from_numeric(numeric).ok_or_else(|| de::Error::invalid_value(Unexpected::Unsigned(numeric), &"numeric country code"))
+            } else {
+                Err(de::Error::missing_field("alpha2"))
+            }
+        }
+    }
+}
+
+/// How `lenient` treats reserved and withdrawn codes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LegacyPolicy {
+    /// Map the exceptionally reserved codes UK and EL, and withdrawn codes
+    /// with a single successor, e.g. ZR to CD and TP to TL.
+    Map,
+    /// Map only the exceptionally reserved codes UK and EL.
+    MapExceptional,
+    /// Reject all of them.
+    Reject,
+}
+
+/// Deserializes a country from an alpha-2 or alpha-3 code in any case, a
+/// numeric code as an integer or string, or the English name, as a string or
+/// bytes.  Legacy codes are mapped as with `LegacyPolicy::Map`.  For use with
+/// `#[serde(deserialize_with = "iso_country::serde::lenient")]`; the format
+/// must be self-describing.
+pub fn lenient<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+    lenient_with(deserializer, LegacyPolicy::Map)
+}
+
+/// `lenient` with `LegacyPolicy::MapExceptional`, for use with
+/// `#[serde(deserialize_with = "iso_country::serde::lenient_exceptional")]`.
+pub fn lenient_exceptional<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+    lenient_with(deserializer, LegacyPolicy::MapExceptional)
+}
+
+/// `lenient` with `LegacyPolicy::Reject`, for use with
+/// `#[serde(deserialize_with = "iso_country::serde::lenient_reject")]`.
+pub fn lenient_reject<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+    lenient_with(deserializer, LegacyPolicy::Reject)
+}
+
+/// `lenient` with a given legacy code policy.  `lenient`,
+/// `lenient_exceptional` and `lenient_reject` apply it in derives.
+pub fn lenient_with<'de, D: Deserializer<'de>>(deserializer: D, policy: LegacyPolicy) -> Result<Country, D::Error> {
+    deserializer.deserialize_any(LenientVisitor(policy))
+}
+
+const ACCEPTED_FORMS : &str = "an alpha-2 code like \"PL\", an alpha-3 code like \"POL\", \
+                               a numeric code like 616 or \"616\", or an English name like \"Poland\"";
+
+/// Codes that are reserved or withdrawn and map to a single current country.
+/// The flag marks exceptionally reserved codes.
+const LEGACY_CODE_SEARCH_TABLE : &[(&str, Country, bool)] = &[
+    ("BU",  Country::MM, false),
+    ("BUR", Country::MM, false),
+    ("DD",  Country::DE, false),
+    ("DDR", Country::DE, false),
+    ("EL",  Country::GR, true),
+    ("FX",  Country::FR, false),
+    ("FXX", Country::FR, false),
+    ("ROM", Country::RO, false),
+    ("TMP", Country::TL, false),
+    ("TP",  Country::TL, false),
+    ("UK",  Country::GB, true),
+    ("VD",  Country::VN, false),
+    ("VDR", Country::VN, false),
+    ("YD",  Country::YE, false),
+    ("YMD", Country::YE, false),
+    ("ZAR", Country::CD, false),
+    ("ZR",  Country::CD, false),
+];
+
+/// Withdrawn codes of countries that were split, which cannot be mapped.
+const WITHDRAWN_CODES : &[&str] = &["AN", "ANT", "CS", "CSK", "SCG", "SU", "SUN", "YU", "YUG"];
+
+struct LenientVisitor(LegacyPolicy);
+
+impl LenientVisitor {
+    fn parse<E: de::Error>(&self, value: &str) -> Result<Country, E> {
+        let code = value.trim().to_ascii_uppercase();
+        let country = match code.len() {
+            2 => code.parse().ok(),
+            3 if code.bytes().all(|b| b.is_ascii_digit()) => code.parse().ok().and_then(from_numeric),
+            3 => Country::from_alpha3(&code),
+            _ => None,
+        };
+        if let Some(country) = country.or_else(|| Country::from_name(value.trim())) {
+            return Ok(country)
+        }
+        if let Ok(pos) = LEGACY_CODE_SEARCH_TABLE.binary_search_by(|&(o, _, _)| o.cmp(&code)) {
+            let (_, country, exceptional) = LEGACY_CODE_SEARCH_TABLE[pos];
+            return match self.0 {
+                LegacyPolicy::Map => Ok(country),
+                LegacyPolicy::MapExceptional if exceptional => Ok(country),
+                _ => Err(E::custom(format_args!("legacy country code {:?} is not accepted, use {:?} instead", value, country.to_string()))),
+            }
+        }
+        if WITHDRAWN_CODES.contains(&code.as_str()) {
+            return Err(E::custom(format_args!("withdrawn country code {:?} has no single successor", value)))
+        }
+        Err(E::invalid_value(Unexpected::Str(value), &ACCEPTED_FORMS))
+    }
+}
+
+impl<'de> Visitor<'de> for LenientVisitor {
+    type Value = Country;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(ACCEPTED_FORMS)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Country, E> {
+        self.parse(value)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Country, E> {
+        match std::str::from_utf8(value) {
+            Ok(s) => self.parse(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Country, E> {
+        from_numeric(value).ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Country, E> {
+        u64::try_from(value).ok().and_then(from_numeric)
+            .ok_or_else(|| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+}
+
+/// Deserializes a string with the given parser, described by the given
+/// expectation in errors.
+struct StrVisitor(&'static str, fn(&str) -> Option<Country>);
+
+impl<'de> Visitor<'de> for StrVisitor {
+    type Value = Country;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Country, E> {
+        (self.1)(value).ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use serde_json::Value;
+    use super::LegacyPolicy;
+
+    fn to_json(serialize: fn(&Country, serde_json::value::Serializer) -> serde_json::Result<Value>, country: Country) -> String {
+        serialize(&country, serde_json::value::Serializer).unwrap().to_string()
+    }
+
+    fn from_json(deserialize: fn(Value) -> serde_json::Result<Country>, json: &str) -> serde_json::Result<Country> {
+        deserialize(serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(r#""POL""#, to_json(super::alpha3::serialize, Country::PL));
+        assert_eq!("4", to_json(super::numeric::serialize, Country::AF));
+        assert_eq!(r#""004""#, to_json(super::numeric_string::serialize, Country::AF));
+        assert_eq!(r#""Germany""#, to_json(super::name::serialize, Country::DE));
+        assert_eq!(r#"{"alpha2":"PL","alpha3":"POL","name":"Poland","numeric":616}"#,
+                   to_json(super::object::serialize, Country::PL));
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(Country::PL, from_json(super::alpha3::deserialize, r#""POL""#).unwrap());
+        assert_eq!(Country::AF, from_json(super::numeric::deserialize, "4").unwrap());
+        assert_eq!(Country::AF, from_json(super::numeric_string::deserialize, r#""004""#).unwrap());
+        assert_eq!(Country::DE, from_json(super::name::deserialize, r#""Germany""#).unwrap());
+        assert_eq!(Country::PL, from_json(super::object::deserialize,
+                                          r#"{"alpha2":"PL","alpha3":"POL","numeric":616,"name":"Poland"}"#).unwrap());
+        assert_eq!(Country::PL, from_json(super::object::deserialize, r#"{"numeric":616}"#).unwrap());
+    }
+
+    #[test]
+    fn object_round_trip() {
+        for country in Country::iter() {
+            let json = super::object::serialize(&country, serde_json::value::Serializer).unwrap();
+            assert_eq!(country, super::object::deserialize(json).unwrap());
+        }
+        assert!(super::object::serialize(&Country::Unspecified, serde_json::value::Serializer).is_err());
+        assert!(super::alpha3::serialize(&Country::Unspecified, serde_json::value::Serializer).is_err());
+    }
+
+    #[test]
+    fn invalid_values() {
+        let error = from_json(super::alpha3::deserialize, r#""PL""#).unwrap_err().to_string();
+        assert!(error.contains("expected 3 letter country code"), "{}", error);
+        assert!(from_json(super::numeric::deserialize, "1000").is_err());
+        assert!(from_json(super::numeric_string::deserialize, r#""4""#).is_err());
+        assert!(from_json(super::object::deserialize, r#"{"name":"Poland"}"#).is_err());
+    }
+
+    #[test]
+    fn lenient() {
+        let parse = |json: &str| from_json(super::lenient, json);
+        assert_eq!(Country::PL, parse(r#""pl""#).unwrap());
+        assert_eq!(Country::PL, parse(r#""pol""#).unwrap());
+        assert_eq!(Country::PL, parse("616").unwrap());
+        assert_eq!(Country::AF, parse(r#""004""#).unwrap());
+        assert_eq!(Country::PL, parse(r#""Poland""#).unwrap());
+        assert_eq!(Country::GB, parse(r#""UK""#).unwrap());
+        assert_eq!(Country::GR, parse(r#""EL""#).unwrap());
+        assert_eq!(Country::CD, parse(r#""ZR""#).unwrap());
+        let bytes = ::serde::de::value::BytesDeserializer::<::serde::de::value::Error>::new(b"de");
+        assert_eq!(Country::DE, super::lenient(bytes).unwrap());
+    }
+
+    #[test]
+    fn lenient_errors() {
+        fn exceptional(d: Value) -> serde_json::Result<Country> {
+            super::lenient_with(d, LegacyPolicy::MapExceptional)
+        }
+        assert_eq!(Country::GB, from_json(exceptional, r#""UK""#).unwrap());
+        let error = from_json(exceptional, r#""ZR""#).unwrap_err().to_string();
+        assert_eq!(r#"legacy country code "ZR" is not accepted, use "CD" instead"#, error);
+        assert!(from_json(super::lenient_reject, r#""UK""#).is_err());
+        let error = from_json(super::lenient, r#""YU""#).unwrap_err().to_string();
+        assert_eq!(r#"withdrawn country code "YU" has no single successor"#, error);
+        let error = from_json(super::lenient, r#""Atlantis""#).unwrap_err().to_string();
+        assert!(error.contains(r#"expected an alpha-2 code like "PL", an alpha-3 code like "POL""#), "{}", error);
+    }
+
+    #[test]
+    fn lenient_derive() {
+        #[derive(::serde::Deserialize)]
+        struct Record {
+            #[serde(deserialize_with = "crate::serde::lenient")]
+            any: Country,
+            #[serde(deserialize_with = "crate::serde::lenient_exceptional")]
+            exceptional: Country,
+            #[serde(deserialize_with = "crate::serde::lenient_reject")]
+            reject: Country,
+        }
+        let record : Record = serde_json::from_str(r#"{"any":"ZR","exceptional":"UK","reject":"pol"}"#).unwrap();
+        assert_eq!((Country::CD, Country::GB, Country::PL), (record.any, record.exceptional, record.reject));
+        assert!(serde_json::from_str::<Record>(r#"{"any":"ZR","exceptional":"ZR","reject":"PL"}"#).is_err());
+        assert!(serde_json::from_str::<Record>(r#"{"any":"ZR","exceptional":"UK","reject":"UK"}"#).is_err());
+    }
+
+    #[test]
+    fn legacy_code_table_is_sorted() {
+        let table = super::LEGACY_CODE_SEARCH_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
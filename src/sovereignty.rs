@@ -0,0 +1,169 @@
+// Sovereignty and the status of dependent territories.
+//
+// ISO 3166-1 assigns codes to dependent territories and special areas as
+// well as to sovereign states.  The classification below follows the
+// constitutional arrangement of the state responsible for each territory.
+
+use crate::Country;
+
+/// The political status of an ISO 3166-1 entry.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// A member or observer state of the United Nations.
+    Sovereign,
+    /// A state which is not universally recognized (Taiwan).
+    PartiallyRecognized,
+    /// A territory whose sovereignty is contested (Western Sahara).
+    Disputed,
+    /// A self-governing region of a sovereign state (Åland, Faroe Islands,
+    /// Greenland).
+    AutonomousRegion,
+    /// A constituent country of the Kingdom of the Netherlands (Aruba,
+    /// Curaçao, Sint Maarten).
+    ConstituentCountry,
+    /// A special municipality of the Netherlands (Bonaire, Sint Eustatius
+    /// and Saba).
+    SpecialMunicipality,
+    /// A French overseas department and region, fully part of France and
+    /// of the EU.
+    OverseasDepartment,
+    /// A French overseas collectivity, including New Caledonia.
+    OverseasCollectivity,
+    /// The French Southern and Antarctic Lands.
+    OverseasTerritory,
+    /// A self-governing possession of the British Crown (Guernsey, Jersey,
+    /// Isle of Man).
+    CrownDependency,
+    /// A British Overseas Territory.
+    BritishOverseasTerritory,
+    /// A special administrative region of China (Hong Kong, Macao).
+    SpecialAdministrativeRegion,
+    /// An unincorporated territory of the United States.
+    UnincorporatedTerritory,
+    /// An external territory of Australia.
+    ExternalTerritory,
+    /// A state in free association with New Zealand (Cook Islands, Niue).
+    FreeAssociation,
+    /// A dependent territory without self-government (Bouvet Island,
+    /// Tokelau).
+    Dependency,
+    /// An area that is an integral part of its sovereign state but has its
+    /// own code (Svalbard and Jan Mayen).
+    IntegralPart,
+    /// Antarctica, governed under the Antarctic Treaty System.
+    Antarctic,
+}
+
+impl Country {
+    /// The political status of this entry, `None` for `Unspecified`.
+    pub fn status(&self) -> Option<Status> {
+        use Country::*;
+        use self::Status::*;
+        Some(match *self {
+            Unspecified => return None,
+            TW => PartiallyRecognized,
+            EH => Disputed,
+            AX | FO | GL => AutonomousRegion,
+            AW | CW | SX => ConstituentCountry,
+            BQ => SpecialMunicipality,
+            GF | GP | MQ | RE | YT => OverseasDepartment,
+            BL | MF | NC | PF | PM | WF => OverseasCollectivity,
+            TF => OverseasTerritory,
+            GG | IM | JE => CrownDependency,
+            AI | BM | FK | GI | GS | IO | KY | MS | PN | SH | TC | VG => BritishOverseasTerritory,
+            HK | MO => SpecialAdministrativeRegion,
+            AS | GU | MP | PR | UM | VI => UnincorporatedTerritory,
+            CC | CX | HM | NF => ExternalTerritory,
+            CK | NU => FreeAssociation,
+            BV | TK => Dependency,
+            SJ => IntegralPart,
+            AQ => Antarctic,
+            _ => Sovereign,
+        })
+    }
+
+    /// Returns `true` for UN member and observer states.
+    pub fn is_sovereign(&self) -> bool {
+        self.status() == Some(Status::Sovereign)
+    }
+
+    /// The sovereign state responsible for a dependent territory.
+    ///
+    /// Returns `None` for sovereign states themselves and for areas with no
+    /// agreed sovereign, such as Antarctica, Taiwan and Western Sahara.
+    pub fn sovereign(&self) -> Option<Country> {
+        use Country::*;
+        Some(match *self {
+            FO | GL => DK,
+            AX => FI,
+            AW | BQ | CW | SX => NL,
+            BL | GF | GP | MF | MQ | NC | PF | PM | RE | TF | WF | YT => FR,
+            AI | BM | FK | GG | GI | GS | IM | IO | JE | KY | MS | PN | SH | TC | VG => GB,
+            HK | MO => CN,
+            AS | GU | MP | PR | UM | VI => US,
+            CC | CX | HM | NF => AU,
+            CK | NU | TK => NZ,
+            BV | SJ => NO,
+            _ => return None
+        })
+    }
+
+    /// The dependent territories for which this state is responsible.
+    pub fn dependencies(&self) -> &'static [Country] {
+        use Country::*;
+        match *self {
+            DK => &[FO, GL],
+            FI => &[AX],
+            NL => &[AW, BQ, CW, SX],
+            FR => &[BL, GF, GP, MF, MQ, NC, PF, PM, RE, TF, WF, YT],
+            GB => &[AI, BM, FK, GG, GI, GS, IM, IO, JE, KY, MS, PN, SH, TC, VG],
+            CN => &[HK, MO],
+            US => &[AS, GU, MP, PR, UM, VI],
+            AU => &[CC, CX, HM, NF],
+            NZ => &[CK, NU, TK],
+            NO => &[BV, SJ],
+            _ => &[]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::Status;
+
+    #[test]
+    fn sovereign() {
+        assert_eq!(Some(Country::DK), Country::GL.sovereign());
+        assert_eq!(Some(Country::US), Country::PR.sovereign());
+        assert_eq!(Some(Country::FI), Country::AX.sovereign());
+        assert_eq!(None, Country::PL.sovereign());
+        assert_eq!(None, Country::AQ.sovereign());
+        assert!(Country::PL.is_sovereign());
+        assert!(!Country::HK.is_sovereign());
+        assert!(!Country::Unspecified.is_sovereign());
+    }
+
+    #[test]
+    fn status() {
+        assert_eq!(Some(Status::OverseasDepartment), Country::GF.status());
+        assert_eq!(Some(Status::CrownDependency), Country::JE.status());
+        assert_eq!(Some(Status::SpecialAdministrativeRegion), Country::HK.status());
+        assert_eq!(Some(Status::Dependency), Country::BV.status());
+        assert_eq!(Some(Status::Sovereign), Country::PL.status());
+        assert_eq!(None, Country::Unspecified.status());
+    }
+
+    #[test]
+    fn dependencies_match_sovereign() {
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            if let Some(sovereign) = country.sovereign() {
+                assert!(sovereign.dependencies().contains(&country), "{}", country);
+                assert!(sovereign.is_sovereign());
+            }
+            for dependency in country.dependencies() {
+                assert_eq!(Some(country), dependency.sovereign());
+            }
+        }
+    }
+}
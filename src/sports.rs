@@ -0,0 +1,970 @@
+// Country codes of the International Olympic Committee and of FIFA.
+//
+// Both differ from ISO 3166-1 alpha-3 for dozens of countries (GER, NED,
+// SUI, POR, ...) and, in places, from each other (IRI and IRN for Iran).
+// FIFA has separate associations for the home nations of the United
+// Kingdom and none for the United Kingdom as a whole.
+
+use crate::Country;
+
+/// A FIFA member association.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FifaMember {
+    Country(Country),
+    HomeNation(HomeNation),
+}
+
+/// A home nation of the United Kingdom.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HomeNation {
+    England,
+    Scotland,
+    Wales,
+    NorthernIreland,
+}
+
+impl HomeNation {
+    /// The ISO 3166-2 code, "GB-ENG".
+    pub fn subdivision_code(&self) -> &'static str {
+        match *self {
+            HomeNation::England => "GB-ENG",
+            HomeNation::Scotland => "GB-SCT",
+            HomeNation::Wales => "GB-WLS",
+            HomeNation::NorthernIreland => "GB-NIR",
+        }
+    }
+}
+
+impl FifaMember {
+    /// The country of the association, `GB` for the home nations.
+    pub fn country(&self) -> Country {
+        match *self {
+            FifaMember::Country(country) => country,
+            FifaMember::HomeNation(_) => Country::GB,
+        }
+    }
+
+    /// The FIFA code, `None` for a country without an association of its
+    /// own, e.g. `FifaMember::Country(Country::GB)`.
+    pub fn fifa_code(&self) -> Option<&'static str> {
+        Some(match *self {
+            FifaMember::Country(country) => return country.fifa_code(),
+            FifaMember::HomeNation(HomeNation::England) => "ENG",
+            FifaMember::HomeNation(HomeNation::Scotland) => "SCO",
+            FifaMember::HomeNation(HomeNation::Wales) => "WAL",
+            FifaMember::HomeNation(HomeNation::NorthernIreland) => "NIR",
+        })
+    }
+}
+
+impl Country {
+    /// The code of the National Olympic Committee of this country.
+    pub fn ioc_code(&self) -> Option<&'static str> {
+        use Country::*;
+        Some(match *self {
+            AD => "AND",
+            AE => "UAE",
+            AF => "AFG",
+            AG => "ANT",
+            AL => "ALB",
+            AM => "ARM",
+            AO => "ANG",
+            AR => "ARG",
+            AS => "ASA",
+            AT => "AUT",
+            AU => "AUS",
+            AW => "ARU",
+            AZ => "AZE",
+            BA => "BIH",
+            BB => "BAR",
+            BD => "BAN",
+            BE => "BEL",
+            BF => "BUR",
+            BG => "BUL",
+            BH => "BRN",
+            BI => "BDI",
+            BJ => "BEN",
+            BM => "BER",
+            BN => "BRU",
+            BO => "BOL",
+            BR => "BRA",
+            BS => "BAH",
+            BT => "BHU",
+            BW => "BOT",
+            BY => "BLR",
+            BZ => "BIZ",
+            CA => "CAN",
+            CD => "COD",
+            CF => "CAF",
+            CG => "CGO",
+            CH => "SUI",
+            CI => "CIV",
+            CK => "COK",
+            CL => "CHI",
+            CM => "CMR",
+            CN => "CHN",
+            CO => "COL",
+            CR => "CRC",
+            CU => "CUB",
+            CV => "CPV",
+            CY => "CYP",
+            CZ => "CZE",
+            DE => "GER",
+            DJ => "DJI",
+            DK => "DEN",
+            DM => "DMA",
+            DO => "DOM",
+            DZ => "ALG",
+            EC => "ECU",
+            EE => "EST",
+            EG => "EGY",
+            ER => "ERI",
+            ES => "ESP",
+            ET => "ETH",
+            FI => "FIN",
+            FJ => "FIJ",
+            FM => "FSM",
+            FR => "FRA",
+            GA => "GAB",
+            GB => "GBR",
+            GD => "GRN",
+            GE => "GEO",
+            GH => "GHA",
+            GM => "GAM",
+            GN => "GUI",
+            GQ => "GEQ",
+            GR => "GRE",
+            GT => "GUA",
+            GU => "GUM",
+            GW => "GBS",
+            GY => "GUY",
+            HK => "HKG",
+            HN => "HON",
+            HR => "CRO",
+            HT => "HAI",
+            HU => "HUN",
+            ID => "INA",
+            IE => "IRL",
+            IL => "ISR",
+            IN => "IND",
+            IQ => "IRQ",
+            IR => "IRI",
+            IS => "ISL",
+            IT => "ITA",
+            JM => "JAM",
+            JO => "JOR",
+            JP => "JPN",
+            KE => "KEN",
+            KG => "KGZ",
+            KH => "CAM",
+            KI => "KIR",
+            KM => "COM",
+            KN => "SKN",
+            KP => "PRK",
+            KR => "KOR",
+            KW => "KUW",
+            KY => "CAY",
+            KZ => "KAZ",
+            LA => "LAO",
+            LB => "LBN",
+            LC => "LCA",
+            LI => "LIE",
+            LK => "SRI",
+            LR => "LBR",
+            LS => "LES",
+            LT => "LTU",
+            LU => "LUX",
+            LV => "LAT",
+            LY => "LBA",
+            MA => "MAR",
+            MC => "MON",
+            MD => "MDA",
+            ME => "MNE",
+            MG => "MAD",
+            MH => "MHL",
+            MK => "MKD",
+            ML => "MLI",
+            MM => "MYA",
+            MN => "MGL",
+            MR => "MTN",
+            MT => "MLT",
+            MU => "MRI",
+            MV => "MDV",
+            MW => "MAW",
+            MX => "MEX",
+            MY => "MAS",
+            MZ => "MOZ",
+            NA => "NAM",
+            NE => "NIG",
+            NG => "NGR",
+            NI => "NCA",
+            NL => "NED",
+            NO => "NOR",
+            NP => "NEP",
+            NR => "NRU",
+            NZ => "NZL",
+            OM => "OMA",
+            PA => "PAN",
+            PE => "PER",
+            PG => "PNG",
+            PH => "PHI",
+            PK => "PAK",
+            PL => "POL",
+            PR => "PUR",
+            PS => "PLE",
+            PT => "POR",
+            PW => "PLW",
+            PY => "PAR",
+            QA => "QAT",
+            RO => "ROU",
+            RS => "SRB",
+            RU => "RUS",
+            RW => "RWA",
+            SA => "KSA",
+            SB => "SOL",
+            SC => "SEY",
+            SD => "SUD",
+            SE => "SWE",
+            SG => "SGP",
+            SI => "SLO",
+            SK => "SVK",
+            SL => "SLE",
+            SM => "SMR",
+            SN => "SEN",
+            SO => "SOM",
+            SR => "SUR",
+            SS => "SSD",
+            ST => "STP",
+            SV => "ESA",
+            SY => "SYR",
+            SZ => "SWZ",
+            TD => "CHA",
+            TG => "TOG",
+            TH => "THA",
+            TJ => "TJK",
+            TL => "TLS",
+            TM => "TKM",
+            TN => "TUN",
+            TO => "TGA",
+            TR => "TUR",
+            TT => "TTO",
+            TV => "TUV",
+            TW => "TPE",
+            TZ => "TAN",
+            UA => "UKR",
+            UG => "UGA",
+            US => "USA",
+            UY => "URU",
+            UZ => "UZB",
+            VC => "VIN",
+            VE => "VEN",
+            VG => "IVB",
+            VI => "ISV",
+            VN => "VIE",
+            VU => "VAN",
+            WS => "SAM",
+            YE => "YEM",
+            ZA => "RSA",
+            ZM => "ZAM",
+            ZW => "ZIM",
+            _ => return None
+        })
+    }
+
+    pub fn from_ioc_code(s: &str) -> Option<Country> {
+        let s = s.trim();
+        IOC_CODE_SEARCH_TABLE.binary_search_by(|&(o, _)| crate::cmp_ignore_case(o, s)).ok()
+            .map(|i| IOC_CODE_SEARCH_TABLE[i].1)
+    }
+
+    /// The code of the FIFA member association of this country.  `None`
+    /// for the United Kingdom, which is represented by its home nations.
+    pub fn fifa_code(&self) -> Option<&'static str> {
+        use Country::*;
+        Some(match *self {
+            AD => "AND",
+            AE => "UAE",
+            AF => "AFG",
+            AG => "ATG",
+            AI => "AIA",
+            AL => "ALB",
+            AM => "ARM",
+            AO => "ANG",
+            AR => "ARG",
+            AS => "ASA",
+            AT => "AUT",
+            AU => "AUS",
+            AW => "ARU",
+            AZ => "AZE",
+            BA => "BIH",
+            BB => "BRB",
+            BD => "BAN",
+            BE => "BEL",
+            BF => "BFA",
+            BG => "BUL",
+            BH => "BHR",
+            BI => "BDI",
+            BJ => "BEN",
+            BM => "BER",
+            BN => "BRU",
+            BO => "BOL",
+            BR => "BRA",
+            BS => "BAH",
+            BT => "BHU",
+            BW => "BOT",
+            BY => "BLR",
+            BZ => "BLZ",
+            CA => "CAN",
+            CD => "COD",
+            CF => "CTA",
+            CG => "CGO",
+            CH => "SUI",
+            CI => "CIV",
+            CK => "COK",
+            CL => "CHI",
+            CM => "CMR",
+            CN => "CHN",
+            CO => "COL",
+            CR => "CRC",
+            CU => "CUB",
+            CV => "CPV",
+            CW => "CUW",
+            CY => "CYP",
+            CZ => "CZE",
+            DE => "GER",
+            DJ => "DJI",
+            DK => "DEN",
+            DM => "DMA",
+            DO => "DOM",
+            DZ => "ALG",
+            EC => "ECU",
+            EE => "EST",
+            EG => "EGY",
+            ER => "ERI",
+            ES => "ESP",
+            ET => "ETH",
+            FI => "FIN",
+            FJ => "FIJ",
+            FO => "FRO",
+            FR => "FRA",
+            GA => "GAB",
+            GD => "GRN",
+            GE => "GEO",
+            GH => "GHA",
+            GI => "GIB",
+            GM => "GAM",
+            GN => "GUI",
+            GQ => "EQG",
+            GR => "GRE",
+            GT => "GUA",
+            GU => "GUM",
+            GW => "GNB",
+            GY => "GUY",
+            HK => "HKG",
+            HN => "HON",
+            HR => "CRO",
+            HT => "HAI",
+            HU => "HUN",
+            ID => "IDN",
+            IE => "IRL",
+            IL => "ISR",
+            IN => "IND",
+            IQ => "IRQ",
+            IR => "IRN",
+            IS => "ISL",
+            IT => "ITA",
+            JM => "JAM",
+            JO => "JOR",
+            JP => "JPN",
+            KE => "KEN",
+            KG => "KGZ",
+            KH => "CAM",
+            KM => "COM",
+            KN => "SKN",
+            KP => "PRK",
+            KR => "KOR",
+            KW => "KUW",
+            KY => "CAY",
+            KZ => "KAZ",
+            LA => "LAO",
+            LB => "LBN",
+            LC => "LCA",
+            LI => "LIE",
+            LK => "SRI",
+            LR => "LBR",
+            LS => "LES",
+            LT => "LTU",
+            LU => "LUX",
+            LV => "LVA",
+            LY => "LBY",
+            MA => "MAR",
+            MD => "MDA",
+            ME => "MNE",
+            MG => "MAD",
+            MK => "MKD",
+            ML => "MLI",
+            MM => "MYA",
+            MN => "MNG",
+            MO => "MAC",
+            MR => "MTN",
+            MS => "MSR",
+            MT => "MLT",
+            MU => "MRI",
+            MV => "MDV",
+            MW => "MWI",
+            MX => "MEX",
+            MY => "MAS",
+            MZ => "MOZ",
+            NA => "NAM",
+            NC => "NCL",
+            NE => "NIG",
+            NG => "NGA",
+            NI => "NCA",
+            NL => "NED",
+            NO => "NOR",
+            NP => "NEP",
+            NZ => "NZL",
+            OM => "OMA",
+            PA => "PAN",
+            PE => "PER",
+            PF => "TAH",
+            PG => "PNG",
+            PH => "PHI",
+            PK => "PAK",
+            PL => "POL",
+            PR => "PUR",
+            PS => "PLE",
+            PT => "POR",
+            PY => "PAR",
+            QA => "QAT",
+            RO => "ROU",
+            RS => "SRB",
+            RU => "RUS",
+            RW => "RWA",
+            SA => "KSA",
+            SB => "SOL",
+            SC => "SEY",
+            SD => "SDN",
+            SE => "SWE",
+            SG => "SIN",
+            SI => "SVN",
+            SK => "SVK",
+            SL => "SLE",
+            SM => "SMR",
+            SN => "SEN",
+            SO => "SOM",
+            SR => "SUR",
+            SS => "SSD",
+            ST => "STP",
+            SV => "SLV",
+            SY => "SYR",
+            SZ => "SWZ",
+            TC => "TCA",
+            TD => "CHA",
+            TG => "TOG",
+            TH => "THA",
+            TJ => "TJK",
+            TL => "TLS",
+            TM => "TKM",
+            TN => "TUN",
+            TO => "TGA",
+            TR => "TUR",
+            TT => "TRI",
+            TW => "TPE",
+            TZ => "TAN",
+            UA => "UKR",
+            UG => "UGA",
+            US => "USA",
+            UY => "URU",
+            UZ => "UZB",
+            VC => "VIN",
+            VE => "VEN",
+            VG => "VGB",
+            VI => "VIR",
+            VN => "VIE",
+            VU => "VAN",
+            WS => "SAM",
+            YE => "YEM",
+            ZA => "RSA",
+            ZM => "ZAM",
+            ZW => "ZIM",
+            _ => return None
+        })
+    }
+
+    pub fn from_fifa_code(s: &str) -> Option<FifaMember> {
+        let s = s.trim();
+        FIFA_CODE_SEARCH_TABLE.binary_search_by(|&(o, _)| crate::cmp_ignore_case(o, s)).ok()
+            .map(|i| FIFA_CODE_SEARCH_TABLE[i].1)
+    }
+}
+
+const IOC_CODE_SEARCH_TABLE : &[(&str, Country)] = &[
+    ("AFG", Country::AF),
+    ("ALB", Country::AL),
+    ("ALG", Country::DZ),
+    ("AND", Country::AD),
+    ("ANG", Country::AO),
+    ("ANT", Country::AG),
+    ("ARG", Country::AR),
+    ("ARM", Country::AM),
+    ("ARU", Country::AW),
+    ("ASA", Country::AS),
+    ("AUS", Country::AU),
+    ("AUT", Country::AT),
+    ("AZE", Country::AZ),
+    ("BAH", Country::BS),
+    ("BAN", Country::BD),
+    ("BAR", Country::BB),
+    ("BDI", Country::BI),
+    ("BEL", Country::BE),
+    ("BEN", Country::BJ),
+    ("BER", Country::BM),
+    ("BHU", Country::BT),
+    ("BIH", Country::BA),
+    ("BIZ", Country::BZ),
+    ("BLR", Country::BY),
+    ("BOL", Country::BO),
+    ("BOT", Country::BW),
+    ("BRA", Country::BR),
+    ("BRN", Country::BH),
+    ("BRU", Country::BN),
+    ("BUL", Country::BG),
+    ("BUR", Country::BF),
+    ("CAF", Country::CF),
+    ("CAM", Country::KH),
+    ("CAN", Country::CA),
+    ("CAY", Country::KY),
+    ("CGO", Country::CG),
+    ("CHA", Country::TD),
+    ("CHI", Country::CL),
+    ("CHN", Country::CN),
+    ("CIV", Country::CI),
+    ("CMR", Country::CM),
+    ("COD", Country::CD),
+    ("COK", Country::CK),
+    ("COL", Country::CO),
+    ("COM", Country::KM),
+    ("CPV", Country::CV),
+    ("CRC", Country::CR),
+    ("CRO", Country::HR),
+    ("CUB", Country::CU),
+    ("CYP", Country::CY),
+    ("CZE", Country::CZ),
+    ("DEN", Country::DK),
+    ("DJI", Country::DJ),
+    ("DMA", Country::DM),
+    ("DOM", Country::DO),
+    ("ECU", Country::EC),
+    ("EGY", Country::EG),
+    ("ERI", Country::ER),
+    ("ESA", Country::SV),
+    ("ESP", Country::ES),
+    ("EST", Country::EE),
+    ("ETH", Country::ET),
+    ("FIJ", Country::FJ),
+    ("FIN", Country::FI),
+    ("FRA", Country::FR),
+    ("FSM", Country::FM),
+    ("GAB", Country::GA),
+    ("GAM", Country::GM),
+    ("GBR", Country::GB),
+    ("GBS", Country::GW),
+    ("GEO", Country::GE),
+    ("GEQ", Country::GQ),
+    ("GER", Country::DE),
+    ("GHA", Country::GH),
+    ("GRE", Country::GR),
+    ("GRN", Country::GD),
+    ("GUA", Country::GT),
+    ("GUI", Country::GN),
+    ("GUM", Country::GU),
+    ("GUY", Country::GY),
+    ("HAI", Country::HT),
+    ("HKG", Country::HK),
+    ("HON", Country::HN),
+    ("HUN", Country::HU),
+    ("INA", Country::ID),
+    ("IND", Country::IN),
+    ("IRI", Country::IR),
+    ("IRL", Country::IE),
+    ("IRQ", Country::IQ),
+    ("ISL", Country::IS),
+    ("ISR", Country::IL),
+    ("ISV", Country::VI),
+    ("ITA", Country::IT),
+    ("IVB", Country::VG),
+    ("JAM", Country::JM),
+    ("JOR", Country::JO),
+    ("JPN", Country::JP),
+    ("KAZ", Country::KZ),
+    ("KEN", Country::KE),
+    ("KGZ", Country::KG),
+    ("KIR", Country::KI),
+    ("KOR", Country::KR),
+    ("KSA", Country::SA),
+    ("KUW", Country::KW),
+    ("LAO", Country::LA),
+    ("LAT", Country::LV),
+    ("LBA", Country::LY),
+    ("LBN", Country::LB),
+    ("LBR", Country::LR),
+    ("LCA", Country::LC),
+    ("LES", Country::LS),
+    ("LIE", Country::LI),
+    ("LTU", Country::LT),
+    ("LUX", Country::LU),
+    ("MAD", Country::MG),
+    ("MAR", Country::MA),
+    ("MAS", Country::MY),
+    ("MAW", Country::MW),
+    ("MDA", Country::MD),
+    ("MDV", Country::MV),
+    ("MEX", Country::MX),
+    ("MGL", Country::MN),
+    ("MHL", Country::MH),
+    ("MKD", Country::MK),
+    ("MLI", Country::ML),
+    ("MLT", Country::MT),
+    ("MNE", Country::ME),
+    ("MON", Country::MC),
+    ("MOZ", Country::MZ),
+    ("MRI", Country::MU),
+    ("MTN", Country::MR),
+    ("MYA", Country::MM),
+    ("NAM", Country::NA),
+    ("NCA", Country::NI),
+    ("NED", Country::NL),
+    ("NEP", Country::NP),
+    ("NGR", Country::NG),
+    ("NIG", Country::NE),
+    ("NOR", Country::NO),
+    ("NRU", Country::NR),
+    ("NZL", Country::NZ),
+    ("OMA", Country::OM),
+    ("PAK", Country::PK),
+    ("PAN", Country::PA),
+    ("PAR", Country::PY),
+    ("PER", Country::PE),
+    ("PHI", Country::PH),
+    ("PLE", Country::PS),
+    ("PLW", Country::PW),
+    ("PNG", Country::PG),
+    ("POL", Country::PL),
+    ("POR", Country::PT),
+    ("PRK", Country::KP),
+    ("PUR", Country::PR),
+    ("QAT", Country::QA),
+    ("ROU", Country::RO),
+    ("RSA", Country::ZA),
+    ("RUS", Country::RU),
+    ("RWA", Country::RW),
+    ("SAM", Country::WS),
+    ("SEN", Country::SN),
+    ("SEY", Country::SC),
+    ("SGP", Country::SG),
+    ("SKN", Country::KN),
+    ("SLE", Country::SL),
+    ("SLO", Country::SI),
+    ("SMR", Country::SM),
+    ("SOL", Country::SB),
+    ("SOM", Country::SO),
+    ("SRB", Country::RS),
+    ("SRI", Country::LK),
+    ("SSD", Country::SS),
+    ("STP", Country::ST),
+    ("SUD", Country::SD),
+    ("SUI", Country::CH),
+    ("SUR", Country::SR),
+    ("SVK", Country::SK),
+    ("SWE", Country::SE),
+    ("SWZ", Country::SZ),
+    ("SYR", Country::SY),
+    ("TAN", Country::TZ),
+    ("TGA", Country::TO),
+    ("THA", Country::TH),
+    ("TJK", Country::TJ),
+    ("TKM", Country::TM),
+    ("TLS", Country::TL),
+    ("TOG", Country::TG),
+    ("TPE", Country::TW),
+    ("TTO", Country::TT),
+    ("TUN", Country::TN),
+    ("TUR", Country::TR),
+    ("TUV", Country::TV),
+    ("UAE", Country::AE),
+    ("UGA", Country::UG),
+    ("UKR", Country::UA),
+    ("URU", Country::UY),
+    ("USA", Country::US),
+    ("UZB", Country::UZ),
+    ("VAN", Country::VU),
+    ("VEN", Country::VE),
+    ("VIE", Country::VN),
+    ("VIN", Country::VC),
+    ("YEM", Country::YE),
+    ("ZAM", Country::ZM),
+    ("ZIM", Country::ZW),
+];
+
+const FIFA_CODE_SEARCH_TABLE : &[(&str, FifaMember)] = &[
+    ("AFG", FifaMember::Country(Country::AF)),
+    ("AIA", FifaMember::Country(Country::AI)),
+    ("ALB", FifaMember::Country(Country::AL)),
+    ("ALG", FifaMember::Country(Country::DZ)),
+    ("AND", FifaMember::Country(Country::AD)),
+    ("ANG", FifaMember::Country(Country::AO)),
+    ("ARG", FifaMember::Country(Country::AR)),
+    ("ARM", FifaMember::Country(Country::AM)),
+    ("ARU", FifaMember::Country(Country::AW)),
+    ("ASA", FifaMember::Country(Country::AS)),
+    ("ATG", FifaMember::Country(Country::AG)),
+    ("AUS", FifaMember::Country(Country::AU)),
+    ("AUT", FifaMember::Country(Country::AT)),
+    ("AZE", FifaMember::Country(Country::AZ)),
+    ("BAH", FifaMember::Country(Country::BS)),
+    ("BAN", FifaMember::Country(Country::BD)),
+    ("BDI", FifaMember::Country(Country::BI)),
+    ("BEL", FifaMember::Country(Country::BE)),
+    ("BEN", FifaMember::Country(Country::BJ)),
+    ("BER", FifaMember::Country(Country::BM)),
+    ("BFA", FifaMember::Country(Country::BF)),
+    ("BHR", FifaMember::Country(Country::BH)),
+    ("BHU", FifaMember::Country(Country::BT)),
+    ("BIH", FifaMember::Country(Country::BA)),
+    ("BLR", FifaMember::Country(Country::BY)),
+    ("BLZ", FifaMember::Country(Country::BZ)),
+    ("BOL", FifaMember::Country(Country::BO)),
+    ("BOT", FifaMember::Country(Country::BW)),
+    ("BRA", FifaMember::Country(Country::BR)),
+    ("BRB", FifaMember::Country(Country::BB)),
+    ("BRU", FifaMember::Country(Country::BN)),
+    ("BUL", FifaMember::Country(Country::BG)),
+    ("CAM", FifaMember::Country(Country::KH)),
+    ("CAN", FifaMember::Country(Country::CA)),
+    ("CAY", FifaMember::Country(Country::KY)),
+    ("CGO", FifaMember::Country(Country::CG)),
+    ("CHA", FifaMember::Country(Country::TD)),
+    ("CHI", FifaMember::Country(Country::CL)),
+    ("CHN", FifaMember::Country(Country::CN)),
+    ("CIV", FifaMember::Country(Country::CI)),
+    ("CMR", FifaMember::Country(Country::CM)),
+    ("COD", FifaMember::Country(Country::CD)),
+    ("COK", FifaMember::Country(Country::CK)),
+    ("COL", FifaMember::Country(Country::CO)),
+    ("COM", FifaMember::Country(Country::KM)),
+    ("CPV", FifaMember::Country(Country::CV)),
+    ("CRC", FifaMember::Country(Country::CR)),
+    ("CRO", FifaMember::Country(Country::HR)),
+    ("CTA", FifaMember::Country(Country::CF)),
+    ("CUB", FifaMember::Country(Country::CU)),
+    ("CUW", FifaMember::Country(Country::CW)),
+    ("CYP", FifaMember::Country(Country::CY)),
+    ("CZE", FifaMember::Country(Country::CZ)),
+    ("DEN", FifaMember::Country(Country::DK)),
+    ("DJI", FifaMember::Country(Country::DJ)),
+    ("DMA", FifaMember::Country(Country::DM)),
+    ("DOM", FifaMember::Country(Country::DO)),
+    ("ECU", FifaMember::Country(Country::EC)),
+    ("EGY", FifaMember::Country(Country::EG)),
+    ("ENG", FifaMember::HomeNation(HomeNation::England)),
+    ("EQG", FifaMember::Country(Country::GQ)),
+    ("ERI", FifaMember::Country(Country::ER)),
+    ("ESP", FifaMember::Country(Country::ES)),
+    ("EST", FifaMember::Country(Country::EE)),
+    ("ETH", FifaMember::Country(Country::ET)),
+    ("FIJ", FifaMember::Country(Country::FJ)),
+    ("FIN", FifaMember::Country(Country::FI)),
+    ("FRA", FifaMember::Country(Country::FR)),
+    ("FRO", FifaMember::Country(Country::FO)),
+    ("GAB", FifaMember::Country(Country::GA)),
+    ("GAM", FifaMember::Country(Country::GM)),
+    ("GEO", FifaMember::Country(Country::GE)),
+    ("GER", FifaMember::Country(Country::DE)),
+    ("GHA", FifaMember::Country(Country::GH)),
+    ("GIB", FifaMember::Country(Country::GI)),
+    ("GNB", FifaMember::Country(Country::GW)),
+    ("GRE", FifaMember::Country(Country::GR)),
+    ("GRN", FifaMember::Country(Country::GD)),
+    ("GUA", FifaMember::Country(Country::GT)),
+    ("GUI", FifaMember::Country(Country::GN)),
+    ("GUM", FifaMember::Country(Country::GU)),
+    ("GUY", FifaMember::Country(Country::GY)),
+    ("HAI", FifaMember::Country(Country::HT)),
+    ("HKG", FifaMember::Country(Country::HK)),
+    ("HON", FifaMember::Country(Country::HN)),
+    ("HUN", FifaMember::Country(Country::HU)),
+    ("IDN", FifaMember::Country(Country::ID)),
+    ("IND", FifaMember::Country(Country::IN)),
+    ("IRL", FifaMember::Country(Country::IE)),
+    ("IRN", FifaMember::Country(Country::IR)),
+    ("IRQ", FifaMember::Country(Country::IQ)),
+    ("ISL", FifaMember::Country(Country::IS)),
+    ("ISR", FifaMember::Country(Country::IL)),
+    ("ITA", FifaMember::Country(Country::IT)),
+    ("JAM", FifaMember::Country(Country::JM)),
+    ("JOR", FifaMember::Country(Country::JO)),
+    ("JPN", FifaMember::Country(Country::JP)),
+    ("KAZ", FifaMember::Country(Country::KZ)),
+    ("KEN", FifaMember::Country(Country::KE)),
+    ("KGZ", FifaMember::Country(Country::KG)),
+    ("KOR", FifaMember::Country(Country::KR)),
+    ("KSA", FifaMember::Country(Country::SA)),
+    ("KUW", FifaMember::Country(Country::KW)),
+    ("LAO", FifaMember::Country(Country::LA)),
+    ("LBN", FifaMember::Country(Country::LB)),
+    ("LBR", FifaMember::Country(Country::LR)),
+    ("LBY", FifaMember::Country(Country::LY)),
+    ("LCA", FifaMember::Country(Country::LC)),
+    ("LES", FifaMember::Country(Country::LS)),
+    ("LIE", FifaMember::Country(Country::LI)),
+    ("LTU", FifaMember::Country(Country::LT)),
+    ("LUX", FifaMember::Country(Country::LU)),
+    ("LVA", FifaMember::Country(Country::LV)),
+    ("MAC", FifaMember::Country(Country::MO)),
+    ("MAD", FifaMember::Country(Country::MG)),
+    ("MAR", FifaMember::Country(Country::MA)),
+    ("MAS", FifaMember::Country(Country::MY)),
+    ("MDA", FifaMember::Country(Country::MD)),
+    ("MDV", FifaMember::Country(Country::MV)),
+    ("MEX", FifaMember::Country(Country::MX)),
+    ("MKD", FifaMember::Country(Country::MK)),
+    ("MLI", FifaMember::Country(Country::ML)),
+    ("MLT", FifaMember::Country(Country::MT)),
+    ("MNE", FifaMember::Country(Country::ME)),
+    ("MNG", FifaMember::Country(Country::MN)),
+    ("MOZ", FifaMember::Country(Country::MZ)),
+    ("MRI", FifaMember::Country(Country::MU)),
+    ("MSR", FifaMember::Country(Country::MS)),
+    ("MTN", FifaMember::Country(Country::MR)),
+    ("MWI", FifaMember::Country(Country::MW)),
+    ("MYA", FifaMember::Country(Country::MM)),
+    ("NAM", FifaMember::Country(Country::NA)),
+    ("NCA", FifaMember::Country(Country::NI)),
+    ("NCL", FifaMember::Country(Country::NC)),
+    ("NED", FifaMember::Country(Country::NL)),
+    ("NEP", FifaMember::Country(Country::NP)),
+    ("NGA", FifaMember::Country(Country::NG)),
+    ("NIG", FifaMember::Country(Country::NE)),
+    ("NIR", FifaMember::HomeNation(HomeNation::NorthernIreland)),
+    ("NOR", FifaMember::Country(Country::NO)),
+    ("NZL", FifaMember::Country(Country::NZ)),
+    ("OMA", FifaMember::Country(Country::OM)),
+    ("PAK", FifaMember::Country(Country::PK)),
+    ("PAN", FifaMember::Country(Country::PA)),
+    ("PAR", FifaMember::Country(Country::PY)),
+    ("PER", FifaMember::Country(Country::PE)),
+    ("PHI", FifaMember::Country(Country::PH)),
+    ("PLE", FifaMember::Country(Country::PS)),
+    ("PNG", FifaMember::Country(Country::PG)),
+    ("POL", FifaMember::Country(Country::PL)),
+    ("POR", FifaMember::Country(Country::PT)),
+    ("PRK", FifaMember::Country(Country::KP)),
+    ("PUR", FifaMember::Country(Country::PR)),
+    ("QAT", FifaMember::Country(Country::QA)),
+    ("ROU", FifaMember::Country(Country::RO)),
+    ("RSA", FifaMember::Country(Country::ZA)),
+    ("RUS", FifaMember::Country(Country::RU)),
+    ("RWA", FifaMember::Country(Country::RW)),
+    ("SAM", FifaMember::Country(Country::WS)),
+    ("SCO", FifaMember::HomeNation(HomeNation::Scotland)),
+    ("SDN", FifaMember::Country(Country::SD)),
+    ("SEN", FifaMember::Country(Country::SN)),
+    ("SEY", FifaMember::Country(Country::SC)),
+    ("SIN", FifaMember::Country(Country::SG)),
+    ("SKN", FifaMember::Country(Country::KN)),
+    ("SLE", FifaMember::Country(Country::SL)),
+    ("SLV", FifaMember::Country(Country::SV)),
+    ("SMR", FifaMember::Country(Country::SM)),
+    ("SOL", FifaMember::Country(Country::SB)),
+    ("SOM", FifaMember::Country(Country::SO)),
+    ("SRB", FifaMember::Country(Country::RS)),
+    ("SRI", FifaMember::Country(Country::LK)),
+    ("SSD", FifaMember::Country(Country::SS)),
+    ("STP", FifaMember::Country(Country::ST)),
+    ("SUI", FifaMember::Country(Country::CH)),
+    ("SUR", FifaMember::Country(Country::SR)),
+    ("SVK", FifaMember::Country(Country::SK)),
+    ("SVN", FifaMember::Country(Country::SI)),
+    ("SWE", FifaMember::Country(Country::SE)),
+    ("SWZ", FifaMember::Country(Country::SZ)),
+    ("SYR", FifaMember::Country(Country::SY)),
+    ("TAH", FifaMember::Country(Country::PF)),
+    ("TAN", FifaMember::Country(Country::TZ)),
+    ("TCA", FifaMember::Country(Country::TC)),
+    ("TGA", FifaMember::Country(Country::TO)),
+    ("THA", FifaMember::Country(Country::TH)),
+    ("TJK", FifaMember::Country(Country::TJ)),
+    ("TKM", FifaMember::Country(Country::TM)),
+    ("TLS", FifaMember::Country(Country::TL)),
+    ("TOG", FifaMember::Country(Country::TG)),
+    ("TPE", FifaMember::Country(Country::TW)),
+    ("TRI", FifaMember::Country(Country::TT)),
+    ("TUN", FifaMember::Country(Country::TN)),
+    ("TUR", FifaMember::Country(Country::TR)),
+    ("UAE", FifaMember::Country(Country::AE)),
+    ("UGA", FifaMember::Country(Country::UG)),
+    ("UKR", FifaMember::Country(Country::UA)),
+    ("URU", FifaMember::Country(Country::UY)),
+    ("USA", FifaMember::Country(Country::US)),
+    ("UZB", FifaMember::Country(Country::UZ)),
+    ("VAN", FifaMember::Country(Country::VU)),
+    ("VEN", FifaMember::Country(Country::VE)),
+    ("VGB", FifaMember::Country(Country::VG)),
+    ("VIE", FifaMember::Country(Country::VN)),
+    ("VIN", FifaMember::Country(Country::VC)),
+    ("VIR", FifaMember::Country(Country::VI)),
+    ("WAL", FifaMember::HomeNation(HomeNation::Wales)),
+    ("YEM", FifaMember::Country(Country::YE)),
+    ("ZAM", FifaMember::Country(Country::ZM)),
+    ("ZIM", FifaMember::Country(Country::ZW)),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ FifaMember, HomeNation };
+
+    #[test]
+    fn codes() {
+        assert_eq!(Some("GER"), Country::DE.ioc_code());
+        assert_eq!(Some("NED"), Country::NL.ioc_code());
+        assert_eq!(Some("SUI"), Country::CH.fifa_code());
+        assert_eq!(Some("POR"), Country::PT.fifa_code());
+        assert_eq!(Some("IRI"), Country::IR.ioc_code());
+        assert_eq!(Some("IRN"), Country::IR.fifa_code());
+        assert_eq!(Some("GBR"), Country::GB.ioc_code());
+        assert_eq!(None, Country::GB.fifa_code());
+        assert_eq!(None, Country::AQ.ioc_code());
+    }
+
+    #[test]
+    fn reverse() {
+        assert_eq!(Some(Country::DE), Country::from_ioc_code("GER"));
+        assert_eq!(Some(Country::CH), Country::from_ioc_code("sui"));
+        assert_eq!(None, Country::from_ioc_code("ENG"));
+        assert_eq!(Some(FifaMember::Country(Country::NL)), Country::from_fifa_code("NED"));
+        let england = Country::from_fifa_code("ENG").unwrap();
+        assert_eq!(FifaMember::HomeNation(HomeNation::England), england);
+        assert_eq!(Country::GB, england.country());
+        assert_eq!(Some("ENG"), england.fifa_code());
+        assert_eq!(None, FifaMember::Country(Country::GB).fifa_code());
+        let northern_ireland = Country::from_fifa_code("NIR").unwrap();
+        assert_eq!(FifaMember::HomeNation(HomeNation::NorthernIreland), northern_ireland);
+        assert_eq!("GB-NIR", HomeNation::NorthernIreland.subdivision_code());
+    }
+
+    #[test]
+    fn search_tables_match_codes() {
+        assert!(super::IOC_CODE_SEARCH_TABLE.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(super::FIFA_CODE_SEARCH_TABLE.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(code, member) in super::FIFA_CODE_SEARCH_TABLE {
+            assert_eq!(Some(code), member.fifa_code());
+        }
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            if let Some(code) = country.ioc_code() {
+                assert_eq!(Some(country), Country::from_ioc_code(code));
+            }
+        }
+    }
+}
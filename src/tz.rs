@@ -0,0 +1,951 @@
+// IANA time zones, generated from tzdata 2025b.
+//
+// Zones per country come from zone.tab, which lists each country's own
+// zones.  `Country::from_time_zone` also knows the zone1970.tab sharing,
+// where one zone covers several countries that have agreed on clocks since
+// 1970, and the link names of the backward file (e.g. "Asia/Calcutta").
+
+use crate::Country;
+
+impl Country {
+    /// The IANA time zones of this country.  The zone of the capital or the
+    /// most populous area comes first, the others follow in zone.tab order.
+    pub fn time_zones(&self) -> &'static [&'static str] {
+        use Country::*;
+        match *self {
+            Unspecified => &[],
+            AD => &["Europe/Andorra"],
+            AE => &["Asia/Dubai"],
+            AF => &["Asia/Kabul"],
+            AG => &["America/Antigua"],
+            AI => &["America/Anguilla"],
+            AL => &["Europe/Tirane"],
+            AM => &["Asia/Yerevan"],
+            AO => &["Africa/Luanda"],
+            AQ => &[
+                "Antarctica/McMurdo", "Antarctica/Casey", "Antarctica/Davis",
+                "Antarctica/DumontDUrville", "Antarctica/Mawson", "Antarctica/Palmer",
+                "Antarctica/Rothera", "Antarctica/Syowa", "Antarctica/Troll", "Antarctica/Vostok",
+            ],
+            AR => &[
+                "America/Argentina/Buenos_Aires", "America/Argentina/Cordoba",
+                "America/Argentina/Salta", "America/Argentina/Jujuy", "America/Argentina/Tucuman",
+                "America/Argentina/Catamarca", "America/Argentina/La_Rioja",
+                "America/Argentina/San_Juan", "America/Argentina/Mendoza",
+                "America/Argentina/San_Luis", "America/Argentina/Rio_Gallegos",
+                "America/Argentina/Ushuaia",
+            ],
+            AS => &["Pacific/Pago_Pago"],
+            AT => &["Europe/Vienna"],
+            AU => &[
+                "Australia/Sydney", "Australia/Lord_Howe", "Antarctica/Macquarie",
+                "Australia/Hobart", "Australia/Melbourne", "Australia/Broken_Hill",
+                "Australia/Brisbane", "Australia/Lindeman", "Australia/Adelaide",
+                "Australia/Darwin", "Australia/Perth", "Australia/Eucla",
+            ],
+            AW => &["America/Aruba"],
+            AX => &["Europe/Mariehamn"],
+            AZ => &["Asia/Baku"],
+            BA => &["Europe/Sarajevo"],
+            BB => &["America/Barbados"],
+            BD => &["Asia/Dhaka"],
+            BE => &["Europe/Brussels"],
+            BF => &["Africa/Ouagadougou"],
+            BG => &["Europe/Sofia"],
+            BH => &["Asia/Bahrain"],
+            BI => &["Africa/Bujumbura"],
+            BJ => &["Africa/Porto-Novo"],
+            BL => &["America/St_Barthelemy"],
+            BM => &["Atlantic/Bermuda"],
+            BN => &["Asia/Brunei"],
+            BO => &["America/La_Paz"],
+            BQ => &["America/Kralendijk"],
+            BR => &[
+                "America/Sao_Paulo", "America/Noronha", "America/Belem", "America/Fortaleza",
+                "America/Recife", "America/Araguaina", "America/Maceio", "America/Bahia",
+                "America/Campo_Grande", "America/Cuiaba", "America/Santarem",
+                "America/Porto_Velho", "America/Boa_Vista", "America/Manaus", "America/Eirunepe",
+                "America/Rio_Branco",
+            ],
+            BS => &["America/Nassau"],
+            BT => &["Asia/Thimphu"],
+            BV => &[],
+            BW => &["Africa/Gaborone"],
+            BY => &["Europe/Minsk"],
+            BZ => &["America/Belize"],
+            CA => &[
+                "America/Toronto", "America/St_Johns", "America/Halifax", "America/Glace_Bay",
+                "America/Moncton", "America/Goose_Bay", "America/Blanc-Sablon", "America/Iqaluit",
+                "America/Atikokan", "America/Winnipeg", "America/Resolute", "America/Rankin_Inlet",
+                "America/Regina", "America/Swift_Current", "America/Edmonton",
+                "America/Cambridge_Bay", "America/Inuvik", "America/Creston",
+                "America/Dawson_Creek", "America/Fort_Nelson", "America/Whitehorse",
+                "America/Dawson", "America/Vancouver",
+            ],
+            CC => &["Indian/Cocos"],
+            CD => &["Africa/Kinshasa", "Africa/Lubumbashi"],
+            CF => &["Africa/Bangui"],
+            CG => &["Africa/Brazzaville"],
+            CH => &["Europe/Zurich"],
+            CI => &["Africa/Abidjan"],
+            CK => &["Pacific/Rarotonga"],
+            CL => &[
+                "America/Santiago", "America/Coyhaique", "America/Punta_Arenas", "Pacific/Easter",
+            ],
+            CM => &["Africa/Douala"],
+            CN => &["Asia/Shanghai", "Asia/Urumqi"],
+            CO => &["America/Bogota"],
+            CR => &["America/Costa_Rica"],
+            CU => &["America/Havana"],
+            CV => &["Atlantic/Cape_Verde"],
+            CW => &["America/Curacao"],
+            CX => &["Indian/Christmas"],
+            CY => &["Asia/Nicosia", "Asia/Famagusta"],
+            CZ => &["Europe/Prague"],
+            DE => &["Europe/Berlin", "Europe/Busingen"],
+            DJ => &["Africa/Djibouti"],
+            DK => &["Europe/Copenhagen"],
+            DM => &["America/Dominica"],
+            DO => &["America/Santo_Domingo"],
+            DZ => &["Africa/Algiers"],
+            EC => &["America/Guayaquil", "Pacific/Galapagos"],
+            EE => &["Europe/Tallinn"],
+            EG => &["Africa/Cairo"],
+            EH => &["Africa/El_Aaiun"],
+            ER => &["Africa/Asmara"],
+            ES => &["Europe/Madrid", "Africa/Ceuta", "Atlantic/Canary"],
+            ET => &["Africa/Addis_Ababa"],
+            FI => &["Europe/Helsinki"],
+            FJ => &["Pacific/Fiji"],
+            FK => &["Atlantic/Stanley"],
+            FM => &["Pacific/Pohnpei", "Pacific/Chuuk", "Pacific/Kosrae"],
+            FO => &["Atlantic/Faroe"],
+            FR => &["Europe/Paris"],
+            GA => &["Africa/Libreville"],
+            GB => &["Europe/London"],
+            GD => &["America/Grenada"],
+            GE => &["Asia/Tbilisi"],
+            GF => &["America/Cayenne"],
+            GG => &["Europe/Guernsey"],
+            GH => &["Africa/Accra"],
+            GI => &["Europe/Gibraltar"],
+            GL => &[
+                "America/Nuuk", "America/Danmarkshavn", "America/Scoresbysund", "America/Thule",
+            ],
+            GM => &["Africa/Banjul"],
+            GN => &["Africa/Conakry"],
+            GP => &["America/Guadeloupe"],
+            GQ => &["Africa/Malabo"],
+            GR => &["Europe/Athens"],
+            GS => &["Atlantic/South_Georgia"],
+            GT => &["America/Guatemala"],
+            GU => &["Pacific/Guam"],
+            GW => &["Africa/Bissau"],
+            GY => &["America/Guyana"],
+            HK => &["Asia/Hong_Kong"],
+            HM => &[],
+            HN => &["America/Tegucigalpa"],
+            HR => &["Europe/Zagreb"],
+            HT => &["America/Port-au-Prince"],
+            HU => &["Europe/Budapest"],
+            ID => &["Asia/Jakarta", "Asia/Pontianak", "Asia/Makassar", "Asia/Jayapura"],
+            IE => &["Europe/Dublin"],
+            IL => &["Asia/Jerusalem"],
+            IM => &["Europe/Isle_of_Man"],
+            IN => &["Asia/Kolkata"],
+            IO => &["Indian/Chagos"],
+            IQ => &["Asia/Baghdad"],
+            IR => &["Asia/Tehran"],
+            IS => &["Atlantic/Reykjavik"],
+            IT => &["Europe/Rome"],
+            JE => &["Europe/Jersey"],
+            JM => &["America/Jamaica"],
+            JO => &["Asia/Amman"],
+            JP => &["Asia/Tokyo"],
+            KE => &["Africa/Nairobi"],
+            KG => &["Asia/Bishkek"],
+            KH => &["Asia/Phnom_Penh"],
+            KI => &["Pacific/Tarawa", "Pacific/Kanton", "Pacific/Kiritimati"],
+            KM => &["Indian/Comoro"],
+            KN => &["America/St_Kitts"],
+            KP => &["Asia/Pyongyang"],
+            KR => &["Asia/Seoul"],
+            KW => &["Asia/Kuwait"],
+            KY => &["America/Cayman"],
+            KZ => &[
+                "Asia/Almaty", "Asia/Qyzylorda", "Asia/Qostanay", "Asia/Aqtobe", "Asia/Aqtau",
+                "Asia/Atyrau", "Asia/Oral",
+            ],
+            LA => &["Asia/Vientiane"],
+            LB => &["Asia/Beirut"],
+            LC => &["America/St_Lucia"],
+            LI => &["Europe/Vaduz"],
+            LK => &["Asia/Colombo"],
+            LR => &["Africa/Monrovia"],
+            LS => &["Africa/Maseru"],
+            LT => &["Europe/Vilnius"],
+            LU => &["Europe/Luxembourg"],
+            LV => &["Europe/Riga"],
+            LY => &["Africa/Tripoli"],
+            MA => &["Africa/Casablanca"],
+            MC => &["Europe/Monaco"],
+            MD => &["Europe/Chisinau"],
+            ME => &["Europe/Podgorica"],
+            MF => &["America/Marigot"],
+            MG => &["Indian/Antananarivo"],
+            MH => &["Pacific/Majuro", "Pacific/Kwajalein"],
+            MK => &["Europe/Skopje"],
+            ML => &["Africa/Bamako"],
+            MM => &["Asia/Yangon"],
+            MN => &["Asia/Ulaanbaatar", "Asia/Hovd"],
+            MO => &["Asia/Macau"],
+            MP => &["Pacific/Saipan"],
+            MQ => &["America/Martinique"],
+            MR => &["Africa/Nouakchott"],
+            MS => &["America/Montserrat"],
+            MT => &["Europe/Malta"],
+            MU => &["Indian/Mauritius"],
+            MV => &["Indian/Maldives"],
+            MW => &["Africa/Blantyre"],
+            MX => &[
+                "America/Mexico_City", "America/Cancun", "America/Merida", "America/Monterrey",
+                "America/Matamoros", "America/Chihuahua", "America/Ciudad_Juarez",
+                "America/Ojinaga", "America/Mazatlan", "America/Bahia_Banderas",
+                "America/Hermosillo", "America/Tijuana",
+            ],
+            MY => &["Asia/Kuala_Lumpur", "Asia/Kuching"],
+            MZ => &["Africa/Maputo"],
+            NA => &["Africa/Windhoek"],
+            NC => &["Pacific/Noumea"],
+            NE => &["Africa/Niamey"],
+            NF => &["Pacific/Norfolk"],
+            NG => &["Africa/Lagos"],
+            NI => &["America/Managua"],
+            NL => &["Europe/Amsterdam"],
+            NO => &["Europe/Oslo"],
+            NP => &["Asia/Kathmandu"],
+            NR => &["Pacific/Nauru"],
+            NU => &["Pacific/Niue"],
+            NZ => &["Pacific/Auckland", "Pacific/Chatham"],
+            OM => &["Asia/Muscat"],
+            PA => &["America/Panama"],
+            PE => &["America/Lima"],
+            PF => &["Pacific/Tahiti", "Pacific/Marquesas", "Pacific/Gambier"],
+            PG => &["Pacific/Port_Moresby", "Pacific/Bougainville"],
+            PH => &["Asia/Manila"],
+            PK => &["Asia/Karachi"],
+            PL => &["Europe/Warsaw"],
+            PM => &["America/Miquelon"],
+            PN => &["Pacific/Pitcairn"],
+            PR => &["America/Puerto_Rico"],
+            PS => &["Asia/Gaza", "Asia/Hebron"],
+            PT => &["Europe/Lisbon", "Atlantic/Madeira", "Atlantic/Azores"],
+            PW => &["Pacific/Palau"],
+            PY => &["America/Asuncion"],
+            QA => &["Asia/Qatar"],
+            RE => &["Indian/Reunion"],
+            RO => &["Europe/Bucharest"],
+            RS => &["Europe/Belgrade"],
+            RU => &[
+                "Europe/Moscow", "Europe/Kaliningrad", "Europe/Kirov", "Europe/Volgograd",
+                "Europe/Astrakhan", "Europe/Saratov", "Europe/Ulyanovsk", "Europe/Samara",
+                "Asia/Yekaterinburg", "Asia/Omsk", "Asia/Novosibirsk", "Asia/Barnaul",
+                "Asia/Tomsk", "Asia/Novokuznetsk", "Asia/Krasnoyarsk", "Asia/Irkutsk",
+                "Asia/Chita", "Asia/Yakutsk", "Asia/Khandyga", "Asia/Vladivostok", "Asia/Ust-Nera",
+                "Asia/Magadan", "Asia/Sakhalin", "Asia/Srednekolymsk", "Asia/Kamchatka",
+                "Asia/Anadyr",
+            ],
+            RW => &["Africa/Kigali"],
+            SA => &["Asia/Riyadh"],
+            SB => &["Pacific/Guadalcanal"],
+            SC => &["Indian/Mahe"],
+            SD => &["Africa/Khartoum"],
+            SE => &["Europe/Stockholm"],
+            SG => &["Asia/Singapore"],
+            SH => &["Atlantic/St_Helena"],
+            SI => &["Europe/Ljubljana"],
+            SJ => &["Arctic/Longyearbyen"],
+            SK => &["Europe/Bratislava"],
+            SL => &["Africa/Freetown"],
+            SM => &["Europe/San_Marino"],
+            SN => &["Africa/Dakar"],
+            SO => &["Africa/Mogadishu"],
+            SR => &["America/Paramaribo"],
+            SS => &["Africa/Juba"],
+            ST => &["Africa/Sao_Tome"],
+            SV => &["America/El_Salvador"],
+            SX => &["America/Lower_Princes"],
+            SY => &["Asia/Damascus"],
+            SZ => &["Africa/Mbabane"],
+            TC => &["America/Grand_Turk"],
+            TD => &["Africa/Ndjamena"],
+            TF => &["Indian/Kerguelen"],
+            TG => &["Africa/Lome"],
+            TH => &["Asia/Bangkok"],
+            TJ => &["Asia/Dushanbe"],
+            TK => &["Pacific/Fakaofo"],
+            TL => &["Asia/Dili"],
+            TM => &["Asia/Ashgabat"],
+            TN => &["Africa/Tunis"],
+            TO => &["Pacific/Tongatapu"],
+            TR => &["Europe/Istanbul"],
+            TT => &["America/Port_of_Spain"],
+            TV => &["Pacific/Funafuti"],
+            TW => &["Asia/Taipei"],
+            TZ => &["Africa/Dar_es_Salaam"],
+            UA => &["Europe/Kyiv", "Europe/Simferopol"],
+            UG => &["Africa/Kampala"],
+            UM => &["Pacific/Midway", "Pacific/Wake"],
+            US => &[
+                "America/New_York", "America/Detroit", "America/Kentucky/Louisville",
+                "America/Kentucky/Monticello", "America/Indiana/Indianapolis",
+                "America/Indiana/Vincennes", "America/Indiana/Winamac", "America/Indiana/Marengo",
+                "America/Indiana/Petersburg", "America/Indiana/Vevay", "America/Chicago",
+                "America/Indiana/Tell_City", "America/Indiana/Knox", "America/Menominee",
+                "America/North_Dakota/Center", "America/North_Dakota/New_Salem",
+                "America/North_Dakota/Beulah", "America/Denver", "America/Boise",
+                "America/Phoenix", "America/Los_Angeles", "America/Anchorage", "America/Juneau",
+                "America/Sitka", "America/Metlakatla", "America/Yakutat", "America/Nome",
+                "America/Adak", "Pacific/Honolulu",
+            ],
+            UY => &["America/Montevideo"],
+            UZ => &["Asia/Tashkent", "Asia/Samarkand"],
+            VA => &["Europe/Vatican"],
+            VC => &["America/St_Vincent"],
+            VE => &["America/Caracas"],
+            VG => &["America/Tortola"],
+            VI => &["America/St_Thomas"],
+            VN => &["Asia/Ho_Chi_Minh"],
+            VU => &["Pacific/Efate"],
+            WF => &["Pacific/Wallis"],
+            WS => &["Pacific/Apia"],
+            YE => &["Asia/Aden"],
+            YT => &["Indian/Mayotte"],
+            ZA => &["Africa/Johannesburg"],
+            ZM => &["Africa/Lusaka"],
+            ZW => &["Africa/Harare"],
+        }
+    }
+
+    /// All countries using the given IANA time zone.
+    ///
+    /// Returns an empty slice for unknown zones and for zones such as
+    /// "Etc/UTC" which are not tied to any country.
+    pub fn from_time_zone(zone: &str) -> &'static [Country] {
+        match TIME_ZONE_SEARCH_TABLE.binary_search_by(|&(o, _)| o.cmp(zone)) {
+            Ok(pos) => TIME_ZONE_SEARCH_TABLE[pos].1,
+            Err(_)  => &[]
+        }
+    }
+}
+
+const TIME_ZONE_SEARCH_TABLE : &[(&str, &[Country])] = &[
+    ("Africa/Abidjan", &[
+        Country::CI, Country::BF, Country::GH, Country::GM, Country::GN, Country::IS, Country::ML,
+        Country::MR, Country::SH, Country::SL, Country::SN, Country::TG,
+    ]),
+    ("Africa/Accra", &[Country::GH]),
+    ("Africa/Addis_Ababa", &[Country::ET]),
+    ("Africa/Algiers", &[Country::DZ]),
+    ("Africa/Asmara", &[Country::ER]),
+    ("Africa/Asmera", &[
+        Country::KE, Country::DJ, Country::ER, Country::ET, Country::KM, Country::MG, Country::SO,
+        Country::TZ, Country::UG, Country::YT,
+    ]),
+    ("Africa/Bamako", &[Country::ML]),
+    ("Africa/Bangui", &[Country::CF]),
+    ("Africa/Banjul", &[Country::GM]),
+    ("Africa/Bissau", &[Country::GW]),
+    ("Africa/Blantyre", &[Country::MW]),
+    ("Africa/Brazzaville", &[Country::CG]),
+    ("Africa/Bujumbura", &[Country::BI]),
+    ("Africa/Cairo", &[Country::EG]),
+    ("Africa/Casablanca", &[Country::MA]),
+    ("Africa/Ceuta", &[Country::ES]),
+    ("Africa/Conakry", &[Country::GN]),
+    ("Africa/Dakar", &[Country::SN]),
+    ("Africa/Dar_es_Salaam", &[Country::TZ]),
+    ("Africa/Djibouti", &[Country::DJ]),
+    ("Africa/Douala", &[Country::CM]),
+    ("Africa/El_Aaiun", &[Country::EH]),
+    ("Africa/Freetown", &[Country::SL]),
+    ("Africa/Gaborone", &[Country::BW]),
+    ("Africa/Harare", &[Country::ZW]),
+    ("Africa/Johannesburg", &[Country::ZA, Country::LS, Country::SZ]),
+    ("Africa/Juba", &[Country::SS]),
+    ("Africa/Kampala", &[Country::UG]),
+    ("Africa/Khartoum", &[Country::SD]),
+    ("Africa/Kigali", &[Country::RW]),
+    ("Africa/Kinshasa", &[Country::CD]),
+    ("Africa/Lagos", &[
+        Country::NG, Country::AO, Country::BJ, Country::CD, Country::CF, Country::CG, Country::CM,
+        Country::GA, Country::GQ, Country::NE,
+    ]),
+    ("Africa/Libreville", &[Country::GA]),
+    ("Africa/Lome", &[Country::TG]),
+    ("Africa/Luanda", &[Country::AO]),
+    ("Africa/Lubumbashi", &[Country::CD]),
+    ("Africa/Lusaka", &[Country::ZM]),
+    ("Africa/Malabo", &[Country::GQ]),
+    ("Africa/Maputo", &[
+        Country::MZ, Country::BI, Country::BW, Country::CD, Country::MW, Country::RW, Country::ZM,
+        Country::ZW,
+    ]),
+    ("Africa/Maseru", &[Country::LS]),
+    ("Africa/Mbabane", &[Country::SZ]),
+    ("Africa/Mogadishu", &[Country::SO]),
+    ("Africa/Monrovia", &[Country::LR]),
+    ("Africa/Nairobi", &[
+        Country::KE, Country::DJ, Country::ER, Country::ET, Country::KM, Country::MG, Country::SO,
+        Country::TZ, Country::UG, Country::YT,
+    ]),
+    ("Africa/Ndjamena", &[Country::TD]),
+    ("Africa/Niamey", &[Country::NE]),
+    ("Africa/Nouakchott", &[Country::MR]),
+    ("Africa/Ouagadougou", &[Country::BF]),
+    ("Africa/Porto-Novo", &[Country::BJ]),
+    ("Africa/Sao_Tome", &[Country::ST]),
+    ("Africa/Timbuktu", &[
+        Country::CI, Country::BF, Country::GH, Country::GM, Country::GN, Country::IS, Country::ML,
+        Country::MR, Country::SH, Country::SL, Country::SN, Country::TG,
+    ]),
+    ("Africa/Tripoli", &[Country::LY]),
+    ("Africa/Tunis", &[Country::TN]),
+    ("Africa/Windhoek", &[Country::NA]),
+    ("America/Adak", &[Country::US]),
+    ("America/Anchorage", &[Country::US]),
+    ("America/Anguilla", &[Country::AI]),
+    ("America/Antigua", &[Country::AG]),
+    ("America/Araguaina", &[Country::BR]),
+    ("America/Argentina/Buenos_Aires", &[Country::AR]),
+    ("America/Argentina/Catamarca", &[Country::AR]),
+    ("America/Argentina/ComodRivadavia", &[Country::AR]),
+    ("America/Argentina/Cordoba", &[Country::AR]),
+    ("America/Argentina/Jujuy", &[Country::AR]),
+    ("America/Argentina/La_Rioja", &[Country::AR]),
+    ("America/Argentina/Mendoza", &[Country::AR]),
+    ("America/Argentina/Rio_Gallegos", &[Country::AR]),
+    ("America/Argentina/Salta", &[Country::AR]),
+    ("America/Argentina/San_Juan", &[Country::AR]),
+    ("America/Argentina/San_Luis", &[Country::AR]),
+    ("America/Argentina/Tucuman", &[Country::AR]),
+    ("America/Argentina/Ushuaia", &[Country::AR]),
+    ("America/Aruba", &[Country::AW]),
+    ("America/Asuncion", &[Country::PY]),
+    ("America/Atikokan", &[Country::CA]),
+    ("America/Atka", &[Country::US]),
+    ("America/Bahia", &[Country::BR]),
+    ("America/Bahia_Banderas", &[Country::MX]),
+    ("America/Barbados", &[Country::BB]),
+    ("America/Belem", &[Country::BR]),
+    ("America/Belize", &[Country::BZ]),
+    ("America/Blanc-Sablon", &[Country::CA]),
+    ("America/Boa_Vista", &[Country::BR]),
+    ("America/Bogota", &[Country::CO]),
+    ("America/Boise", &[Country::US]),
+    ("America/Buenos_Aires", &[Country::AR]),
+    ("America/Cambridge_Bay", &[Country::CA]),
+    ("America/Campo_Grande", &[Country::BR]),
+    ("America/Cancun", &[Country::MX]),
+    ("America/Caracas", &[Country::VE]),
+    ("America/Catamarca", &[Country::AR]),
+    ("America/Cayenne", &[Country::GF]),
+    ("America/Cayman", &[Country::KY]),
+    ("America/Chicago", &[Country::US]),
+    ("America/Chihuahua", &[Country::MX]),
+    ("America/Ciudad_Juarez", &[Country::MX]),
+    ("America/Coral_Harbour", &[Country::PA, Country::CA, Country::KY]),
+    ("America/Cordoba", &[Country::AR]),
+    ("America/Costa_Rica", &[Country::CR]),
+    ("America/Coyhaique", &[Country::CL]),
+    ("America/Creston", &[Country::CA]),
+    ("America/Cuiaba", &[Country::BR]),
+    ("America/Curacao", &[Country::CW]),
+    ("America/Danmarkshavn", &[Country::GL]),
+    ("America/Dawson", &[Country::CA]),
+    ("America/Dawson_Creek", &[Country::CA]),
+    ("America/Denver", &[Country::US]),
+    ("America/Detroit", &[Country::US]),
+    ("America/Dominica", &[Country::DM]),
+    ("America/Edmonton", &[Country::CA]),
+    ("America/Eirunepe", &[Country::BR]),
+    ("America/El_Salvador", &[Country::SV]),
+    ("America/Ensenada", &[Country::MX]),
+    ("America/Fort_Nelson", &[Country::CA]),
+    ("America/Fort_Wayne", &[Country::US]),
+    ("America/Fortaleza", &[Country::BR]),
+    ("America/Glace_Bay", &[Country::CA]),
+    ("America/Godthab", &[Country::GL]),
+    ("America/Goose_Bay", &[Country::CA]),
+    ("America/Grand_Turk", &[Country::TC]),
+    ("America/Grenada", &[Country::GD]),
+    ("America/Guadeloupe", &[Country::GP]),
+    ("America/Guatemala", &[Country::GT]),
+    ("America/Guayaquil", &[Country::EC]),
+    ("America/Guyana", &[Country::GY]),
+    ("America/Halifax", &[Country::CA]),
+    ("America/Havana", &[Country::CU]),
+    ("America/Hermosillo", &[Country::MX]),
+    ("America/Indiana/Indianapolis", &[Country::US]),
+    ("America/Indiana/Knox", &[Country::US]),
+    ("America/Indiana/Marengo", &[Country::US]),
+    ("America/Indiana/Petersburg", &[Country::US]),
+    ("America/Indiana/Tell_City", &[Country::US]),
+    ("America/Indiana/Vevay", &[Country::US]),
+    ("America/Indiana/Vincennes", &[Country::US]),
+    ("America/Indiana/Winamac", &[Country::US]),
+    ("America/Indianapolis", &[Country::US]),
+    ("America/Inuvik", &[Country::CA]),
+    ("America/Iqaluit", &[Country::CA]),
+    ("America/Jamaica", &[Country::JM]),
+    ("America/Jujuy", &[Country::AR]),
+    ("America/Juneau", &[Country::US]),
+    ("America/Kentucky/Louisville", &[Country::US]),
+    ("America/Kentucky/Monticello", &[Country::US]),
+    ("America/Knox_IN", &[Country::US]),
+    ("America/Kralendijk", &[Country::BQ]),
+    ("America/La_Paz", &[Country::BO]),
+    ("America/Lima", &[Country::PE]),
+    ("America/Los_Angeles", &[Country::US]),
+    ("America/Louisville", &[Country::US]),
+    ("America/Lower_Princes", &[Country::SX]),
+    ("America/Maceio", &[Country::BR]),
+    ("America/Managua", &[Country::NI]),
+    ("America/Manaus", &[Country::BR]),
+    ("America/Marigot", &[Country::MF]),
+    ("America/Martinique", &[Country::MQ]),
+    ("America/Matamoros", &[Country::MX]),
+    ("America/Mazatlan", &[Country::MX]),
+    ("America/Mendoza", &[Country::AR]),
+    ("America/Menominee", &[Country::US]),
+    ("America/Merida", &[Country::MX]),
+    ("America/Metlakatla", &[Country::US]),
+    ("America/Mexico_City", &[Country::MX]),
+    ("America/Miquelon", &[Country::PM]),
+    ("America/Moncton", &[Country::CA]),
+    ("America/Monterrey", &[Country::MX]),
+    ("America/Montevideo", &[Country::UY]),
+    ("America/Montreal", &[Country::CA, Country::BS]),
+    ("America/Montserrat", &[Country::MS]),
+    ("America/Nassau", &[Country::BS]),
+    ("America/New_York", &[Country::US]),
+    ("America/Nipigon", &[Country::CA, Country::BS]),
+    ("America/Nome", &[Country::US]),
+    ("America/Noronha", &[Country::BR]),
+    ("America/North_Dakota/Beulah", &[Country::US]),
+    ("America/North_Dakota/Center", &[Country::US]),
+    ("America/North_Dakota/New_Salem", &[Country::US]),
+    ("America/Nuuk", &[Country::GL]),
+    ("America/Ojinaga", &[Country::MX]),
+    ("America/Panama", &[Country::PA, Country::CA, Country::KY]),
+    ("America/Pangnirtung", &[Country::CA]),
+    ("America/Paramaribo", &[Country::SR]),
+    ("America/Phoenix", &[Country::US, Country::CA]),
+    ("America/Port-au-Prince", &[Country::HT]),
+    ("America/Port_of_Spain", &[Country::TT]),
+    ("America/Porto_Acre", &[Country::BR]),
+    ("America/Porto_Velho", &[Country::BR]),
+    ("America/Puerto_Rico", &[
+        Country::PR, Country::AG, Country::CA, Country::AI, Country::AW, Country::BL, Country::BQ,
+        Country::CW, Country::DM, Country::GD, Country::GP, Country::KN, Country::LC, Country::MF,
+        Country::MS, Country::SX, Country::TT, Country::VC, Country::VG, Country::VI,
+    ]),
+    ("America/Punta_Arenas", &[Country::CL]),
+    ("America/Rainy_River", &[Country::CA]),
+    ("America/Rankin_Inlet", &[Country::CA]),
+    ("America/Recife", &[Country::BR]),
+    ("America/Regina", &[Country::CA]),
+    ("America/Resolute", &[Country::CA]),
+    ("America/Rio_Branco", &[Country::BR]),
+    ("America/Rosario", &[Country::AR]),
+    ("America/Santa_Isabel", &[Country::MX]),
+    ("America/Santarem", &[Country::BR]),
+    ("America/Santiago", &[Country::CL]),
+    ("America/Santo_Domingo", &[Country::DO]),
+    ("America/Sao_Paulo", &[Country::BR]),
+    ("America/Scoresbysund", &[Country::GL]),
+    ("America/Shiprock", &[Country::US]),
+    ("America/Sitka", &[Country::US]),
+    ("America/St_Barthelemy", &[Country::BL]),
+    ("America/St_Johns", &[Country::CA]),
+    ("America/St_Kitts", &[Country::KN]),
+    ("America/St_Lucia", &[Country::LC]),
+    ("America/St_Thomas", &[Country::VI]),
+    ("America/St_Vincent", &[Country::VC]),
+    ("America/Swift_Current", &[Country::CA]),
+    ("America/Tegucigalpa", &[Country::HN]),
+    ("America/Thule", &[Country::GL]),
+    ("America/Thunder_Bay", &[Country::CA, Country::BS]),
+    ("America/Tijuana", &[Country::MX]),
+    ("America/Toronto", &[Country::CA, Country::BS]),
+    ("America/Tortola", &[Country::VG]),
+    ("America/Vancouver", &[Country::CA]),
+    ("America/Virgin", &[
+        Country::PR, Country::AG, Country::CA, Country::AI, Country::AW, Country::BL, Country::BQ,
+        Country::CW, Country::DM, Country::GD, Country::GP, Country::KN, Country::LC, Country::MF,
+        Country::MS, Country::SX, Country::TT, Country::VC, Country::VG, Country::VI,
+    ]),
+    ("America/Whitehorse", &[Country::CA]),
+    ("America/Winnipeg", &[Country::CA]),
+    ("America/Yakutat", &[Country::US]),
+    ("America/Yellowknife", &[Country::CA]),
+    ("Antarctica/Casey", &[Country::AQ]),
+    ("Antarctica/Davis", &[Country::AQ]),
+    ("Antarctica/DumontDUrville", &[Country::AQ]),
+    ("Antarctica/Macquarie", &[Country::AU]),
+    ("Antarctica/Mawson", &[Country::AQ]),
+    ("Antarctica/McMurdo", &[Country::AQ]),
+    ("Antarctica/Palmer", &[Country::AQ]),
+    ("Antarctica/Rothera", &[Country::AQ]),
+    ("Antarctica/South_Pole", &[Country::NZ, Country::AQ]),
+    ("Antarctica/Syowa", &[Country::AQ]),
+    ("Antarctica/Troll", &[Country::AQ]),
+    ("Antarctica/Vostok", &[Country::AQ]),
+    ("Arctic/Longyearbyen", &[Country::SJ]),
+    ("Asia/Aden", &[Country::YE]),
+    ("Asia/Almaty", &[Country::KZ]),
+    ("Asia/Amman", &[Country::JO]),
+    ("Asia/Anadyr", &[Country::RU]),
+    ("Asia/Aqtau", &[Country::KZ]),
+    ("Asia/Aqtobe", &[Country::KZ]),
+    ("Asia/Ashgabat", &[Country::TM]),
+    ("Asia/Ashkhabad", &[Country::TM]),
+    ("Asia/Atyrau", &[Country::KZ]),
+    ("Asia/Baghdad", &[Country::IQ]),
+    ("Asia/Bahrain", &[Country::BH]),
+    ("Asia/Baku", &[Country::AZ]),
+    ("Asia/Bangkok", &[Country::TH, Country::CX, Country::KH, Country::LA, Country::VN]),
+    ("Asia/Barnaul", &[Country::RU]),
+    ("Asia/Beirut", &[Country::LB]),
+    ("Asia/Bishkek", &[Country::KG]),
+    ("Asia/Brunei", &[Country::BN]),
+    ("Asia/Calcutta", &[Country::IN]),
+    ("Asia/Chita", &[Country::RU]),
+    ("Asia/Choibalsan", &[Country::MN]),
+    ("Asia/Chongqing", &[Country::CN]),
+    ("Asia/Chungking", &[Country::CN]),
+    ("Asia/Colombo", &[Country::LK]),
+    ("Asia/Dacca", &[Country::BD]),
+    ("Asia/Damascus", &[Country::SY]),
+    ("Asia/Dhaka", &[Country::BD]),
+    ("Asia/Dili", &[Country::TL]),
+    ("Asia/Dubai", &[Country::AE, Country::OM, Country::RE, Country::SC, Country::TF]),
+    ("Asia/Dushanbe", &[Country::TJ]),
+    ("Asia/Famagusta", &[Country::CY]),
+    ("Asia/Gaza", &[Country::PS]),
+    ("Asia/Harbin", &[Country::CN]),
+    ("Asia/Hebron", &[Country::PS]),
+    ("Asia/Ho_Chi_Minh", &[Country::VN]),
+    ("Asia/Hong_Kong", &[Country::HK]),
+    ("Asia/Hovd", &[Country::MN]),
+    ("Asia/Irkutsk", &[Country::RU]),
+    ("Asia/Istanbul", &[Country::TR]),
+    ("Asia/Jakarta", &[Country::ID]),
+    ("Asia/Jayapura", &[Country::ID]),
+    ("Asia/Jerusalem", &[Country::IL]),
+    ("Asia/Kabul", &[Country::AF]),
+    ("Asia/Kamchatka", &[Country::RU]),
+    ("Asia/Karachi", &[Country::PK]),
+    ("Asia/Kashgar", &[Country::CN]),
+    ("Asia/Kathmandu", &[Country::NP]),
+    ("Asia/Katmandu", &[Country::NP]),
+    ("Asia/Khandyga", &[Country::RU]),
+    ("Asia/Kolkata", &[Country::IN]),
+    ("Asia/Krasnoyarsk", &[Country::RU]),
+    ("Asia/Kuala_Lumpur", &[Country::MY]),
+    ("Asia/Kuching", &[Country::MY, Country::BN]),
+    ("Asia/Kuwait", &[Country::KW]),
+    ("Asia/Macao", &[Country::MO]),
+    ("Asia/Macau", &[Country::MO]),
+    ("Asia/Magadan", &[Country::RU]),
+    ("Asia/Makassar", &[Country::ID]),
+    ("Asia/Manila", &[Country::PH]),
+    ("Asia/Muscat", &[Country::OM]),
+    ("Asia/Nicosia", &[Country::CY]),
+    ("Asia/Novokuznetsk", &[Country::RU]),
+    ("Asia/Novosibirsk", &[Country::RU]),
+    ("Asia/Omsk", &[Country::RU]),
+    ("Asia/Oral", &[Country::KZ]),
+    ("Asia/Phnom_Penh", &[Country::KH]),
+    ("Asia/Pontianak", &[Country::ID]),
+    ("Asia/Pyongyang", &[Country::KP]),
+    ("Asia/Qatar", &[Country::QA, Country::BH]),
+    ("Asia/Qostanay", &[Country::KZ]),
+    ("Asia/Qyzylorda", &[Country::KZ]),
+    ("Asia/Rangoon", &[Country::MM, Country::CC]),
+    ("Asia/Riyadh", &[Country::SA, Country::AQ, Country::KW, Country::YE]),
+    ("Asia/Saigon", &[Country::VN]),
+    ("Asia/Sakhalin", &[Country::RU]),
+    ("Asia/Samarkand", &[Country::UZ]),
+    ("Asia/Seoul", &[Country::KR]),
+    ("Asia/Shanghai", &[Country::CN]),
+    ("Asia/Singapore", &[Country::SG, Country::AQ, Country::MY]),
+    ("Asia/Srednekolymsk", &[Country::RU]),
+    ("Asia/Taipei", &[Country::TW]),
+    ("Asia/Tashkent", &[Country::UZ]),
+    ("Asia/Tbilisi", &[Country::GE]),
+    ("Asia/Tehran", &[Country::IR]),
+    ("Asia/Tel_Aviv", &[Country::IL]),
+    ("Asia/Thimbu", &[Country::BT]),
+    ("Asia/Thimphu", &[Country::BT]),
+    ("Asia/Tokyo", &[Country::JP, Country::AU]),
+    ("Asia/Tomsk", &[Country::RU]),
+    ("Asia/Ujung_Pandang", &[Country::ID]),
+    ("Asia/Ulaanbaatar", &[Country::MN]),
+    ("Asia/Ulan_Bator", &[Country::MN]),
+    ("Asia/Urumqi", &[Country::CN]),
+    ("Asia/Ust-Nera", &[Country::RU]),
+    ("Asia/Vientiane", &[Country::LA]),
+    ("Asia/Vladivostok", &[Country::RU]),
+    ("Asia/Yakutsk", &[Country::RU]),
+    ("Asia/Yangon", &[Country::MM, Country::CC]),
+    ("Asia/Yekaterinburg", &[Country::RU]),
+    ("Asia/Yerevan", &[Country::AM]),
+    ("Atlantic/Azores", &[Country::PT]),
+    ("Atlantic/Bermuda", &[Country::BM]),
+    ("Atlantic/Canary", &[Country::ES]),
+    ("Atlantic/Cape_Verde", &[Country::CV]),
+    ("Atlantic/Faeroe", &[Country::FO]),
+    ("Atlantic/Faroe", &[Country::FO]),
+    ("Atlantic/Jan_Mayen", &[Country::DE, Country::DK, Country::NO, Country::SE, Country::SJ]),
+    ("Atlantic/Madeira", &[Country::PT]),
+    ("Atlantic/Reykjavik", &[Country::IS]),
+    ("Atlantic/South_Georgia", &[Country::GS]),
+    ("Atlantic/St_Helena", &[Country::SH]),
+    ("Atlantic/Stanley", &[Country::FK]),
+    ("Australia/ACT", &[Country::AU]),
+    ("Australia/Adelaide", &[Country::AU]),
+    ("Australia/Brisbane", &[Country::AU]),
+    ("Australia/Broken_Hill", &[Country::AU]),
+    ("Australia/Canberra", &[Country::AU]),
+    ("Australia/Currie", &[Country::AU]),
+    ("Australia/Darwin", &[Country::AU]),
+    ("Australia/Eucla", &[Country::AU]),
+    ("Australia/Hobart", &[Country::AU]),
+    ("Australia/LHI", &[Country::AU]),
+    ("Australia/Lindeman", &[Country::AU]),
+    ("Australia/Lord_Howe", &[Country::AU]),
+    ("Australia/Melbourne", &[Country::AU]),
+    ("Australia/NSW", &[Country::AU]),
+    ("Australia/North", &[Country::AU]),
+    ("Australia/Perth", &[Country::AU]),
+    ("Australia/Queensland", &[Country::AU]),
+    ("Australia/South", &[Country::AU]),
+    ("Australia/Sydney", &[Country::AU]),
+    ("Australia/Tasmania", &[Country::AU]),
+    ("Australia/Victoria", &[Country::AU]),
+    ("Australia/West", &[Country::AU]),
+    ("Australia/Yancowinna", &[Country::AU]),
+    ("Brazil/Acre", &[Country::BR]),
+    ("Brazil/DeNoronha", &[Country::BR]),
+    ("Brazil/East", &[Country::BR]),
+    ("Brazil/West", &[Country::BR]),
+    ("Canada/Atlantic", &[Country::CA]),
+    ("Canada/Central", &[Country::CA]),
+    ("Canada/Eastern", &[Country::CA, Country::BS]),
+    ("Canada/Mountain", &[Country::CA]),
+    ("Canada/Newfoundland", &[Country::CA]),
+    ("Canada/Pacific", &[Country::CA]),
+    ("Canada/Saskatchewan", &[Country::CA]),
+    ("Canada/Yukon", &[Country::CA]),
+    ("Chile/Continental", &[Country::CL]),
+    ("Chile/EasterIsland", &[Country::CL]),
+    ("Cuba", &[Country::CU]),
+    ("Egypt", &[Country::EG]),
+    ("Eire", &[Country::IE]),
+    ("Europe/Amsterdam", &[Country::NL]),
+    ("Europe/Andorra", &[Country::AD]),
+    ("Europe/Astrakhan", &[Country::RU]),
+    ("Europe/Athens", &[Country::GR]),
+    ("Europe/Belfast", &[Country::GB, Country::GG, Country::IM, Country::JE]),
+    ("Europe/Belgrade", &[
+        Country::RS, Country::BA, Country::HR, Country::ME, Country::MK, Country::SI,
+    ]),
+    ("Europe/Berlin", &[Country::DE, Country::DK, Country::NO, Country::SE, Country::SJ]),
+    ("Europe/Bratislava", &[Country::SK]),
+    ("Europe/Brussels", &[Country::BE, Country::LU, Country::NL]),
+    ("Europe/Bucharest", &[Country::RO]),
+    ("Europe/Budapest", &[Country::HU]),
+    ("Europe/Busingen", &[Country::DE]),
+    ("Europe/Chisinau", &[Country::MD]),
+    ("Europe/Copenhagen", &[Country::DK]),
+    ("Europe/Dublin", &[Country::IE]),
+    ("Europe/Gibraltar", &[Country::GI]),
+    ("Europe/Guernsey", &[Country::GG]),
+    ("Europe/Helsinki", &[Country::FI, Country::AX]),
+    ("Europe/Isle_of_Man", &[Country::IM]),
+    ("Europe/Istanbul", &[Country::TR]),
+    ("Europe/Jersey", &[Country::JE]),
+    ("Europe/Kaliningrad", &[Country::RU]),
+    ("Europe/Kiev", &[Country::UA]),
+    ("Europe/Kirov", &[Country::RU]),
+    ("Europe/Kyiv", &[Country::UA]),
+    ("Europe/Lisbon", &[Country::PT]),
+    ("Europe/Ljubljana", &[Country::SI]),
+    ("Europe/London", &[Country::GB, Country::GG, Country::IM, Country::JE]),
+    ("Europe/Luxembourg", &[Country::LU]),
+    ("Europe/Madrid", &[Country::ES]),
+    ("Europe/Malta", &[Country::MT]),
+    ("Europe/Mariehamn", &[Country::AX]),
+    ("Europe/Minsk", &[Country::BY]),
+    ("Europe/Monaco", &[Country::MC]),
+    ("Europe/Moscow", &[Country::RU]),
+    ("Europe/Nicosia", &[Country::CY]),
+    ("Europe/Oslo", &[Country::NO]),
+    ("Europe/Paris", &[Country::FR, Country::MC]),
+    ("Europe/Podgorica", &[Country::ME]),
+    ("Europe/Prague", &[Country::CZ, Country::SK]),
+    ("Europe/Riga", &[Country::LV]),
+    ("Europe/Rome", &[Country::IT, Country::SM, Country::VA]),
+    ("Europe/Samara", &[Country::RU]),
+    ("Europe/San_Marino", &[Country::SM]),
+    ("Europe/Sarajevo", &[Country::BA]),
+    ("Europe/Saratov", &[Country::RU]),
+    ("Europe/Simferopol", &[Country::RU, Country::UA]),
+    ("Europe/Skopje", &[Country::MK]),
+    ("Europe/Sofia", &[Country::BG]),
+    ("Europe/Stockholm", &[Country::SE]),
+    ("Europe/Tallinn", &[Country::EE]),
+    ("Europe/Tirane", &[Country::AL]),
+    ("Europe/Tiraspol", &[Country::MD]),
+    ("Europe/Ulyanovsk", &[Country::RU]),
+    ("Europe/Uzhgorod", &[Country::UA]),
+    ("Europe/Vaduz", &[Country::LI]),
+    ("Europe/Vatican", &[Country::VA]),
+    ("Europe/Vienna", &[Country::AT]),
+    ("Europe/Vilnius", &[Country::LT]),
+    ("Europe/Volgograd", &[Country::RU]),
+    ("Europe/Warsaw", &[Country::PL]),
+    ("Europe/Zagreb", &[Country::HR]),
+    ("Europe/Zaporozhye", &[Country::UA]),
+    ("Europe/Zurich", &[Country::CH, Country::DE, Country::LI]),
+    ("GB", &[Country::GB, Country::GG, Country::IM, Country::JE]),
+    ("GB-Eire", &[Country::GB, Country::GG, Country::IM, Country::JE]),
+    ("Hongkong", &[Country::HK]),
+    ("Iceland", &[
+        Country::CI, Country::BF, Country::GH, Country::GM, Country::GN, Country::IS, Country::ML,
+        Country::MR, Country::SH, Country::SL, Country::SN, Country::TG,
+    ]),
+    ("Indian/Antananarivo", &[Country::MG]),
+    ("Indian/Chagos", &[Country::IO]),
+    ("Indian/Christmas", &[Country::CX]),
+    ("Indian/Cocos", &[Country::CC]),
+    ("Indian/Comoro", &[Country::KM]),
+    ("Indian/Kerguelen", &[Country::TF]),
+    ("Indian/Mahe", &[Country::SC]),
+    ("Indian/Maldives", &[Country::MV, Country::TF]),
+    ("Indian/Mauritius", &[Country::MU]),
+    ("Indian/Mayotte", &[Country::YT]),
+    ("Indian/Reunion", &[Country::RE]),
+    ("Iran", &[Country::IR]),
+    ("Israel", &[Country::IL]),
+    ("Jamaica", &[Country::JM]),
+    ("Japan", &[Country::JP, Country::AU]),
+    ("Kwajalein", &[Country::MH]),
+    ("Libya", &[Country::LY]),
+    ("Mexico/BajaNorte", &[Country::MX]),
+    ("Mexico/BajaSur", &[Country::MX]),
+    ("Mexico/General", &[Country::MX]),
+    ("NZ", &[Country::NZ, Country::AQ]),
+    ("NZ-CHAT", &[Country::NZ]),
+    ("Navajo", &[Country::US]),
+    ("PRC", &[Country::CN]),
+    ("Pacific/Apia", &[Country::WS]),
+    ("Pacific/Auckland", &[Country::NZ, Country::AQ]),
+    ("Pacific/Bougainville", &[Country::PG]),
+    ("Pacific/Chatham", &[Country::NZ]),
+    ("Pacific/Chuuk", &[Country::FM]),
+    ("Pacific/Easter", &[Country::CL]),
+    ("Pacific/Efate", &[Country::VU]),
+    ("Pacific/Enderbury", &[Country::KI]),
+    ("Pacific/Fakaofo", &[Country::TK]),
+    ("Pacific/Fiji", &[Country::FJ]),
+    ("Pacific/Funafuti", &[Country::TV]),
+    ("Pacific/Galapagos", &[Country::EC]),
+    ("Pacific/Gambier", &[Country::PF]),
+    ("Pacific/Guadalcanal", &[Country::SB, Country::FM]),
+    ("Pacific/Guam", &[Country::GU, Country::MP]),
+    ("Pacific/Honolulu", &[Country::US]),
+    ("Pacific/Johnston", &[Country::US]),
+    ("Pacific/Kanton", &[Country::KI]),
+    ("Pacific/Kiritimati", &[Country::KI]),
+    ("Pacific/Kosrae", &[Country::FM]),
+    ("Pacific/Kwajalein", &[Country::MH]),
+    ("Pacific/Majuro", &[Country::MH]),
+    ("Pacific/Marquesas", &[Country::PF]),
+    ("Pacific/Midway", &[Country::UM]),
+    ("Pacific/Nauru", &[Country::NR]),
+    ("Pacific/Niue", &[Country::NU]),
+    ("Pacific/Norfolk", &[Country::NF]),
+    ("Pacific/Noumea", &[Country::NC]),
+    ("Pacific/Pago_Pago", &[Country::AS, Country::UM]),
+    ("Pacific/Palau", &[Country::PW]),
+    ("Pacific/Pitcairn", &[Country::PN]),
+    ("Pacific/Pohnpei", &[Country::FM]),
+    ("Pacific/Ponape", &[Country::SB, Country::FM]),
+    ("Pacific/Port_Moresby", &[Country::PG, Country::AQ, Country::FM]),
+    ("Pacific/Rarotonga", &[Country::CK]),
+    ("Pacific/Saipan", &[Country::MP]),
+    ("Pacific/Samoa", &[Country::AS, Country::UM]),
+    ("Pacific/Tahiti", &[Country::PF]),
+    ("Pacific/Tarawa", &[Country::KI, Country::MH, Country::TV, Country::UM, Country::WF]),
+    ("Pacific/Tongatapu", &[Country::TO]),
+    ("Pacific/Truk", &[Country::PG, Country::AQ, Country::FM]),
+    ("Pacific/Wake", &[Country::UM]),
+    ("Pacific/Wallis", &[Country::WF]),
+    ("Pacific/Yap", &[Country::PG, Country::AQ, Country::FM]),
+    ("Poland", &[Country::PL]),
+    ("Portugal", &[Country::PT]),
+    ("ROC", &[Country::TW]),
+    ("ROK", &[Country::KR]),
+    ("Singapore", &[Country::SG, Country::AQ, Country::MY]),
+    ("Turkey", &[Country::TR]),
+    ("US/Alaska", &[Country::US]),
+    ("US/Aleutian", &[Country::US]),
+    ("US/Arizona", &[Country::US, Country::CA]),
+    ("US/Central", &[Country::US]),
+    ("US/East-Indiana", &[Country::US]),
+    ("US/Eastern", &[Country::US]),
+    ("US/Hawaii", &[Country::US]),
+    ("US/Indiana-Starke", &[Country::US]),
+    ("US/Michigan", &[Country::US]),
+    ("US/Mountain", &[Country::US]),
+    ("US/Pacific", &[Country::US]),
+    ("US/Samoa", &[Country::AS, Country::UM]),
+    ("W-SU", &[Country::RU]),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+
+    #[test]
+    fn time_zones() {
+        assert_eq!(&["Europe/Warsaw"], Country::PL.time_zones());
+        assert_eq!(&["Europe/Vaduz"], Country::LI.time_zones());
+        assert_eq!("Europe/Berlin", Country::DE.time_zones()[0]);
+        assert_eq!("Australia/Sydney", Country::AU.time_zones()[0]);
+        assert_eq!(&["America/Antigua"], Country::AG.time_zones());
+        assert!(Country::US.time_zones().contains(&"America/Los_Angeles"));
+        assert!(Country::BV.time_zones().is_empty());
+        assert!(Country::Unspecified.time_zones().is_empty());
+    }
+
+    #[test]
+    fn from_time_zone() {
+        assert_eq!(&[Country::PL], Country::from_time_zone("Europe/Warsaw"));
+        assert_eq!(&[Country::CH, Country::DE, Country::LI], Country::from_time_zone("Europe/Zurich"));
+        assert_eq!(&[Country::LI], Country::from_time_zone("Europe/Vaduz"));
+        assert_eq!(&[Country::IN], Country::from_time_zone("Asia/Calcutta"));
+        assert_eq!(&[Country::UA], Country::from_time_zone("Europe/Kiev"));
+        assert_eq!(&[Country::AR], Country::from_time_zone("America/Buenos_Aires"));
+        assert!(Country::from_time_zone("Etc/UTC").is_empty());
+        assert!(Country::from_time_zone("Mars/Olympus_Mons").is_empty());
+    }
+
+    #[test]
+    fn search_table_is_sorted() {
+        let table = super::TIME_ZONE_SEARCH_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
@@ -0,0 +1,593 @@
+// VAT identification numbers.
+//
+// Offline format and check digit validation for the EU member states,
+// Northern Ireland, the United Kingdom, Switzerland and Norway.  A number
+// which passes these checks is well formed but may still not be registered;
+// only the tax authority (e.g. VIES) can confirm that.
+
+use std::fmt;
+use std::error::Error;
+
+use crate::Country;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum VatError {
+    /// The number does not start with a known VAT prefix.
+    UnknownPrefix(String),
+    /// The number has the wrong length or characters for its country.
+    InvalidFormat,
+    InvalidChecksum,
+}
+
+impl Error for VatError {
+    fn description(&self) -> &str { "error parsing VAT number" }
+}
+
+impl fmt::Display for VatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A well formed VAT number.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VatNumber {
+    pub country: Country,
+    /// The VAT prefix, which is "EL" for Greece, "XI" for Northern Ireland
+    /// and "CHE" for Switzerland.
+    pub prefix: &'static str,
+    /// The number without prefix, separators or suffixes such as "MWST".
+    pub number: String,
+}
+
+impl fmt::Display for VatNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.prefix, self.number)
+    }
+}
+
+impl Country {
+    /// The prefix of VAT numbers issued by this country.
+    ///
+    /// Greece uses "EL" rather than its ISO code.  Traders in Northern
+    /// Ireland use "XI" for goods, see `vat::validate`.
+    pub fn vat_prefix(&self) -> Option<&'static str> {
+        use Country::*;
+        Some(match *self {
+            AT => "AT",
+            BE => "BE",
+            BG => "BG",
+            CH => "CHE",
+            CY => "CY",
+            CZ => "CZ",
+            DE => "DE",
+            DK => "DK",
+            EE => "EE",
+            ES => "ES",
+            FI => "FI",
+            FR => "FR",
+            GB => "GB",
+            GR => "EL",
+            HR => "HR",
+            HU => "HU",
+            IE => "IE",
+            IT => "IT",
+            LT => "LT",
+            LU => "LU",
+            LV => "LV",
+            MT => "MT",
+            NL => "NL",
+            NO => "NO",
+            PL => "PL",
+            PT => "PT",
+            RO => "RO",
+            SE => "SE",
+            SI => "SI",
+            SK => "SK",
+            _ => return None
+        })
+    }
+}
+
+const VAT_PREFIX_SEARCH_TABLE : &[(&str, Country)] = &[
+    ("AT",  Country::AT),
+    ("BE",  Country::BE),
+    ("BG",  Country::BG),
+    ("CHE", Country::CH),
+    ("CY",  Country::CY),
+    ("CZ",  Country::CZ),
+    ("DE",  Country::DE),
+    ("DK",  Country::DK),
+    ("EE",  Country::EE),
+    ("EL",  Country::GR),
+    ("ES",  Country::ES),
+    ("FI",  Country::FI),
+    ("FR",  Country::FR),
+    ("GB",  Country::GB),
+    ("HR",  Country::HR),
+    ("HU",  Country::HU),
+    ("IE",  Country::IE),
+    ("IT",  Country::IT),
+    ("LT",  Country::LT),
+    ("LU",  Country::LU),
+    ("LV",  Country::LV),
+    ("MT",  Country::MT),
+    ("NL",  Country::NL),
+    ("NO",  Country::NO),
+    ("PL",  Country::PL),
+    ("PT",  Country::PT),
+    ("RO",  Country::RO),
+    ("SE",  Country::SE),
+    ("SI",  Country::SI),
+    ("SK",  Country::SK),
+    ("XI",  Country::GB),
+];
+
+/// Validates a VAT number including its prefix, e.g. "DE 136 695 976" or
+/// "CHE-107.787.577 MWST".
+pub fn validate(s: &str) -> Result<VatNumber, VatError> {
+    let s = normalize(s);
+    let len = if s.starts_with("CHE") { 3 } else { 2 };
+    match s.get(..len).and_then(lookup) {
+        Some((prefix, country)) => check(country, prefix, &s[len..]),
+        None => Err(VatError::UnknownPrefix(s.chars().take(len).collect()))
+    }
+}
+
+/// Validates a VAT number issued by the given country, with or without its
+/// prefix.
+pub fn validate_for(country: Country, s: &str) -> Result<VatNumber, VatError> {
+    let s = normalize(s);
+    let prefix = country.vat_prefix().ok_or_else(|| VatError::UnknownPrefix(country.to_string()))?;
+    for &(p, c) in VAT_PREFIX_SEARCH_TABLE {
+        if c == country && s.starts_with(p) {
+            if let Ok(number) = check(country, p, &s[p.len()..]) {
+                return Ok(number);
+            }
+        }
+    }
+    check(country, prefix, &s)
+}
+
+fn lookup(prefix: &str) -> Option<(&'static str, Country)> {
+    VAT_PREFIX_SEARCH_TABLE.binary_search_by(|&(o, _)| o.cmp(prefix))
+        .ok()
+        .map(|pos| VAT_PREFIX_SEARCH_TABLE[pos])
+}
+
+fn normalize(s: &str) -> String {
+    let mut s: String = s.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '.' | '-' | '/' | ','))
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    for suffix in &["MWST", "TVA", "IVA", "MVA"] {
+        if s.len() > suffix.len() + 3 && s.ends_with(suffix) {
+            s.truncate(s.len() - suffix.len());
+        }
+    }
+    s
+}
+
+fn check(country: Country, prefix: &'static str, number: &str) -> Result<VatNumber, VatError> {
+    use Country::*;
+    let mut number = number.to_string();
+    if (country == BE && number.len() == 9) || (country == GR && number.len() == 8) {
+        number.insert(0, '0');
+    }
+    let n = number.as_bytes();
+    match country {
+        AT => at(n),
+        BE => be(n),
+        BG => bg(n),
+        CH => ch(n),
+        CY => cy(n),
+        CZ => cz(n),
+        DE => de(n),
+        DK => dk(n),
+        EE => ee(n),
+        ES => es(n),
+        FI => fi(n),
+        FR => fr(n),
+        GB => gb(n),
+        GR => gr(n),
+        HR => hr(n),
+        HU => hu(n),
+        IE => ie(n),
+        IT => it(n),
+        LT => lt(n),
+        LU => lu(n),
+        LV => lv(n),
+        MT => mt(n),
+        NL => nl(n),
+        NO => no(n),
+        PL => pl(n),
+        PT => pt(n),
+        RO => ro(n),
+        SE => se(n),
+        SI => si(n),
+        SK => sk(n),
+        _ => Err(VatError::UnknownPrefix(prefix.to_string()))
+    }?;
+    Ok(VatNumber { country, prefix, number })
+}
+
+type Check = Result<(), VatError>;
+
+fn format(valid: bool) -> Check {
+    if valid { Ok(()) } else { Err(VatError::InvalidFormat) }
+}
+
+fn checksum(valid: bool) -> Check {
+    if valid { Ok(()) } else { Err(VatError::InvalidChecksum) }
+}
+
+fn is_digits(n: &[u8]) -> bool {
+    n.iter().all(u8::is_ascii_digit)
+}
+
+fn digit(b: u8) -> u32 {
+    (b - b'0') as u32
+}
+
+fn number(n: &[u8]) -> u64 {
+    n.iter().fold(0, |acc, &b| acc * 10 + digit(b) as u64)
+}
+
+fn weighted(weights: &[u32], n: &[u8]) -> u32 {
+    weights.iter().zip(n).map(|(w, &b)| w * digit(b)).sum()
+}
+
+// Luhn checksum, zero for a valid number.
+fn luhn(n: &[u8]) -> u32 {
+    n.iter().rev().enumerate().map(|(i, &b)| {
+        let d = digit(b);
+        if i % 2 == 1 { if d > 4 { d * 2 - 9 } else { d * 2 } } else { d }
+    }).sum::<u32>() % 10
+}
+
+// ISO 7064 Mod 11,10 over the whole number including its check digit.
+fn mod_11_10(n: &[u8]) -> bool {
+    let (check, body) = match n.split_last() {
+        Some(split) => split,
+        None => return false
+    };
+    let p = body.iter().fold(10, |p, &b| {
+        let s = (digit(b) + p) % 10;
+        (if s == 0 { 10 } else { s }) * 2 % 11
+    });
+    (11 - p) % 10 == digit(*check)
+}
+
+fn at(n: &[u8]) -> Check {
+    format(n.len() == 9 && n[0] == b'U' && is_digits(&n[1..]))?;
+    checksum((16 - luhn(&n[1..8])) % 10 == digit(n[8]))
+}
+
+fn be(n: &[u8]) -> Check {
+    format(n.len() == 10 && is_digits(n) && n[0] <= b'1')?;
+    checksum(97 - number(&n[..8]) % 97 == number(&n[8..]))
+}
+
+fn bg(n: &[u8]) -> Check {
+    format((n.len() == 9 || n.len() == 10) && is_digits(n))?;
+    if n.len() == 9 {
+        let mut check = weighted(&[1, 2, 3, 4, 5, 6, 7, 8], n) % 11;
+        if check == 10 {
+            check = weighted(&[3, 4, 5, 6, 7, 8, 9, 10], n) % 11 % 10;
+        }
+        return checksum(check == digit(n[8]));
+    }
+    // Ten digits are a personal number, a foreigner's number or another
+    // registration number, each with its own weights.
+    let check = digit(n[9]);
+    let person = weighted(&[2, 4, 8, 5, 10, 9, 7, 3, 6], n) % 11 % 10 == check;
+    let foreigner = weighted(&[21, 19, 17, 13, 11, 9, 7, 3, 1], n) % 10 == check;
+    let other = (11 - weighted(&[4, 3, 2, 7, 6, 5, 4, 3, 2], n) % 11) % 11 == check;
+    checksum(person || foreigner || other)
+}
+
+fn ch(n: &[u8]) -> Check {
+    format(n.len() == 9 && is_digits(n))?;
+    let check = 11 - weighted(&[5, 4, 3, 2, 7, 6, 5, 4], n) % 11;
+    checksum(check != 10 && check % 11 == digit(n[8]))
+}
+
+fn cy(n: &[u8]) -> Check {
+    format(n.len() == 9 && is_digits(&n[..8]) && n[8].is_ascii_uppercase() && n[0] != b'2')?;
+    const ODD: [u32; 10] = [1, 0, 5, 7, 9, 13, 15, 17, 19, 21];
+    let sum: u32 = n[..8].iter().enumerate()
+        .map(|(i, &b)| if i % 2 == 0 { ODD[digit(b) as usize] } else { digit(b) })
+        .sum();
+    checksum(b'A' + (sum % 26) as u8 == n[8])
+}
+
+fn cz(n: &[u8]) -> Check {
+    format((8..=10).contains(&n.len()) && is_digits(n))?;
+    match n.len() {
+        // Legal entities.
+        8 => {
+            format(n[0] != b'9')?;
+            let check = (11 - weighted(&[8, 7, 6, 5, 4, 3, 2], n) % 11) % 11;
+            checksum((if check == 0 { 1 } else { check }) % 10 == digit(n[7]))
+        },
+        // Birth numbers issued before 1954 have no check digit.
+        9 => Ok(()),
+        _ => {
+            let body = number(&n[..9]);
+            checksum(number(n).is_multiple_of(11) || (body % 11 == 10 && n[9] == b'0'))
+        }
+    }
+}
+
+fn de(n: &[u8]) -> Check {
+    format(n.len() == 9 && is_digits(n) && n[0] != b'0')?;
+    checksum(mod_11_10(n))
+}
+
+fn dk(n: &[u8]) -> Check {
+    format(n.len() == 8 && is_digits(n) && n[0] != b'0')?;
+    checksum(weighted(&[2, 7, 6, 5, 4, 3, 2, 1], n).is_multiple_of(11))
+}
+
+fn ee(n: &[u8]) -> Check {
+    format(n.len() == 9 && is_digits(n) && n.starts_with(b"10"))?;
+    checksum(weighted(&[3, 7, 1, 3, 7, 1, 3, 7, 1], n).is_multiple_of(10))
+}
+
+fn es(n: &[u8]) -> Check {
+    const LETTERS: &[u8] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+    format(n.len() == 9 && is_digits(&n[1..8]))?;
+    let letter = |num: u64| LETTERS[(num % 23) as usize];
+    match n[0] {
+        // DNI of a Spanish national.
+        b'0'..=b'9' => {
+            format(n[7].is_ascii_digit())?;
+            checksum(letter(number(&n[..8])) == n[8])
+        },
+        // NIE of a foreigner.
+        b'X' | b'Y' | b'Z' => checksum(letter((n[0] - b'X') as u64 * 10_000_000 + number(&n[1..8])) == n[8]),
+        b'K' | b'L' | b'M' => checksum(letter(number(&n[1..8])) == n[8]),
+        // CIF of a legal entity.
+        b'A'..=b'H' | b'J' | b'N' | b'P' | b'Q' | b'R' | b'S' | b'U' | b'V' | b'W' => {
+            let sum: u32 = n[1..8].iter().enumerate().map(|(i, &b)| {
+                let d = digit(b);
+                if i % 2 == 0 { d * 2 / 10 + d * 2 % 10 } else { d }
+            }).sum();
+            let check = (10 - sum % 10) % 10;
+            checksum(n[8] == b'0' + check as u8 || n[8] == b"JABCDEFGHI"[check as usize])
+        },
+        _ => format(false)
+    }
+}
+
+fn fi(n: &[u8]) -> Check {
+    format(n.len() == 8 && is_digits(n))?;
+    let rest = weighted(&[7, 9, 10, 5, 8, 4, 2], n) % 11;
+    checksum(rest != 1 && (11 - rest) % 11 == digit(n[7]))
+}
+
+fn fr(n: &[u8]) -> Check {
+    format(n.len() == 11 && is_digits(&n[2..]) && n[..2].iter().all(u8::is_ascii_alphanumeric))?;
+    // Keys containing letters are issued to new companies and cannot be
+    // verified offline.
+    if !is_digits(&n[..2]) {
+        return Ok(());
+    }
+    checksum(number(&n[..2]) == (12 + 3 * (number(&n[2..]) % 97)) % 97)
+}
+
+fn gb(n: &[u8]) -> Check {
+    // Government departments and health authorities.
+    if n.len() == 5 && is_digits(&n[2..]) {
+        return match &n[..2] {
+            b"GD" => checksum(number(&n[2..]) < 500),
+            b"HA" => checksum(number(&n[2..]) >= 500),
+            _ => format(false)
+        };
+    }
+    format((n.len() == 9 || n.len() == 12) && is_digits(n))?;
+    let sum = weighted(&[8, 7, 6, 5, 4, 3, 2, 10, 1], n);
+    checksum(sum.is_multiple_of(97) || (sum + 55).is_multiple_of(97))
+}
+
+fn gr(n: &[u8]) -> Check {
+    format(n.len() == 9 && is_digits(n))?;
+    let sum: u32 = n[..8].iter().enumerate().map(|(i, &b)| digit(b) << (8 - i)).sum();
+    checksum(sum % 11 % 10 == digit(n[8]))
+}
+
+fn hr(n: &[u8]) -> Check {
+    format(n.len() == 11 && is_digits(n))?;
+    checksum(mod_11_10(n))
+}
+
+fn hu(n: &[u8]) -> Check {
+    format(n.len() == 8 && is_digits(n))?;
+    checksum(weighted(&[9, 7, 3, 1, 9, 7, 3, 1], n).is_multiple_of(10))
+}
+
+fn ie(n: &[u8]) -> Check {
+    const LETTERS: &[u8] = b"WABCDEFGHIJKLMNOPQRSTUV";
+    format(n.len() == 8 || n.len() == 9)?;
+    // The old format "8D79739I" moves the first digit behind the others.
+    let old = n[0].is_ascii_digit() && (n[1].is_ascii_uppercase() || n[1] == b'+' || n[1] == b'*');
+    let mut digits = [b'0'; 7];
+    if old {
+        digits[1..6].copy_from_slice(&n[2..7]);
+        digits[6] = n[0];
+    } else {
+        digits.copy_from_slice(&n[..7]);
+    }
+    format(is_digits(&digits) && n[7].is_ascii_uppercase())?;
+    let mut sum = weighted(&[8, 7, 6, 5, 4, 3, 2], &digits);
+    if n.len() == 9 {
+        let pos = LETTERS[..10].iter().position(|&l| l == n[8]);
+        format(!old && pos.is_some())?;
+        sum += 9 * pos.unwrap_or(0) as u32;
+    }
+    checksum(LETTERS[(sum % 23) as usize] == n[7])
+}
+
+fn it(n: &[u8]) -> Check {
+    format(n.len() == 11 && is_digits(n) && n[..7] != *b"0000000")?;
+    let office = number(&n[7..10]);
+    format((1..=100).contains(&office) || matches!(office, 120 | 121 | 888 | 999))?;
+    checksum(luhn(n) == 0)
+}
+
+fn lt(n: &[u8]) -> Check {
+    format((n.len() == 9 || n.len() == 12) && is_digits(n) && n[n.len() - 2] == b'1')?;
+    let body = &n[..n.len() - 1];
+    let sum = |offset: usize| -> u32 {
+        body.iter().enumerate().map(|(i, &b)| (1 + (i + offset) % 9) as u32 * digit(b)).sum::<u32>() % 11
+    };
+    let mut check = sum(0);
+    if check == 10 {
+        check = sum(2);
+    }
+    checksum(check % 10 == digit(n[n.len() - 1]))
+}
+
+fn lu(n: &[u8]) -> Check {
+    format(n.len() == 8 && is_digits(n))?;
+    checksum(number(&n[..6]) % 89 == number(&n[6..]))
+}
+
+fn lv(n: &[u8]) -> Check {
+    format(n.len() == 11 && is_digits(n))?;
+    if n[0] > b'3' {
+        // Legal entities.
+        return checksum(weighted(&[9, 1, 4, 8, 3, 10, 2, 5, 7, 6, 1], n) % 11 == 3);
+    }
+    // Personal codes issued since 2017 start with 32 and have no check digit.
+    if n.starts_with(b"32") {
+        return Ok(());
+    }
+    let sum = weighted(&[1, 6, 3, 7, 9, 10, 5, 8, 4, 2], n);
+    checksum((1101 - sum) % 11 == digit(n[10]))
+}
+
+fn mt(n: &[u8]) -> Check {
+    format(n.len() == 8 && is_digits(n) && n[0] != b'0')?;
+    checksum(weighted(&[3, 4, 6, 7, 8, 9, 10, 1], n).is_multiple_of(37))
+}
+
+fn nl(n: &[u8]) -> Check {
+    format(n.len() == 12 && is_digits(&n[..9]) && n[9] == b'B' && is_digits(&n[10..]))?;
+    let legacy = weighted(&[9, 8, 7, 6, 5, 4, 3, 2], n) % 11 == digit(n[8]);
+    // Numbers issued to sole proprietors since 2020 use mod 97 over the
+    // whole number including "NL", as for IBANs.
+    let modern = b"NL".iter().chain(n).fold(0, |acc, &b| {
+        let value = (b as char).to_digit(36).unwrap_or(0);
+        if value < 10 { (acc * 10 + value) % 97 } else { (acc * 100 + value) % 97 }
+    }) == 1;
+    checksum(legacy || modern)
+}
+
+fn no(n: &[u8]) -> Check {
+    format(n.len() == 9 && is_digits(n))?;
+    let check = 11 - weighted(&[3, 2, 7, 6, 5, 4, 3, 2], n) % 11;
+    checksum(check != 10 && check % 11 == digit(n[8]))
+}
+
+fn pl(n: &[u8]) -> Check {
+    format(n.len() == 10 && is_digits(n))?;
+    checksum(weighted(&[6, 5, 7, 2, 3, 4, 5, 6, 7], n) % 11 == digit(n[9]))
+}
+
+fn pt(n: &[u8]) -> Check {
+    format(n.len() == 9 && is_digits(n) && n[0] != b'0')?;
+    let check = 11 - weighted(&[9, 8, 7, 6, 5, 4, 3, 2], n) % 11;
+    checksum((if check > 9 { 0 } else { check }) == digit(n[8]))
+}
+
+fn ro(n: &[u8]) -> Check {
+    format((2..=10).contains(&n.len()) && is_digits(n) && n[0] != b'0')?;
+    let mut padded = [b'0'; 10];
+    padded[10 - n.len()..].copy_from_slice(n);
+    let sum = weighted(&[7, 5, 3, 2, 1, 7, 5, 3, 2], &padded);
+    checksum(sum * 10 % 11 % 10 == digit(padded[9]))
+}
+
+fn se(n: &[u8]) -> Check {
+    format(n.len() == 12 && is_digits(n) && n.ends_with(b"01"))?;
+    checksum(luhn(&n[..10]) == 0)
+}
+
+fn si(n: &[u8]) -> Check {
+    format(n.len() == 8 && is_digits(n) && n[0] != b'0')?;
+    let check = 11 - weighted(&[8, 7, 6, 5, 4, 3, 2], n) % 11;
+    checksum((if check == 10 { 0 } else { check }) == digit(n[7]))
+}
+
+fn sk(n: &[u8]) -> Check {
+    format(n.len() == 10 && is_digits(n) && n[0] != b'0' && matches!(n[2], b'2' | b'3' | b'4' | b'7' | b'8' | b'9'))?;
+    checksum(number(n).is_multiple_of(11))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+    use super::{ validate, validate_for, VatError };
+
+    #[test]
+    fn valid() {
+        for vat in &[
+            "ATU13585627", "BE0403019261", "BG175074752", "BG7523169263", "CY10259033P",
+            "CZ25123891", "CZ7103192745", "DE136695976", "DK13585628", "EE100931558",
+            "EL094259216", "ESA13585625", "ES54362315K", "ESX2482300W", "FI20774740",
+            "FR40303265045", "HR33392005961", "HU12892312", "IE6433435F", "IE6433435OA",
+            "IE8D79739I", "IT00743110157", "LT119511515", "LT100001919017", "LU15027442",
+            "LV40003521600", "LV16117519997", "MT11679112", "NL004495445B01", "PL8567346215",
+            "PT501964843", "RO18547290", "SE123456789701", "SI50223054", "SK2022749619",
+            "GB980780684", "XI980780684", "CHE107787577", "NO995525828",
+        ] {
+            assert_eq!(*vat, validate(vat).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn normalized() {
+        let vat = validate("el 094 259 216").unwrap();
+        assert_eq!(Country::GR, vat.country);
+        assert_eq!("094259216", vat.number);
+
+        let vat = validate("CHE-107.787.577 MWST").unwrap();
+        assert_eq!(Country::CH, vat.country);
+        assert_eq!("CHE107787577", vat.to_string());
+
+        let vat = validate("XI 980 7806 84").unwrap();
+        assert_eq!(Country::GB, vat.country);
+        assert_eq!("XI", vat.prefix);
+
+        assert_eq!("BE0403019261", validate("BE 403.019.261").unwrap().to_string());
+        assert_eq!("NO995525828", validate("NO 995 525 828 MVA").unwrap().to_string());
+    }
+
+    #[test]
+    fn for_country() {
+        assert_eq!("PL8567346215", validate_for(Country::PL, "856-734-62-15").unwrap().to_string());
+        assert_eq!("EL094259216", validate_for(Country::GR, "EL094259216").unwrap().to_string());
+        assert_eq!(Err(VatError::InvalidFormat), validate_for(Country::DE, "12345"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(VatError::InvalidChecksum), validate("DE136695977"));
+        assert_eq!(Err(VatError::InvalidChecksum), validate("PL8567346216"));
+        assert_eq!(Err(VatError::InvalidFormat), validate("ATX13585627"));
+        assert_eq!(Err(VatError::UnknownPrefix("GR".to_string())), validate("GR094259216"));
+        assert_eq!(Err(VatError::UnknownPrefix("US".to_string())), validate("US123456789"));
+        assert_eq!(Err(VatError::UnknownPrefix("ÉL".to_string())), validate("ÉL094259216"));
+        assert_eq!(Err(VatError::UnknownPrefix("US".to_string())), validate_for(Country::US, "123"));
+    }
+
+    #[test]
+    fn prefixes() {
+        assert_eq!(Some("EL"), Country::GR.vat_prefix());
+        assert_eq!(Some("PL"), Country::PL.vat_prefix());
+        assert_eq!(None, Country::US.vat_prefix());
+        let table = super::VAT_PREFIX_SEARCH_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
@@ -0,0 +1,470 @@
+// International vehicle registration codes.
+//
+// Distinguishing signs of vehicles in international traffic as registered
+// with the UN under the 1949 Geneva and 1968 Vienna Conventions on Road
+// Traffic.
+
+use crate::Country;
+
+impl Country {
+    /// The distinguishing sign of vehicles registered in this country,
+    /// e.g. "D" for Germany and "UK" for the United Kingdom.
+    pub fn vehicle_code(&self) -> Option<&'static str> {
+        use Country::*;
+        Some(match *self {
+            AD => "AND",
+            AE => "UAE",
+            AF => "AFG",
+            AI => "AXA",
+            AL => "AL",
+            AM => "AM",
+            AO => "ANG",
+            AR => "RA",
+            AU => "AUS",
+            AW => "ARU",
+            AX => "AX",
+            AZ => "AZ",
+            BA => "BIH",
+            BB => "BDS",
+            BD => "BD",
+            BE => "B",
+            BF => "BF",
+            BG => "BG",
+            BH => "BRN",
+            BI => "RU",
+            BJ => "DY",
+            BN => "BRU",
+            BO => "BOL",
+            BR => "BR",
+            BS => "BS",
+            BT => "BHT",
+            BW => "RB",
+            BY => "BY",
+            BZ => "BH",
+            CA => "CDN",
+            CD => "CGO",
+            CF => "RCA",
+            CG => "RCB",
+            CH => "CH",
+            CI => "CI",
+            CL => "RCH",
+            CM => "CAM",
+            CO => "CO",
+            CR => "CR",
+            CU => "C",
+            CY => "CY",
+            CZ => "CZ",
+            DE => "D",
+            DK => "DK",
+            DM => "WD",
+            DO => "DOM",
+            DZ => "DZ",
+            EC => "EC",
+            EE => "EST",
+            EG => "ET",
+            ER => "ER",
+            ES => "E",
+            ET => "ETH",
+            FI => "FIN",
+            FJ => "FJI",
+            FO => "FO",
+            FR => "F",
+            GA => "G",
+            GB => "UK",
+            GD => "WG",
+            GE => "GE",
+            GG => "GBG",
+            GH => "GH",
+            GI => "GBZ",
+            GM => "WAG",
+            GN => "RG",
+            GQ => "GQ",
+            GR => "GR",
+            GT => "GCA",
+            GW => "RGB",
+            GY => "GUY",
+            HK => "HK",
+            HR => "HR",
+            HT => "RH",
+            HU => "H",
+            ID => "RI",
+            IE => "IRL",
+            IL => "IL",
+            IM => "GBM",
+            IN => "IND",
+            IQ => "IRQ",
+            IR => "IR",
+            IS => "IS",
+            IT => "I",
+            JE => "GBJ",
+            JM => "JA",
+            JO => "HKJ",
+            JP => "J",
+            KE => "EAK",
+            KG => "KS",
+            KH => "K",
+            KM => "COM",
+            KR => "ROK",
+            KW => "KWT",
+            KZ => "KZ",
+            LA => "LAO",
+            LB => "RL",
+            LC => "WL",
+            LI => "FL",
+            LK => "CL",
+            LR => "LB",
+            LS => "LS",
+            LT => "LT",
+            LU => "L",
+            LV => "LV",
+            LY => "LAR",
+            MA => "MA",
+            MC => "MC",
+            MD => "MD",
+            ME => "MNE",
+            MG => "RM",
+            MK => "NMK",
+            ML => "RMM",
+            MM => "MYA",
+            MN => "MGL",
+            MR => "RIM",
+            MT => "M",
+            MU => "MS",
+            MV => "MV",
+            MW => "MW",
+            MX => "MEX",
+            MY => "MAL",
+            MZ => "MOC",
+            NA => "NAM",
+            NE => "RN",
+            NG => "WAN",
+            NI => "NIC",
+            NL => "NL",
+            NO => "N",
+            NP => "NEP",
+            NR => "NAU",
+            NZ => "NZ",
+            OM => "OM",
+            PA => "PA",
+            PE => "PE",
+            PG => "PNG",
+            PH => "RP",
+            PK => "PK",
+            PL => "PL",
+            PT => "P",
+            PY => "PY",
+            QA => "Q",
+            RO => "RO",
+            RS => "SRB",
+            RU => "RUS",
+            RW => "RWA",
+            SA => "KSA",
+            SB => "SOL",
+            SC => "SY",
+            SD => "SUD",
+            SE => "S",
+            SG => "SGP",
+            SI => "SLO",
+            SK => "SK",
+            SL => "WAL",
+            SM => "RSM",
+            SN => "SN",
+            SO => "SO",
+            SR => "SME",
+            ST => "STP",
+            SV => "ES",
+            SY => "SYR",
+            SZ => "SD",
+            TD => "TCH",
+            TG => "TG",
+            TH => "T",
+            TJ => "TJ",
+            TL => "TL",
+            TM => "TM",
+            TN => "TN",
+            TO => "TO",
+            TR => "TR",
+            TT => "TT",
+            TW => "RC",
+            TZ => "EAT",
+            UA => "UA",
+            UG => "EAU",
+            US => "USA",
+            UY => "ROU",
+            UZ => "UZ",
+            VA => "V",
+            VC => "WV",
+            VE => "YV",
+            VN => "VN",
+            VU => "VU",
+            WS => "WS",
+            ZA => "ZA",
+            ZM => "Z",
+            ZW => "ZW",
+            _ => return None
+        })
+    }
+
+    /// The distinguishing sign in use in a given year.  A sign is taken to
+    /// change at the start of the year in which it was replaced, so this
+    /// returns "UK" for the United Kingdom in 2021 and "GB" before.
+    pub fn vehicle_code_in(&self, year: u16) -> Option<&'static str> {
+        FORMER_VEHICLE_CODES.iter()
+            .filter(|&&(country, _, until)| country == *self && year < until)
+            .min_by_key(|&&(_, _, until)| until)
+            .map(|&(_, code, _)| code)
+            .or_else(|| self.vehicle_code())
+    }
+
+    /// Looks up a distinguishing sign.  Former signs such as "GB" and "SF"
+    /// (Finland until 1993) are accepted too.
+    pub fn from_vehicle_code(s: &str) -> Option<Country> {
+        let s = s.trim();
+        VEHICLE_CODE_SEARCH_TABLE.binary_search_by(|&(o, _)| crate::cmp_ignore_case(o, s)).ok()
+            .map(|i| VEHICLE_CODE_SEARCH_TABLE[i].1)
+    }
+}
+
+// Signs which have been replaced, with the year of the change.
+const FORMER_VEHICLE_CODES : &[(Country, &str, u16)] = &[
+    (Country::BF, "HV", 1984),
+    (Country::FI, "SF", 1993),
+    (Country::CD, "ZRE", 1997),
+    (Country::MK, "MK", 2019),
+    (Country::GB, "GB", 2021),
+];
+
+const VEHICLE_CODE_SEARCH_TABLE : &[(&str, Country)] = &[
+    ("AFG", Country::AF),
+    ("AL", Country::AL),
+    ("AM", Country::AM),
+    ("AND", Country::AD),
+    ("ANG", Country::AO),
+    ("ARU", Country::AW),
+    ("AUS", Country::AU),
+    ("AX", Country::AX),
+    ("AXA", Country::AI),
+    ("AZ", Country::AZ),
+    ("B", Country::BE),
+    ("BD", Country::BD),
+    ("BDS", Country::BB),
+    ("BF", Country::BF),
+    ("BG", Country::BG),
+    ("BH", Country::BZ),
+    ("BHT", Country::BT),
+    ("BIH", Country::BA),
+    ("BOL", Country::BO),
+    ("BR", Country::BR),
+    ("BRN", Country::BH),
+    ("BRU", Country::BN),
+    ("BS", Country::BS),
+    ("BY", Country::BY),
+    ("C", Country::CU),
+    ("CAM", Country::CM),
+    ("CDN", Country::CA),
+    ("CGO", Country::CD),
+    ("CH", Country::CH),
+    ("CI", Country::CI),
+    ("CL", Country::LK),
+    ("CO", Country::CO),
+    ("COM", Country::KM),
+    ("CR", Country::CR),
+    ("CY", Country::CY),
+    ("CZ", Country::CZ),
+    ("D", Country::DE),
+    ("DK", Country::DK),
+    ("DOM", Country::DO),
+    ("DY", Country::BJ),
+    ("DZ", Country::DZ),
+    ("E", Country::ES),
+    ("EAK", Country::KE),
+    ("EAT", Country::TZ),
+    ("EAU", Country::UG),
+    ("EC", Country::EC),
+    ("ER", Country::ER),
+    ("ES", Country::SV),
+    ("EST", Country::EE),
+    ("ET", Country::EG),
+    ("ETH", Country::ET),
+    ("F", Country::FR),
+    ("FIN", Country::FI),
+    ("FJI", Country::FJ),
+    ("FL", Country::LI),
+    ("FO", Country::FO),
+    ("G", Country::GA),
+    ("GB", Country::GB),
+    ("GBG", Country::GG),
+    ("GBJ", Country::JE),
+    ("GBM", Country::IM),
+    ("GBZ", Country::GI),
+    ("GCA", Country::GT),
+    ("GE", Country::GE),
+    ("GH", Country::GH),
+    ("GQ", Country::GQ),
+    ("GR", Country::GR),
+    ("GUY", Country::GY),
+    ("H", Country::HU),
+    ("HK", Country::HK),
+    ("HKJ", Country::JO),
+    ("HR", Country::HR),
+    ("HV", Country::BF),
+    ("I", Country::IT),
+    ("IL", Country::IL),
+    ("IND", Country::IN),
+    ("IR", Country::IR),
+    ("IRL", Country::IE),
+    ("IRQ", Country::IQ),
+    ("IS", Country::IS),
+    ("J", Country::JP),
+    ("JA", Country::JM),
+    ("K", Country::KH),
+    ("KS", Country::KG),
+    ("KSA", Country::SA),
+    ("KWT", Country::KW),
+    ("KZ", Country::KZ),
+    ("L", Country::LU),
+    ("LAO", Country::LA),
+    ("LAR", Country::LY),
+    ("LB", Country::LR),
+    ("LS", Country::LS),
+    ("LT", Country::LT),
+    ("LV", Country::LV),
+    ("M", Country::MT),
+    ("MA", Country::MA),
+    ("MAL", Country::MY),
+    ("MC", Country::MC),
+    ("MD", Country::MD),
+    ("MEX", Country::MX),
+    ("MGL", Country::MN),
+    ("MK", Country::MK),
+    ("MNE", Country::ME),
+    ("MOC", Country::MZ),
+    ("MS", Country::MU),
+    ("MV", Country::MV),
+    ("MW", Country::MW),
+    ("MYA", Country::MM),
+    ("N", Country::NO),
+    ("NAM", Country::NA),
+    ("NAU", Country::NR),
+    ("NEP", Country::NP),
+    ("NIC", Country::NI),
+    ("NL", Country::NL),
+    ("NMK", Country::MK),
+    ("NZ", Country::NZ),
+    ("OM", Country::OM),
+    ("P", Country::PT),
+    ("PA", Country::PA),
+    ("PE", Country::PE),
+    ("PK", Country::PK),
+    ("PL", Country::PL),
+    ("PNG", Country::PG),
+    ("PY", Country::PY),
+    ("Q", Country::QA),
+    ("RA", Country::AR),
+    ("RB", Country::BW),
+    ("RC", Country::TW),
+    ("RCA", Country::CF),
+    ("RCB", Country::CG),
+    ("RCH", Country::CL),
+    ("RG", Country::GN),
+    ("RGB", Country::GW),
+    ("RH", Country::HT),
+    ("RI", Country::ID),
+    ("RIM", Country::MR),
+    ("RL", Country::LB),
+    ("RM", Country::MG),
+    ("RMM", Country::ML),
+    ("RN", Country::NE),
+    ("RO", Country::RO),
+    ("ROK", Country::KR),
+    ("ROU", Country::UY),
+    ("RP", Country::PH),
+    ("RSM", Country::SM),
+    ("RU", Country::BI),
+    ("RUS", Country::RU),
+    ("RWA", Country::RW),
+    ("S", Country::SE),
+    ("SD", Country::SZ),
+    ("SF", Country::FI),
+    ("SGP", Country::SG),
+    ("SK", Country::SK),
+    ("SLO", Country::SI),
+    ("SME", Country::SR),
+    ("SN", Country::SN),
+    ("SO", Country::SO),
+    ("SOL", Country::SB),
+    ("SRB", Country::RS),
+    ("STP", Country::ST),
+    ("SUD", Country::SD),
+    ("SY", Country::SC),
+    ("SYR", Country::SY),
+    ("T", Country::TH),
+    ("TCH", Country::TD),
+    ("TG", Country::TG),
+    ("TJ", Country::TJ),
+    ("TL", Country::TL),
+    ("TM", Country::TM),
+    ("TN", Country::TN),
+    ("TO", Country::TO),
+    ("TR", Country::TR),
+    ("TT", Country::TT),
+    ("UA", Country::UA),
+    ("UAE", Country::AE),
+    ("UK", Country::GB),
+    ("USA", Country::US),
+    ("UZ", Country::UZ),
+    ("V", Country::VA),
+    ("VN", Country::VN),
+    ("VU", Country::VU),
+    ("WAG", Country::GM),
+    ("WAL", Country::SL),
+    ("WAN", Country::NG),
+    ("WD", Country::DM),
+    ("WG", Country::GD),
+    ("WL", Country::LC),
+    ("WS", Country::WS),
+    ("WV", Country::VC),
+    ("YV", Country::VE),
+    ("Z", Country::ZM),
+    ("ZA", Country::ZA),
+    ("ZRE", Country::CD),
+    ("ZW", Country::ZW),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::Country;
+
+    #[test]
+    fn vehicle_code() {
+        assert_eq!(Some("D"), Country::DE.vehicle_code());
+        assert_eq!(Some("PL"), Country::PL.vehicle_code());
+        assert_eq!(Some("CH"), Country::CH.vehicle_code());
+        assert_eq!(Some("RUS"), Country::RU.vehicle_code());
+        assert_eq!(Some("UK"), Country::GB.vehicle_code());
+        assert_eq!(None, Country::AQ.vehicle_code());
+    }
+
+    #[test]
+    fn former_codes() {
+        assert_eq!(Some("GB"), Country::GB.vehicle_code_in(2020));
+        assert_eq!(Some("UK"), Country::GB.vehicle_code_in(2021));
+        assert_eq!(Some("SF"), Country::FI.vehicle_code_in(1990));
+        assert_eq!(Some("D"), Country::DE.vehicle_code_in(1990));
+        assert_eq!(Some(Country::GB), Country::from_vehicle_code("GB"));
+        assert_eq!(Some(Country::GB), Country::from_vehicle_code("uk"));
+        assert_eq!(Some(Country::FI), Country::from_vehicle_code("SF"));
+        assert_eq!(None, Country::from_vehicle_code("XX"));
+    }
+
+    #[test]
+    fn search_table_matches_codes() {
+        let table = super::VEHICLE_CODE_SEARCH_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(_, country) in crate::COUNTRY_CODE_SEARCH_TABLE {
+            if let Some(code) = country.vehicle_code() {
+                assert_eq!(Some(country), Country::from_vehicle_code(code));
+            }
+        }
+    }
+}